@@ -5,7 +5,8 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use eframe::egui::{self, Color32, FontId, Margin, RichText, Rounding, Stroke, Vec2};
-use quicktransform::transforms::{encode, hash, generate};
+use eframe::egui::text::{LayoutJob, TextFormat};
+use quicktransform::transforms::{cert, cipher, detect, encode, hash, generate, jwt, number, otp, query, serial, strength, structured, time, unicode};
 use quicktransform::{APP_NAME, BRAND, VERSION};
 use std::path::PathBuf;
 
@@ -187,19 +188,73 @@ struct App {
     encode_op: EncodeOp,
     hash_algo: HashAlgo,
     gen_len: String,
+    gen_count: String,
+    gen_format: generate::ListFormat,
+    gen_unique: bool,
+    /// Optional u64 seed for reproducible (not secret) output
+    gen_seed: String,
+    gen_regex: String,
+    pass_policy: generate::PasswordPolicy,
+    passphrase: generate::PassphraseOptions,
+    /// Last strength estimate and the output it was made for
+    strength: Option<(String, strength::Strength)>,
+    uuid_version: u8,
+    uuid_namespace: String,
+    uuid_name: String,
+    snowflake_epoch: String,
+    snowflake_worker: String,
     file: Option<PathBuf>,
     status: Option<(String, bool)>, // (message, is_error)
     show_help: bool,
     help_section: usize,
     theme: Theme,
+    num_input: String,
+    num_width: String,
+    cipher_op: CipherOp,
+    cipher_key: String,
+    cipher_decrypt: bool,
+    jwt_key: String,
+    jwt_sign: bool,
+    jwt_alg: String,
+    asn1_tree: Vec<encode::Asn1Node>,
+    datauri_image: Option<egui::ColorImage>,
+    datauri_texture: Option<egui::TextureHandle>,
+    data_from: serial::Format,
+    data_to: serial::Format,
+    data_minify: bool,
+    data_sort: bool,
+    data_query: String,
+    time_from: time::Kind,
+    /// Base32 secret, or an otpauth:// URI until it is parsed
+    otp_secret: String,
+    otp: otp::OtpAuth,
+    otp_issuer: String,
+    otp_hotp: bool,
+    inspect_op: InspectOp,
+    inspect_enc: usize,
+    inspect_chars: Vec<unicode::CharInfo>,
 }
 
 #[derive(Default, Clone, Copy, PartialEq)]
-enum Tab { #[default] Encode, Hash, Generate }
+enum Tab { #[default] Encode, Hash, Generate, Cipher, Jwt, Data, Time, Otp, Inspect }
 
 #[derive(Default, Clone, Copy, PartialEq)]
 enum EncodeOp {
-    #[default] B64Enc, B64Dec, HexEnc, HexDec, UrlEnc, UrlDec, HtmlEnc, HtmlDec
+    #[default] B64Enc, B64Dec, HexEnc, HexDec, UrlEnc, UrlDec, HtmlEnc, HtmlDec, HexDump,
+    BinEnc, BinDec, CArray, Cert, Asn1, DataUri, Auto,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+enum InspectOp {
+    #[default] Chars,
+    Normalize(unicode::Form),
+    Encode,
+    Decode,
+}
+
+#[derive(Default, Clone, Copy, PartialEq)]
+enum CipherOp {
+    #[default] Rot13, RotN, Atbash, Vigenere, Affine, Xor, CrackRot, CrackXor
 }
 
 #[derive(Default, Clone, Copy, PartialEq)]
@@ -307,21 +362,41 @@ impl App {
             .stroke(Stroke::new(1.0, Colors::border(t)))
             .inner_margin(20.0)
             .show(ui, |ui| {
-                match self.tab {
-                    Tab::Encode => self.encode_panel(ui),
-                    Tab::Hash => self.hash_panel(ui),
-                    Tab::Generate => self.generate_panel(ui),
-                }
+                egui::ScrollArea::vertical().max_height((ui.available_height() - 40.0).max(200.0)).show(ui, |ui| {
+                    match self.tab {
+                        Tab::Encode => self.encode_panel(ui),
+                        Tab::Hash => self.hash_panel(ui),
+                        Tab::Generate => self.generate_panel(ui),
+                        Tab::Cipher => self.cipher_panel(ui),
+                        Tab::Jwt => self.jwt_panel(ui),
+                        Tab::Data => self.data_panel(ui),
+                        Tab::Time => self.time_panel(ui),
+                        Tab::Otp => self.otp_panel(ui),
+                        Tab::Inspect => self.inspect_panel(ui),
+                    }
+                });
             });
     }
 
     fn tab_bar(&mut self, ui: &mut egui::Ui) {
-        ui.horizontal(|ui| {
+        ui.horizontal_wrapped(|ui| {
             self.tab_button(ui, "Encode", Tab::Encode, Colors::ACCENT);
             ui.add_space(4.0);
             self.tab_button(ui, "Hash", Tab::Hash, Colors::PURPLE);
             ui.add_space(4.0);
             self.tab_button(ui, "Generate", Tab::Generate, Colors::GREEN);
+            ui.add_space(4.0);
+            self.tab_button(ui, "Cipher", Tab::Cipher, Colors::AMBER);
+            ui.add_space(4.0);
+            self.tab_button(ui, "JWT", Tab::Jwt, Colors::BLUE);
+            ui.add_space(4.0);
+            self.tab_button(ui, "Data", Tab::Data, Colors::ACCENT_SOFT);
+            ui.add_space(4.0);
+            self.tab_button(ui, "Time", Tab::Time, Colors::RED);
+            ui.add_space(4.0);
+            self.tab_button(ui, "OTP", Tab::Otp, Colors::GREEN);
+            ui.add_space(4.0);
+            self.tab_button(ui, "Inspect", Tab::Inspect, Colors::PURPLE);
         });
     }
 
@@ -337,7 +412,7 @@ impl App {
                 .fill(bg)
                 .stroke(stroke)
                 .rounding(Rounding::same(8.0))
-                .min_size(Vec2::new(100.0, 36.0))
+                .min_size(Vec2::new(80.0, 36.0))
         ).clicked() {
             self.tab = tab;
             self.status = None;
@@ -355,6 +430,7 @@ impl App {
 
         ui.horizontal_wrapped(|ui| {
             ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+            self.encode_chip(ui, "Auto", EncodeOp::Auto);
             self.encode_chip(ui, "Base64 Enc", EncodeOp::B64Enc);
             self.encode_chip(ui, "Base64 Dec", EncodeOp::B64Dec);
             self.encode_chip(ui, "Hex Enc", EncodeOp::HexEnc);
@@ -363,10 +439,148 @@ impl App {
             self.encode_chip(ui, "URL Dec", EncodeOp::UrlDec);
             self.encode_chip(ui, "HTML Enc", EncodeOp::HtmlEnc);
             self.encode_chip(ui, "HTML Dec", EncodeOp::HtmlDec);
+            self.encode_chip(ui, "Hex Dump", EncodeOp::HexDump);
+            self.encode_chip(ui, "Binary Enc", EncodeOp::BinEnc);
+            self.encode_chip(ui, "Binary Dec", EncodeOp::BinDec);
+            self.encode_chip(ui, "C Array", EncodeOp::CArray);
+            self.encode_chip(ui, "Certificate", EncodeOp::Cert);
+            self.encode_chip(ui, "ASN.1", EncodeOp::Asn1);
+            self.encode_chip(ui, "Data URI", EncodeOp::DataUri);
         });
 
+        self.handle_dropped_file(ui.ctx());
+
         ui.add_space(20.0);
         self.io_section(ui, true);
+
+        if self.encode_op == EncodeOp::DataUri {
+            if let Some(image) = self.datauri_image.take() {
+                self.datauri_texture = Some(ui.ctx().load_texture("datauri-preview", image, Default::default()));
+            }
+            if let Some(ref texture) = self.datauri_texture {
+                ui.add_space(20.0);
+                ui.label(RichText::new("Preview").size(12.0).color(Colors::text_muted(self.theme)));
+                ui.add_space(6.0);
+                ui.add(egui::Image::new(texture).max_size(Vec2::splat(160.0)));
+            }
+        }
+
+        if self.encode_op == EncodeOp::Asn1 && !self.asn1_tree.is_empty() {
+            ui.add_space(20.0);
+            ui.label(RichText::new("Structure").size(12.0).color(Colors::text_muted(self.theme)));
+            ui.add_space(6.0);
+            Self::asn1_tree_ui(ui, &self.asn1_tree, self.theme);
+        }
+
+        self.uuid_section(ui);
+
+        ui.add_space(20.0);
+        self.number_section(ui);
+    }
+
+    /// Pasting a UUID shows its version, embedded fields and other forms
+    fn uuid_section(&self, ui: &mut egui::Ui) {
+        let input = self.input.trim();
+        if uuid::Uuid::parse_str(input).is_err() {
+            return;
+        }
+        if let Ok(report) = generate::uuid_inspect(input, None) {
+            self.report_grid(ui, "UUID", &report.summary());
+        }
+    }
+
+    /// A file dropped on the Encode tab becomes a data URI
+    fn handle_dropped_file(&mut self, ctx: &egui::Context) {
+        let Some(file) = ctx.input(|i| i.raw.dropped_files.first().cloned()) else {
+            return;
+        };
+        let data = match (&file.bytes, &file.path) {
+            (Some(bytes), _) => Ok(bytes.to_vec()),
+            (None, Some(path)) => std::fs::read(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e)),
+            (None, None) => return,
+        };
+        match data {
+            Ok(data) => {
+                self.encode_op = EncodeOp::DataUri;
+                self.input.clear();
+                self.output = encode::data_uri_encode(&data);
+                self.set_preview(&data);
+                let name = file.path.as_ref()
+                    .and_then(|p| p.file_name())
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or(file.name);
+                self.status = Some((format!("{} → {}, {} bytes", name, encode::mime_type(&data), data.len()), false));
+            }
+            Err(e) => self.status = Some((e, true)),
+        }
+    }
+
+    /// Queue a thumbnail for raster images; anything else clears the preview
+    fn set_preview(&mut self, data: &[u8]) {
+        self.datauri_texture = None;
+        self.datauri_image = image::load_from_memory(data).ok().map(|img| {
+            let img = img.thumbnail(320, 320).to_rgba8();
+            let size = [img.width() as usize, img.height() as usize];
+            egui::ColorImage::from_rgba_unmultiplied(size, img.as_raw())
+        });
+    }
+
+    fn asn1_tree_ui(ui: &mut egui::Ui, nodes: &[encode::Asn1Node], t: Theme) {
+        for node in nodes {
+            let text = RichText::new(format!("{:>5}  {}", node.offset, node.label()))
+                .size(12.0)
+                .monospace()
+                .color(Colors::text_primary(t));
+            if node.children.is_empty() {
+                ui.label(text);
+            } else {
+                egui::CollapsingHeader::new(text)
+                    .id_salt(node.offset)
+                    .default_open(true)
+                    .show(ui, |ui| Self::asn1_tree_ui(ui, &node.children, t));
+            }
+        }
+    }
+
+    fn number_section(&mut self, ui: &mut egui::Ui) {
+        let t = self.theme;
+        ui.label(RichText::new("Number").size(12.0).color(Colors::text_muted(t)));
+        ui.add_space(6.0);
+
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.num_input)
+                    .desired_width(220.0)
+                    .font(FontId::monospace(13.0))
+                    .hint_text("255, 0xff, 0b1010...")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            ui.label(RichText::new("Bits").size(12.0).color(Colors::text_muted(t)));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.num_width)
+                    .desired_width(50.0)
+                    .font(FontId::monospace(13.0))
+                    .hint_text("-")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+        });
+
+        if self.num_input.trim().is_empty() {
+            return;
+        }
+        let width = self.num_width.trim().parse().ok();
+        let text = match number::parse_int(&self.num_input, None).and_then(|n| number::convert(&n, width)) {
+            Ok(r) => {
+                let mut s = format!("DEC  {}\nHEX  {}\nOCT  {}\nBIN  {}\nB36  {}", r.decimal, r.hex, r.octal, r.binary, r.base36);
+                if let Some(signed) = r.signed {
+                    s.push_str(&format!("\nSGN  {}", signed));
+                }
+                RichText::new(s).color(Colors::text_primary(t))
+            }
+            Err(e) => RichText::new(e).color(Colors::RED),
+        };
+        ui.add_space(6.0);
+        ui.label(text.size(12.0).monospace());
     }
 
     fn encode_chip(&mut self, ui: &mut egui::Ui, label: &str, op: EncodeOp) {
@@ -438,115 +652,933 @@ impl App {
             }
         });
 
-        ui.add_space(16.0);
-        self.io_section(ui, self.file.is_none());
+        ui.add_space(16.0);
+        self.io_section(ui, self.file.is_none());
+    }
+
+    fn hash_chip(&mut self, ui: &mut egui::Ui, label: &str, algo: HashAlgo, color: Color32) {
+        let t = self.theme;
+        let active = self.hash_algo == algo;
+        let bg = if active { color.gamma_multiply(0.2) } else { Colors::bg_card(t) };
+        let text = if active { color } else { Colors::text_secondary(t) };
+
+        if ui.add(
+            egui::Button::new(RichText::new(label).size(11.0).color(text))
+                .fill(bg)
+                .rounding(Rounding::same(14.0))
+                .min_size(Vec2::new(0.0, 28.0))
+        ).clicked() {
+            self.hash_algo = algo;
+        }
+    }
+
+    // ========================================================================
+    // GENERATE PANEL
+    // ========================================================================
+
+    fn generate_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.theme;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Length").size(12.0).color(Colors::text_muted(t)));
+            ui.add_space(8.0);
+
+            ui.add(
+                egui::TextEdit::singleline(&mut self.gen_len)
+                    .desired_width(70.0)
+                    .font(FontId::monospace(14.0))
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+
+            if self.gen_len.is_empty() {
+                self.gen_len = "16".to_string();
+            }
+
+            ui.add_space(16.0);
+            ui.label(RichText::new("UUID").size(12.0).color(Colors::text_muted(t)));
+            if self.uuid_version == 0 {
+                self.uuid_version = 4;
+            }
+            egui::ComboBox::from_id_salt("uuid_version")
+                .selected_text(format!("v{}", self.uuid_version))
+                .show_ui(ui, |ui| {
+                    for v in generate::UUID_VERSIONS {
+                        ui.selectable_value(&mut self.uuid_version, v, format!("v{}", v));
+                    }
+                });
+        });
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Count").size(12.0).color(Colors::text_muted(t)));
+            ui.add_space(8.0);
+            ui.add(
+                egui::TextEdit::singleline(&mut self.gen_count)
+                    .desired_width(70.0)
+                    .font(FontId::monospace(14.0))
+                    .hint_text("1")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+
+            ui.add_space(16.0);
+            ui.label(RichText::new("Format").size(12.0).color(Colors::text_muted(t)));
+            egui::ComboBox::from_id_salt("gen_format")
+                .selected_text(self.gen_format.name())
+                .show_ui(ui, |ui| {
+                    for format in generate::LIST_FORMATS {
+                        ui.selectable_value(&mut self.gen_format, format, format.name());
+                    }
+                });
+
+            ui.add_space(16.0);
+            ui.checkbox(&mut self.gen_unique, RichText::new("Unique").size(12.0).color(Colors::text_secondary(t)));
+
+            ui.add_space(16.0);
+            ui.label(RichText::new("Seed").size(12.0).color(Colors::text_muted(t)));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.gen_seed)
+                    .desired_width(110.0)
+                    .font(FontId::monospace(14.0))
+                    .hint_text("random")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            ).on_hover_text("Same seed, same output: for test fixtures only, never for secrets");
+        });
+
+        if matches!(self.uuid_version, 3 | 5 | 8) {
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Namespace").size(12.0).color(Colors::text_muted(t)));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.uuid_namespace)
+                        .desired_width(160.0)
+                        .font(FontId::monospace(13.0))
+                        .hint_text("dns, url, oid, x500, UUID")
+                        .margin(Margin::symmetric(12.0, 8.0))
+                );
+                ui.label(RichText::new("Name").size(12.0).color(Colors::text_muted(t)));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.uuid_name)
+                        .desired_width(200.0)
+                        .font(FontId::monospace(13.0))
+                        .hint_text(if self.uuid_version == 8 { "optional" } else { "required" })
+                        .margin(Margin::symmetric(12.0, 8.0))
+                );
+            });
+        }
+
+        ui.add_space(8.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label(RichText::new("Password").size(12.0).color(Colors::text_muted(t)));
+            let policy = &mut self.pass_policy;
+            for (on, label) in [
+                (&mut policy.lowercase, "a-z"),
+                (&mut policy.uppercase, "A-Z"),
+                (&mut policy.digits, "0-9"),
+                (&mut policy.symbols, "Symbols"),
+                (&mut policy.no_lookalikes, "No look-alikes"),
+                (&mut policy.no_repeats, "No repeats"),
+            ] {
+                ui.checkbox(on, RichText::new(label).size(12.0).color(Colors::text_secondary(t)));
+            }
+        });
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Include").size(12.0).color(Colors::text_muted(t)));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.pass_policy.include)
+                    .desired_width(90.0)
+                    .font(FontId::monospace(13.0))
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            ui.label(RichText::new("Exclude").size(12.0).color(Colors::text_muted(t)));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.pass_policy.exclude)
+                    .desired_width(90.0)
+                    .font(FontId::monospace(13.0))
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            ui.label(RichText::new("Max run").size(12.0).color(Colors::text_muted(t)));
+            ui.add(egui::DragValue::new(&mut self.pass_policy.max_run).range(0..=16))
+                .on_hover_text("Longest run of one repeated character (0 = unlimited)");
+        });
+
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Passphrase words").size(12.0).color(Colors::text_muted(t)));
+            ui.add(egui::DragValue::new(&mut self.passphrase.words).range(1..=20));
+            ui.label(RichText::new("Separator").size(12.0).color(Colors::text_muted(t)));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.passphrase.separator)
+                    .desired_width(40.0)
+                    .font(FontId::monospace(13.0))
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            egui::ComboBox::from_id_salt("word_case")
+                .selected_text(self.passphrase.case.name())
+                .show_ui(ui, |ui| {
+                    for case in generate::WORD_CASES {
+                        ui.selectable_value(&mut self.passphrase.case, case, case.name());
+                    }
+                });
+            ui.checkbox(&mut self.passphrase.digit, RichText::new("+digit").size(12.0).color(Colors::text_secondary(t)));
+            ui.checkbox(&mut self.passphrase.symbol, RichText::new("+symbol").size(12.0).color(Colors::text_secondary(t)));
+        });
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Snowflake epoch").size(12.0).color(Colors::text_muted(t)));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.snowflake_epoch)
+                    .desired_width(130.0)
+                    .font(FontId::monospace(13.0))
+                    .hint_text("twitter, discord, ms")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            ui.label(RichText::new("Worker").size(12.0).color(Colors::text_muted(t)));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.snowflake_worker)
+                    .desired_width(60.0)
+                    .font(FontId::monospace(13.0))
+                    .hint_text("0")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+        });
+
+        ui.add_space(20.0);
+
+        // Generator buttons - modern grid
+        ui.horizontal(|ui| {
+            if self.gen_button(ui, &format!("UUID v{}", self.uuid_version), Colors::ACCENT) {
+                let version = self.uuid_version;
+                let namespace = Some(self.uuid_namespace.trim().to_string()).filter(|n| !n.is_empty());
+                let name = Some(self.uuid_name.clone()).filter(|n| !n.is_empty());
+                self.gen_many(format!("UUID v{}", version), |src| generate::uuid(src, version, namespace.as_deref(), name.as_deref()));
+            }
+            if self.gen_button(ui, "Password", Colors::GREEN) {
+                let len = self.gen_len.parse().unwrap_or(16);
+                let policy = generate::PasswordPolicy { length: len, ..self.pass_policy.clone() }.one_of_each();
+                self.gen_many(format!("{}-char password", len), |src| policy.generate(src));
+                self.append_entropy(policy.entropy());
+            }
+            if self.gen_button(ui, "Passphrase", Colors::GREEN) {
+                let options = self.passphrase.clone();
+                let list = generate::default_wordlist();
+                let mut entropy = 0.0;
+                self.gen_many(format!("{}-word passphrase", options.words), |src| {
+                    generate::passphrase(src, &list, &options).map(|(phrase, bits)| {
+                        entropy = bits;
+                        phrase
+                    })
+                });
+                self.append_entropy(entropy);
+            }
+            if self.gen_button(ui, "Alphanumeric", Colors::AMBER) {
+                let len = self.gen_len.parse().unwrap_or(16);
+                let policy = generate::PasswordPolicy { length: len, ..self.pass_policy.clone() }.symbols(false).one_of_each();
+                self.gen_many(format!("{}-char alphanum", len), |src| policy.generate(src));
+                self.append_entropy(policy.entropy());
+            }
+        });
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            if self.gen_button(ui, "Random Hex", Colors::PURPLE) {
+                let len = self.gen_len.parse().unwrap_or(16);
+                self.gen_many(format!("{} bytes hex", len), |src| Ok(generate::random_hex(src, len)));
+            }
+            if self.gen_button(ui, "Random Base64", Colors::BLUE) {
+                let len = self.gen_len.parse().unwrap_or(16);
+                self.gen_many(format!("{} bytes base64", len), |src| Ok(generate::random_base64(src, len)));
+            }
+        });
+
+        ui.add_space(8.0);
+
+        ui.horizontal_wrapped(|ui| {
+            let len = self.gen_len.parse().unwrap_or(21);
+            if self.gen_button(ui, "ULID", Colors::ACCENT_SOFT) {
+                self.gen_many("ULID".to_string(), |src| Ok(generate::ulid(src)));
+            }
+            if self.gen_button(ui, "KSUID", Colors::ACCENT_SOFT) {
+                self.gen_many("KSUID".to_string(), |src| Ok(generate::ksuid(src)));
+            }
+            if self.gen_button(ui, "NanoID", Colors::ACCENT_SOFT) {
+                self.gen_many("NanoID".to_string(), |src| generate::nanoid(src, len, None));
+            }
+            if self.gen_button(ui, "CUID2", Colors::ACCENT_SOFT) {
+                self.gen_many("CUID2".to_string(), |src| generate::cuid2(src, len.clamp(2, 32)));
+            }
+            if self.gen_button(ui, "Snowflake", Colors::ACCENT_SOFT) {
+                let worker = self.snowflake_worker.trim().parse().unwrap_or(0);
+                match self.snowflake_epoch_ms() {
+                    Ok(epoch) => self.gen_many("Snowflake".to_string(), |src| generate::snowflake(src, epoch, worker)),
+                    Err(e) => self.status = Some((e, true)),
+                }
+            }
+            if self.gen_button(ui, "ObjectId", Colors::ACCENT_SOFT) {
+                self.gen_many("ObjectId".to_string(), |src| Ok(generate::object_id(src)));
+            }
+        });
+
+        ui.add_space(8.0);
+
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.gen_regex)
+                    .desired_width(240.0)
+                    .font(FontId::monospace(14.0))
+                    .hint_text("[A-Z]{4}-[0-9]{4}")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            if self.gen_button(ui, "From Regex", Colors::BLUE) {
+                match generate::RegexPattern::new(&self.gen_regex, false) {
+                    Ok(pattern) => self.gen_many("Regex match".to_string(), |src| pattern.generate(src)),
+                    Err(e) => self.status = Some((e, true)),
+                }
+            }
+        });
+
+        ui.add_space(24.0);
+
+        // Output
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Output").size(12.0).color(Colors::text_muted(t)));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.add(
+                    egui::Button::new(RichText::new("Save to file").size(12.0).color(Colors::text_secondary(t)))
+                        .fill(Colors::bg_card(t))
+                        .stroke(Stroke::new(1.0, Colors::border(t)))
+                        .rounding(Rounding::same(6.0))
+                ).clicked() && !self.output.is_empty() {
+                    self.save_generated();
+                }
+            });
+        });
+        ui.add_space(6.0);
+
+        egui::Frame::none()
+            .fill(Colors::bg_input(t))
+            .rounding(Rounding::same(8.0))
+            .stroke(Stroke::new(1.0, Colors::border(t)))
+            .inner_margin(12.0)
+            .show(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.output)
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(3)
+                        .font(FontId::monospace(13.0))
+                        .frame(false)
+                );
+            });
+
+        ui.add_space(12.0);
+        self.action_bar(ui, false);
+
+        // Decode whatever ID is in the output, generated or pasted
+        let output = self.output.trim();
+        let report = match generate::uuid_inspect(output, None) {
+            Ok(report) if uuid::Uuid::parse_str(output).is_ok() => Some(("UUID", report.summary())),
+            _ => self
+                .snowflake_epoch_ms()
+                .and_then(|epoch| generate::id_inspect(output, epoch))
+                .ok()
+                .map(|report| (report.kind, report.summary())),
+        };
+        if let Some((title, summary)) = report {
+            self.report_grid(ui, title, &summary);
+        } else if !output.is_empty() && !output.contains('\n') {
+            if self.strength.as_ref().map_or(true, |(text, _)| text != output) {
+                self.strength = Some((output.to_string(), strength::estimate(output)));
+            }
+            if let Some((_, result)) = &self.strength {
+                self.strength_meter(ui, result);
+            }
+        }
+    }
+
+    /// Add "(N bits)" to a successful generation status
+    fn append_entropy(&mut self, bits: f64) {
+        if let Some((status, false)) = &mut self.status {
+            status.push_str(&format!(" ({:.0} bits)", bits));
+        }
+    }
+
+    /// Score bar with crack time and the main warning
+    fn strength_meter(&self, ui: &mut egui::Ui, result: &strength::Strength) {
+        let t = self.theme;
+        let color = [Colors::RED, Colors::RED, Colors::AMBER, Colors::GREEN, Colors::ACCENT][result.score as usize];
+        ui.add_space(16.0);
+        ui.label(RichText::new("Strength").size(12.0).color(Colors::text_muted(t)));
+        ui.add_space(6.0);
+        ui.add(
+            egui::ProgressBar::new((result.score as f32 + 1.0) / 5.0)
+                .fill(color)
+                .desired_height(8.0)
+                .rounding(Rounding::same(4.0))
+        );
+        ui.add_space(4.0);
+        ui.label(
+            RichText::new(format!(
+                "{} · 10^{:.0} guesses · {:.0} bits · offline fast hash: {}",
+                result.label(),
+                result.guesses.max(1.0).log10(),
+                result.entropy,
+                result.offline_time()
+            ))
+            .size(12.0)
+            .color(color),
+        );
+        for note in result.warning.iter().chain(result.suggestions.iter()) {
+            ui.label(RichText::new(*note).size(11.0).color(Colors::text_secondary(t)));
+        }
+    }
+
+    /// Fill the output with Count values in the chosen format
+    fn gen_many(&mut self, label: String, mut next: impl FnMut(&mut generate::Source) -> Result<String, String>) {
+        let count = self.gen_count.trim().parse().unwrap_or(1).clamp(1, 100_000);
+        let seed = match self.gen_seed.trim() {
+            "" => None,
+            seed => match seed.parse::<u64>() {
+                Ok(seed) => Some(seed),
+                Err(_) => {
+                    self.status = Some(("Seed must be a whole number (0 to 2^64-1)".into(), true));
+                    return;
+                }
+            },
+        };
+        let mut src = generate::Source::new(seed);
+        match generate::generate_many(count, self.gen_unique, || next(&mut src)) {
+            Ok(values) => {
+                self.output = generate::format_list(&values, self.gen_format);
+                let mut status = if count == 1 { label } else { format!("{} × {}", count, label) };
+                if seed.is_some() {
+                    status.push_str(" · seeded, not secret");
+                }
+                self.status = Some((status, false));
+            }
+            Err(e) => self.status = Some((e, true)),
+        }
+    }
+
+    fn save_generated(&mut self) {
+        let name = format!("generated.{}", self.gen_format.extension());
+        if let Some(path) = rfd::FileDialog::new().set_file_name(name).save_file() {
+            self.status = Some(match std::fs::write(&path, &self.output) {
+                Ok(()) => (format!("Saved to {}", path.display()), false),
+                Err(e) => (format!("Save failed: {}", e), true),
+            });
+        }
+    }
+
+    fn snowflake_epoch_ms(&self) -> Result<i64, String> {
+        match self.snowflake_epoch.trim() {
+            "" => generate::snowflake_epoch("twitter"),
+            epoch => generate::snowflake_epoch(epoch),
+        }
+    }
+
+    /// `Label: value` summary lines as a two-column grid
+    fn report_grid(&self, ui: &mut egui::Ui, title: &str, summary: &str) {
+        let t = self.theme;
+        ui.add_space(20.0);
+        ui.label(RichText::new(title).size(12.0).color(Colors::text_muted(t)));
+        ui.add_space(6.0);
+        egui::Grid::new(("report", title)).striped(true).spacing(Vec2::new(14.0, 4.0)).show(ui, |ui| {
+            for line in summary.lines() {
+                let (label, value) = line.split_once(':').unwrap_or((line, ""));
+                ui.label(RichText::new(label).size(12.0).color(Colors::text_secondary(t)));
+                ui.label(RichText::new(value.trim()).size(12.0).monospace().color(Colors::text_primary(t)));
+                ui.end_row();
+            }
+        });
+    }
+
+    fn gen_button(&mut self, ui: &mut egui::Ui, label: &str, color: Color32) -> bool {
+        ui.add(
+            egui::Button::new(RichText::new(label).size(12.0).color(Colors::text_on_accent(self.theme)))
+                .fill(color.gamma_multiply(0.8))
+                .rounding(Rounding::same(8.0))
+                .min_size(Vec2::new(110.0, 36.0))
+        ).clicked()
+    }
+
+    // ========================================================================
+    // CIPHER PANEL
+    // ========================================================================
+
+    fn cipher_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.theme;
+        ui.label(RichText::new("Cipher").size(12.0).color(Colors::text_muted(t)));
+        ui.add_space(8.0);
+
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+            self.cipher_chip(ui, "ROT13", CipherOp::Rot13);
+            self.cipher_chip(ui, "ROT-N", CipherOp::RotN);
+            self.cipher_chip(ui, "Atbash", CipherOp::Atbash);
+            self.cipher_chip(ui, "Vigenère", CipherOp::Vigenere);
+            self.cipher_chip(ui, "Affine", CipherOp::Affine);
+            self.cipher_chip(ui, "XOR", CipherOp::Xor);
+            self.cipher_chip(ui, "Crack ROT", CipherOp::CrackRot);
+            self.cipher_chip(ui, "Crack XOR", CipherOp::CrackXor);
+        });
+
+        let key_hint = match self.cipher_op {
+            CipherOp::RotN => Some("shift, e.g. 3"),
+            CipherOp::Vigenere => Some("key letters"),
+            CipherOp::Affine => Some("a,b e.g. 5,8"),
+            CipherOp::Xor => Some("key text, or 0x.. for hex"),
+            _ => None,
+        };
+        if let Some(hint) = key_hint {
+            ui.add_space(12.0);
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Key").size(12.0).color(Colors::text_muted(t)));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.cipher_key)
+                        .desired_width(200.0)
+                        .font(FontId::monospace(13.0))
+                        .hint_text(hint)
+                        .margin(Margin::symmetric(12.0, 8.0))
+                );
+                if matches!(self.cipher_op, CipherOp::RotN | CipherOp::Vigenere | CipherOp::Affine) {
+                    ui.checkbox(&mut self.cipher_decrypt, RichText::new("Decrypt").size(12.0).color(Colors::text_secondary(t)));
+                }
+            });
+        }
+
+        ui.add_space(20.0);
+        self.io_section(ui, true);
+    }
+
+    fn cipher_chip(&mut self, ui: &mut egui::Ui, label: &str, op: CipherOp) {
+        let t = self.theme;
+        let active = self.cipher_op == op;
+        let bg = if active { Colors::AMBER.gamma_multiply(0.2) } else { Colors::bg_card(t) };
+        let text = if active { Colors::AMBER } else { Colors::text_secondary(t) };
+        let stroke = if active { Stroke::new(1.0, Colors::AMBER.gamma_multiply(0.5)) } else { Stroke::NONE };
+
+        if ui.add(
+            egui::Button::new(RichText::new(label).size(12.0).color(text))
+                .fill(bg)
+                .stroke(stroke)
+                .rounding(Rounding::same(16.0))
+                .min_size(Vec2::new(0.0, 30.0))
+        ).clicked() {
+            self.cipher_op = op;
+        }
+    }
+
+    fn cipher_transform(&self, input: &str) -> Result<String, String> {
+        let key = self.cipher_key.trim();
+        let crack_table = |c: Vec<cipher::Candidate>| {
+            c.iter().take(8).map(|c| format!("{:<6} {:>6.2}  {}", c.key, c.score, c.plaintext)).collect::<Vec<_>>().join("\n")
+        };
+        match self.cipher_op {
+            CipherOp::Rot13 => Ok(cipher::rot13(input)),
+            CipherOp::RotN => {
+                let shift: i32 = key.parse().map_err(|_| "Shift must be a number".to_string())?;
+                Ok(cipher::rot(input, if self.cipher_decrypt { -shift } else { shift }))
+            }
+            CipherOp::Atbash => Ok(cipher::atbash(input)),
+            CipherOp::Vigenere if self.cipher_decrypt => cipher::vigenere_decrypt(input, key),
+            CipherOp::Vigenere => cipher::vigenere_encrypt(input, key),
+            CipherOp::Affine => {
+                let (a, b) = key.split_once(',')
+                    .and_then(|(a, b)| Some((a.trim().parse().ok()?, b.trim().parse().ok()?)))
+                    .ok_or_else(|| "Affine key must be a,b".to_string())?;
+                if self.cipher_decrypt { cipher::affine_decrypt(input, a, b) } else { cipher::affine_encrypt(input, a, b) }
+            }
+            CipherOp::Xor => {
+                let k = if key.starts_with("0x") { encode::hex_decode_bytes(key)? } else { key.as_bytes().to_vec() };
+                cipher::xor(input.as_bytes(), &k).map(hex::encode)
+            }
+            CipherOp::CrackRot => Ok(crack_table(cipher::brute_force_rot(input))),
+            CipherOp::CrackXor => Ok(crack_table(cipher::brute_force_xor(&encode::hex_decode_bytes(input)?))),
+        }
+    }
+
+    // ========================================================================
+    // JWT PANEL
+    // ========================================================================
+
+    fn jwt_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.theme;
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.jwt_sign, RichText::new("Sign claims").size(12.0).color(Colors::text_secondary(t)));
+            if self.jwt_sign {
+                ui.add_space(8.0);
+                ui.label(RichText::new("Algorithm").size(12.0).color(Colors::text_muted(t)));
+                if self.jwt_alg.is_empty() {
+                    self.jwt_alg = "HS256".to_string();
+                }
+                egui::ComboBox::from_id_salt("jwt_alg")
+                    .selected_text(&self.jwt_alg)
+                    .show_ui(ui, |ui| {
+                        for alg in ["HS256", "HS384", "HS512", "RS256", "RS384", "RS512", "PS256", "ES256", "ES384", "EdDSA"] {
+                            ui.selectable_value(&mut self.jwt_alg, alg.to_string(), alg);
+                        }
+                    });
+            }
+        });
+
+        ui.add_space(12.0);
+        ui.horizontal(|ui| {
+            let label = if self.jwt_sign { "Secret / private key" } else { "Secret / public key" };
+            ui.label(RichText::new(label).size(12.0).color(Colors::text_muted(t)));
+            let is_pem = self.jwt_key.starts_with("-----");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.jwt_key)
+                    .desired_width(220.0)
+                    .font(FontId::monospace(13.0))
+                    .hint_text(if self.jwt_sign { "required" } else { "optional, to verify" })
+                    .password(!is_pem)
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            if ui.add(
+                egui::Button::new(RichText::new("PEM...").size(12.0).color(Colors::text_primary(t)))
+                    .fill(Colors::bg_card(t))
+                    .stroke(Stroke::new(1.0, Colors::border(t)))
+                    .rounding(Rounding::same(6.0))
+            ).clicked() {
+                if let Some(p) = rfd::FileDialog::new().add_filter("PEM", &["pem", "pub", "key"]).pick_file() {
+                    match std::fs::read_to_string(&p) {
+                        Ok(pem) => self.jwt_key = pem,
+                        Err(e) => self.status = Some((format!("Cannot read key: {}", e), true)),
+                    }
+                }
+            }
+        });
+
+        ui.add_space(20.0);
+        self.io_section(ui, true);
+    }
+
+    fn jwt_transform(&mut self, input: &str) -> Result<String, String> {
+        let key = self.jwt_key.as_bytes();
+        if self.jwt_sign {
+            if key.is_empty() {
+                return Err("Enter a secret or load a PEM private key".into());
+            }
+            return jwt::sign(input, &self.jwt_alg, key);
+        }
+
+        let report = jwt::decode(input)?;
+        let mut out = report.summary();
+        if !key.is_empty() {
+            let key = if self.jwt_key.trim_start().starts_with("-----BEGIN") {
+                jwt::VerifyKey::Pem(key)
+            } else {
+                jwt::VerifyKey::Secret(key)
+            };
+            let verified = jwt::verify(input, key)?;
+            out.push_str(if verified { "\nVERIFIED:  signature OK" } else { "\nVERIFIED:  signature MISMATCH" });
+            self.status = Some((report.status(chrono::Utc::now().timestamp()), !verified));
+        }
+        Ok(out)
+    }
+
+    // ========================================================================
+    // DATA PANEL
+    // ========================================================================
+
+    fn data_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.theme;
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+            ui.label(RichText::new("From").size(12.0).color(Colors::text_muted(t)));
+            for format in serial::FORMATS.into_iter().filter(|f| *f != serial::Format::CborDiag) {
+                if self.data_chip(ui, format, self.data_from == format) {
+                    self.data_from = format;
+                }
+            }
+        });
+        ui.add_space(8.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+            ui.label(RichText::new("To").size(12.0).color(Colors::text_muted(t)));
+            ui.add_space(14.0);
+            for format in serial::FORMATS {
+                if self.data_chip(ui, format, self.data_to == format) {
+                    self.data_to = format;
+                }
+            }
+        });
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.data_sort, RichText::new("Sort keys").size(12.0).color(Colors::text_secondary(t)));
+            if self.data_to == serial::Format::Json {
+                ui.checkbox(&mut self.data_minify, RichText::new("Minify").size(12.0).color(Colors::text_secondary(t)));
+            }
+        });
+
+        ui.add_space(20.0);
+        self.io_section(ui, true);
     }
 
-    fn hash_chip(&mut self, ui: &mut egui::Ui, label: &str, algo: HashAlgo, color: Color32) {
+    // ========================================================================
+    // INSPECT PANEL
+    // ========================================================================
+
+    fn inspect_panel(&mut self, ui: &mut egui::Ui) {
         let t = self.theme;
-        let active = self.hash_algo == algo;
-        let bg = if active { color.gamma_multiply(0.2) } else { Colors::bg_card(t) };
-        let text = if active { color } else { Colors::text_secondary(t) };
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+            self.inspect_chip(ui, "Characters", InspectOp::Chars);
+            for form in unicode::FORMS {
+                self.inspect_chip(ui, form.name(), InspectOp::Normalize(form));
+            }
+            ui.add_space(12.0);
+            self.inspect_chip(ui, "Encode → hex", InspectOp::Encode);
+            self.inspect_chip(ui, "Decode hex", InspectOp::Decode);
+        });
+        if matches!(self.inspect_op, InspectOp::Encode | InspectOp::Decode) {
+            ui.add_space(8.0);
+            ui.horizontal_wrapped(|ui| {
+                ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+                ui.label(RichText::new("Encoding").size(12.0).color(Colors::text_muted(t)));
+                for (i, enc) in unicode::ENCODINGS.iter().enumerate() {
+                    let active = self.inspect_enc == i;
+                    let color = if active { Colors::PURPLE } else { Colors::text_secondary(t) };
+                    if ui.selectable_label(active, RichText::new(enc.name()).size(12.0).color(color)).clicked() {
+                        self.inspect_enc = i;
+                    }
+                }
+            });
+        }
+
+        ui.add_space(20.0);
+        self.io_section(ui, true);
+
+        if self.inspect_op == InspectOp::Chars && !self.inspect_chars.is_empty() {
+            ui.add_space(20.0);
+            ui.label(RichText::new("Characters").size(12.0).color(Colors::text_muted(t)));
+            ui.add_space(6.0);
+            egui::Grid::new("inspect_chars").striped(true).spacing(Vec2::new(14.0, 4.0)).show(ui, |ui| {
+                for header in ["Char", "Code", "UTF-8", "UTF-16", "Cat", "Name", ""] {
+                    ui.label(RichText::new(header).size(11.0).color(Colors::text_muted(t)));
+                }
+                ui.end_row();
+                for c in &self.inspect_chars {
+                    let color = if c.warning.is_some() { Colors::RED } else { Colors::text_primary(t) };
+                    let cell = |ui: &mut egui::Ui, text: String| ui.label(RichText::new(text).size(12.0).monospace().color(color));
+                    cell(ui, c.display());
+                    cell(ui, c.code_point());
+                    cell(ui, c.utf8_hex());
+                    cell(ui, c.utf16_hex());
+                    cell(ui, c.category.to_string());
+                    cell(ui, c.name.clone());
+                    cell(ui, c.warning.clone().unwrap_or_default());
+                    ui.end_row();
+                }
+            });
+        }
+    }
 
+    fn inspect_chip(&mut self, ui: &mut egui::Ui, label: &str, op: InspectOp) {
+        let t = self.theme;
+        let active = self.inspect_op == op;
+        let bg = if active { Colors::PURPLE.gamma_multiply(0.2) } else { Colors::bg_card(t) };
+        let text = if active { Colors::PURPLE } else { Colors::text_secondary(t) };
+        let stroke = if active { Stroke::new(1.0, Colors::PURPLE.gamma_multiply(0.5)) } else { Stroke::NONE };
         if ui.add(
-            egui::Button::new(RichText::new(label).size(11.0).color(text))
+            egui::Button::new(RichText::new(label).size(12.0).color(text))
                 .fill(bg)
-                .rounding(Rounding::same(14.0))
-                .min_size(Vec2::new(0.0, 28.0))
+                .stroke(stroke)
+                .rounding(Rounding::same(16.0))
+                .min_size(Vec2::new(0.0, 30.0))
         ).clicked() {
-            self.hash_algo = algo;
+            self.inspect_op = op;
+        }
+    }
+
+    /// Characters view lists flagged characters; the grid below shows all of them
+    fn inspect_transform(&mut self) -> Result<String, String> {
+        // Whitespace matters here, so use the raw input
+        let input = self.input.clone();
+        let encoding = unicode::ENCODINGS[self.inspect_enc.min(unicode::ENCODINGS.len() - 1)];
+        self.inspect_chars.clear();
+        match self.inspect_op {
+            InspectOp::Chars => {
+                let chars = unicode::inspect(&input);
+                let mut out = unicode::summary(&input, &chars);
+                for c in chars.iter().filter(|c| c.warning.is_some()) {
+                    out.push_str(&format!(
+                        "\n  @{} {} {}: {}",
+                        c.offset,
+                        c.code_point(),
+                        c.name,
+                        c.warning.as_deref().unwrap_or_default()
+                    ));
+                }
+                self.inspect_chars = chars;
+                Ok(out)
+            }
+            InspectOp::Normalize(form) => Ok(form.apply(&input)),
+            InspectOp::Encode => encoding.encode(&input).map(hex::encode),
+            InspectOp::Decode => encode::hex_decode_bytes(&input).and_then(|bytes| encoding.decode(&bytes)),
         }
     }
 
     // ========================================================================
-    // GENERATE PANEL
+    // TIME PANEL
     // ========================================================================
 
-    fn generate_panel(&mut self, ui: &mut egui::Ui) {
+    fn time_panel(&mut self, ui: &mut egui::Ui) {
         let t = self.theme;
-        ui.horizontal(|ui| {
-            ui.label(RichText::new("Length").size(12.0).color(Colors::text_muted(t)));
-            ui.add_space(8.0);
-
-            ui.add(
-                egui::TextEdit::singleline(&mut self.gen_len)
-                    .desired_width(70.0)
-                    .font(FontId::monospace(14.0))
-                    .margin(Margin::symmetric(12.0, 8.0))
-            );
-
-            if self.gen_len.is_empty() {
-                self.gen_len = "16".to_string();
+        ui.label(RichText::new("Read numbers as").size(12.0).color(Colors::text_muted(t)));
+        ui.add_space(8.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::new(6.0, 6.0);
+            for kind in time::KINDS {
+                let label = if kind == time::Kind::Auto { "Auto" } else { kind.label() };
+                if self.time_chip(ui, label, self.time_from == kind) {
+                    self.time_from = kind;
+                }
             }
         });
+        ui.add_space(8.0);
+        if self.time_chip(ui, "Now", false) {
+            self.input = "now".to_string();
+            self.transform();
+        }
 
         ui.add_space(20.0);
+        self.io_section(ui, true);
+    }
 
-        // Generator buttons - modern grid
-        ui.horizontal(|ui| {
-            if self.gen_button(ui, "UUID v4", Colors::ACCENT) {
-                self.output = generate::uuid_v4();
-                self.status = Some(("UUID generated".into(), false));
-            }
-            if self.gen_button(ui, "Password", Colors::GREEN) {
-                let len = self.gen_len.parse().unwrap_or(16);
-                self.output = generate::strong_password(len);
-                self.status = Some((format!("{}-char password", len), false));
-            }
-            if self.gen_button(ui, "Alphanumeric", Colors::AMBER) {
-                let len = self.gen_len.parse().unwrap_or(16);
-                self.output = generate::alphanum_password(len);
-                self.status = Some((format!("{}-char alphanum", len), false));
-            }
-        });
-
-        ui.add_space(8.0);
-
-        ui.horizontal(|ui| {
-            if self.gen_button(ui, "Random Hex", Colors::PURPLE) {
-                let len = self.gen_len.parse().unwrap_or(16);
-                self.output = generate::random_hex(len);
-                self.status = Some((format!("{} bytes hex", len), false));
-            }
-            if self.gen_button(ui, "Random Base64", Colors::BLUE) {
-                let len = self.gen_len.parse().unwrap_or(16);
-                self.output = generate::random_base64(len);
-                self.status = Some((format!("{} bytes base64", len), false));
-            }
-        });
+    fn time_chip(&self, ui: &mut egui::Ui, label: &str, active: bool) -> bool {
+        let t = self.theme;
+        let bg = if active { Colors::RED.gamma_multiply(0.2) } else { Colors::bg_card(t) };
+        let text = if active { Colors::RED } else { Colors::text_secondary(t) };
+        let stroke = if active { Stroke::new(1.0, Colors::RED.gamma_multiply(0.5)) } else { Stroke::NONE };
+        ui.add(
+            egui::Button::new(RichText::new(label).size(12.0).color(text))
+                .fill(bg)
+                .stroke(stroke)
+                .rounding(Rounding::same(16.0))
+                .min_size(Vec2::new(0.0, 30.0))
+        ).clicked()
+    }
 
-        ui.add_space(24.0);
+    fn data_chip(&self, ui: &mut egui::Ui, format: serial::Format, active: bool) -> bool {
+        let t = self.theme;
+        let accent = Colors::ACCENT_SOFT;
+        let bg = if active { accent.gamma_multiply(0.2) } else { Colors::bg_card(t) };
+        let text = if active { accent } else { Colors::text_secondary(t) };
+        let stroke = if active { Stroke::new(1.0, accent.gamma_multiply(0.5)) } else { Stroke::NONE };
+        ui.add(
+            egui::Button::new(RichText::new(format.name()).size(12.0).color(text))
+                .fill(bg)
+                .stroke(stroke)
+                .rounding(Rounding::same(16.0))
+                .min_size(Vec2::new(0.0, 30.0))
+        ).clicked()
+    }
 
-        // Output
-        ui.label(RichText::new("Output").size(12.0).color(Colors::text_muted(t)));
+    /// JSONPath / jq box; re-runs on every keystroke but keeps the last good output on errors
+    fn query_box(&mut self, ui: &mut egui::Ui) {
+        let t = self.theme;
+        ui.label(RichText::new("Query").size(12.0).color(Colors::text_muted(t)));
         ui.add_space(6.0);
 
+        let mut changed = false;
         egui::Frame::none()
             .fill(Colors::bg_input(t))
             .rounding(Rounding::same(8.0))
             .stroke(Stroke::new(1.0, Colors::border(t)))
             .inner_margin(12.0)
             .show(ui, |ui| {
-                ui.add(
-                    egui::TextEdit::multiline(&mut self.output)
+                changed = ui.add(
+                    egui::TextEdit::singleline(&mut self.data_query)
                         .desired_width(f32::INFINITY)
-                        .desired_rows(3)
                         .font(FontId::monospace(13.0))
+                        .hint_text("$.items[?(@.price < 10)].name   or   .items | map(.name)")
                         .frame(false)
-                );
+                ).changed();
             });
 
-        ui.add_space(12.0);
-        self.action_bar(ui, false);
+        if changed && !self.input.trim().is_empty() {
+            match self.data_transform(self.input.trim()) {
+                Ok(out) => {
+                    self.output = out;
+                    self.status = Some(("Done".into(), false));
+                }
+                Err(e) => self.status = Some((e, true)),
+            }
+        }
     }
 
-    fn gen_button(&mut self, ui: &mut egui::Ui, label: &str, color: Color32) -> bool {
-        ui.add(
-            egui::Button::new(RichText::new(label).size(12.0).color(Colors::text_on_accent(self.theme)))
-                .fill(color.gamma_multiply(0.8))
-                .rounding(Rounding::same(8.0))
-                .min_size(Vec2::new(110.0, 36.0))
-        ).clicked()
+    /// Binary formats are entered and shown as hex (base64 input also works)
+    fn data_transform(&self, input: &str) -> Result<String, String> {
+        let data = if self.data_from.is_binary() {
+            encode::binary_input(input.as_bytes())?
+        } else {
+            input.as_bytes().to_vec()
+        };
+        let options = structured::FmtOptions { minify: self.data_minify, sort_keys: self.data_sort, indent: 2 };
+        let out = if self.data_query.trim().is_empty() {
+            serial::convert_with(&data, self.data_from, self.data_to, options)?
+        } else {
+            let mut results = query::query(&serial::to_json(&data, self.data_from)?, &self.data_query)?;
+            let value = if results.len() == 1 { results.remove(0) } else { serde_json::Value::Array(results) };
+            serial::write_value(&value, self.data_to, options)?
+        };
+        if self.data_to.is_binary() {
+            Ok(hex::encode(out))
+        } else {
+            String::from_utf8(out).map_err(|e| format!("UTF-8 error: {}", e))
+        }
+    }
+
+    /// Colour JSON-like text: keys, strings, numbers and literals
+    fn highlight(text: &str, t: Theme) -> LayoutJob {
+        let mut job = LayoutJob::default();
+        let mut push = |s: &str, color: Color32| {
+            job.append(s, 0.0, TextFormat { font_id: FontId::monospace(13.0), color, ..Default::default() });
+        };
+        let mut rest = text;
+        while let Some(c) = rest.chars().next() {
+            let (len, color) = match c {
+                '"' | '\'' => {
+                    let mut escaped = false;
+                    let end = rest[1..]
+                        .char_indices()
+                        .find(|&(_, ch)| {
+                            let done = ch == c && !escaped;
+                            escaped = ch == '\\' && !escaped;
+                            done
+                        })
+                        .map_or(rest.len(), |(i, _)| i + 2);
+                    let is_key = rest[end..].trim_start().starts_with(':');
+                    (end, if is_key { Colors::ACCENT } else { Colors::GREEN })
+                }
+                '-' | '0'..='9' => {
+                    let end = rest
+                        .char_indices()
+                        .skip(1)
+                        .find(|&(_, ch)| !(ch.is_ascii_alphanumeric() || matches!(ch, '.' | '+' | '-')))
+                        .map_or(rest.len(), |(i, _)| i);
+                    (end, Colors::AMBER)
+                }
+                c if c.is_alphabetic() => {
+                    let end = rest
+                        .char_indices()
+                        .find(|&(_, ch)| !ch.is_alphanumeric() && ch != '_')
+                        .map_or(rest.len(), |(i, _)| i);
+                    let color = match &rest[..end] {
+                        "true" | "false" | "null" | "undefined" | "NaN" | "Infinity" => Colors::PURPLE,
+                        "h" if rest[end..].starts_with('\'') => Colors::GREEN,
+                        _ => Colors::text_primary(t),
+                    };
+                    (end, color)
+                }
+                c => (c.len_utf8(), Colors::text_secondary(t)),
+            };
+            push(&rest[..len], color);
+            rest = &rest[len..];
+        }
+        job
     }
 
     // ========================================================================
@@ -582,6 +1614,11 @@ impl App {
 
         ui.add_space(16.0);
 
+        if self.tab == Tab::Data {
+            self.query_box(ui);
+            ui.add_space(12.0);
+        }
+
         ui.label(RichText::new("Output").size(12.0).color(Colors::text_muted(t)));
         ui.add_space(6.0);
 
@@ -591,13 +1628,20 @@ impl App {
             .stroke(Stroke::new(1.0, Colors::border(t)))
             .inner_margin(12.0)
             .show(ui, |ui| {
-                ui.add(
-                    egui::TextEdit::multiline(&mut self.output)
-                        .desired_width(f32::INFINITY)
-                        .desired_rows(3)
-                        .font(FontId::monospace(13.0))
-                        .frame(false)
-                );
+                let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+                    let mut job = Self::highlight(text, t);
+                    job.wrap.max_width = wrap_width;
+                    ui.fonts(|f| f.layout_job(job))
+                };
+                let mut edit = egui::TextEdit::multiline(&mut self.output)
+                    .desired_width(f32::INFINITY)
+                    .desired_rows(3)
+                    .font(FontId::monospace(13.0))
+                    .frame(false);
+                if self.tab == Tab::Data {
+                    edit = edit.layouter(&mut layouter);
+                }
+                ui.add(edit);
             });
 
         ui.add_space(12.0);
@@ -665,6 +1709,146 @@ impl App {
         });
     }
 
+    // ========================================================================
+    // OTP PANEL
+    // ========================================================================
+
+    fn otp_panel(&mut self, ui: &mut egui::Ui) {
+        let t = self.theme;
+        let label = |ui: &mut egui::Ui, text: &str| {
+            ui.label(RichText::new(text).size(12.0).color(Colors::text_muted(t)));
+        };
+        label(ui, "Secret (base32) or otpauth:// URI");
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.otp_secret)
+                    .desired_width(360.0)
+                    .font(FontId::monospace(14.0))
+                    .hint_text("JBSWY3DPEHPK3PXP")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+            if self.gen_button(ui, "New secret", Colors::GREEN) {
+                self.otp_secret = otp::generate_secret(&mut generate::Source::system(), 20);
+                self.status = Some(("Generated a 160-bit secret".into(), false));
+            }
+        });
+        if self.otp_secret.trim_start().starts_with("otpauth://") {
+            match otp::parse_uri(&self.otp_secret) {
+                Ok(auth) => {
+                    self.otp_secret = auth.secret.clone();
+                    self.otp_issuer = auth.issuer.clone().unwrap_or_default();
+                    self.otp_hotp = auth.counter.is_some();
+                    self.otp = auth;
+                    self.status = Some(("Loaded otpauth URI".into(), false));
+                }
+                Err(e) => self.status = Some((e, true)),
+            }
+        }
+
+        ui.add_space(12.0);
+        ui.horizontal_wrapped(|ui| {
+            label(ui, "Digits");
+            ui.add(egui::DragValue::new(&mut self.otp.digits).range(4..=10));
+            ui.add_space(12.0);
+            label(ui, "Algorithm");
+            egui::ComboBox::from_id_salt("otp_algorithm")
+                .selected_text(self.otp.algorithm.name())
+                .show_ui(ui, |ui| {
+                    for algorithm in otp::ALGORITHMS {
+                        ui.selectable_value(&mut self.otp.algorithm, algorithm, algorithm.name());
+                    }
+                });
+            ui.add_space(12.0);
+            ui.checkbox(&mut self.otp_hotp, RichText::new("HOTP").size(12.0).color(Colors::text_secondary(t)));
+            ui.add_space(12.0);
+            if self.otp_hotp {
+                let mut counter = self.otp.counter.unwrap_or(0);
+                label(ui, "Counter");
+                ui.add(egui::DragValue::new(&mut counter));
+                self.otp.counter = Some(counter);
+            } else {
+                label(ui, "Period");
+                ui.add(egui::DragValue::new(&mut self.otp.period).range(1..=3600).suffix("s"));
+                self.otp.counter = None;
+            }
+        });
+
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            label(ui, "Issuer");
+            ui.add(egui::TextEdit::singleline(&mut self.otp_issuer).desired_width(140.0).margin(Margin::symmetric(12.0, 8.0)));
+            ui.add_space(12.0);
+            label(ui, "Account");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.otp.account)
+                    .desired_width(180.0)
+                    .hint_text("user")
+                    .margin(Margin::symmetric(12.0, 8.0))
+            );
+        });
+
+        self.otp.secret = self.otp_secret.trim().to_string();
+        self.otp.issuer = Some(self.otp_issuer.trim().to_string()).filter(|s| !s.is_empty());
+        if self.otp.secret.is_empty() {
+            ui.add_space(20.0);
+            label(ui, "Enter a secret or press New secret to see codes.");
+            return;
+        }
+
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap_or_default();
+        ui.add_space(20.0);
+        match self.otp.code(now.as_secs()) {
+            Ok((code, remaining)) => {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(&code).size(40.0).monospace().strong().color(Colors::GREEN));
+                    ui.add_space(16.0);
+                    if self.gen_button(ui, "Copy", Colors::ACCENT) {
+                        ui.ctx().copy_text(code.clone());
+                        self.status = Some(("Copied code".into(), false));
+                    }
+                    if self.otp_hotp && self.gen_button(ui, "Next counter", Colors::AMBER) {
+                        self.otp.counter = self.otp.counter.map(|c| c.saturating_add(1));
+                    }
+                });
+                if let Some(remaining) = remaining {
+                    // Sub-second progress so the bar moves smoothly
+                    let period_ms = self.otp.period as f32 * 1000.0;
+                    let left_ms = period_ms - (now.as_millis() % (self.otp.period as u128 * 1000)) as f32;
+                    let color = if remaining <= 5 { Colors::RED } else { Colors::GREEN };
+                    ui.add_space(8.0);
+                    ui.add(
+                        egui::ProgressBar::new(left_ms / period_ms)
+                            .desired_width(360.0)
+                            .fill(color.gamma_multiply(0.8))
+                            .text(format!("{}s left", remaining))
+                    );
+                    let next = otp::OtpAuth { counter: None, ..self.otp.clone() }.code(now.as_secs() + remaining);
+                    if let Ok((next, _)) = next {
+                        ui.add_space(6.0);
+                        label(ui, &format!("Next: {}", next));
+                    }
+                    ui.ctx().request_repaint_after(std::time::Duration::from_millis(250));
+                }
+            }
+            Err(e) => {
+                ui.label(RichText::new(e).size(13.0).color(Colors::RED));
+                return;
+            }
+        }
+
+        ui.add_space(20.0);
+        let uri = self.otp.to_uri();
+        label(ui, "otpauth URI");
+        ui.add_space(6.0);
+        ui.add(egui::Label::new(RichText::new(&uri).size(12.0).monospace().color(Colors::text_primary(t))).wrap());
+        ui.add_space(6.0);
+        if self.gen_button(ui, "Copy URI", Colors::ACCENT_SOFT) {
+            ui.ctx().copy_text(uri);
+            self.status = Some(("Copied URI".into(), false));
+        }
+    }
+
     // ========================================================================
     // TRANSFORM LOGIC
     // ========================================================================
@@ -682,6 +1866,48 @@ impl App {
                 EncodeOp::UrlDec => encode::url_decode(input),
                 EncodeOp::HtmlEnc => Ok(encode::html_encode(input)),
                 EncodeOp::HtmlDec => Ok(encode::html_decode(input)),
+                EncodeOp::HexDump => Ok(encode::hexdump(input.as_bytes(), encode::DumpStyle::Xxd, 0)),
+                EncodeOp::BinEnc => Ok(encode::binary_encode(input.as_bytes())),
+                EncodeOp::BinDec => encode::binary_decode(input).and_then(|b|
+                    String::from_utf8(b).map_err(|e| format!("UTF-8 error: {}", e))),
+                EncodeOp::CArray => Ok(encode::c_array_encode(input.as_bytes())),
+                EncodeOp::Asn1 => {
+                    self.asn1_tree.clear();
+                    encode::asn1_input(input.as_bytes())
+                        .and_then(|der| encode::asn1_parse(&der))
+                        .map(|nodes| {
+                            let dump = encode::asn1_dump(&nodes);
+                            self.asn1_tree = nodes;
+                            dump
+                        })
+                }
+                EncodeOp::DataUri => {
+                    if input.starts_with("data:") || input.starts_with("url(") {
+                        encode::data_uri_decode(input).map(|(mime, payload)| {
+                            self.set_preview(&payload);
+                            match String::from_utf8(payload) {
+                                Ok(text) if !mime.starts_with("image/") || mime == "image/svg+xml" => text,
+                                Ok(text) => format!("{}, {} bytes", mime, text.len()),
+                                Err(e) => format!("{}, {} bytes", mime, e.into_bytes().len()),
+                            }
+                        })
+                    } else {
+                        let data = input.as_bytes().to_vec();
+                        self.set_preview(&data);
+                        Ok(encode::data_uri_encode(&data))
+                    }
+                }
+                EncodeOp::Cert => cert::inspect(input.as_bytes())
+                    .map(|infos| infos.iter().map(|i| i.summary()).collect::<Vec<_>>().join("\n\n")),
+                EncodeOp::Auto => {
+                    let d = detect::detect(input.as_bytes());
+                    self.output = match std::str::from_utf8(&d.output) {
+                        Ok(text) if d.kind != "binary" => text.to_string(),
+                        _ => encode::hexdump(&d.output, encode::DumpStyle::Xxd, 0),
+                    };
+                    self.status = Some((d.chain(), false));
+                    return;
+                }
             },
             Tab::Hash => {
                 if let Some(ref p) = self.file {
@@ -700,7 +1926,25 @@ impl App {
                     }
                 }
             }
-            Tab::Generate => return,
+            Tab::Cipher => self.cipher_transform(input),
+            Tab::Data => self.data_transform(input),
+            Tab::Time => time::inspect(input, self.time_from).map(|info| info.summary()),
+            Tab::Inspect => self.inspect_transform(),
+            Tab::Jwt => {
+                let input = input.to_string();
+                self.status = None;
+                match self.jwt_transform(&input) {
+                    Ok(out) => {
+                        self.output = out;
+                        if self.status.is_none() {
+                            self.status = Some(("Done".into(), false));
+                        }
+                    }
+                    Err(e) => { self.output.clear(); self.status = Some((e, true)); }
+                }
+                return;
+            }
+            Tab::Generate | Tab::Otp => return,
         };
 
         match result {
@@ -719,7 +1963,7 @@ impl App {
 
     fn help_panel(&mut self, ui: &mut egui::Ui) {
         let t = self.theme;
-        let sections = ["Quick Start", "Encoding", "Hashing", "Generate", "About"];
+        let sections = ["Quick Start", "Encoding", "Hashing", "Generate", "Cipher", "JWT", "Data", "Time", "OTP", "Inspect", "About"];
 
         // Section tabs
         ui.horizontal(|ui| {
@@ -752,6 +1996,12 @@ impl App {
                         1 => self.help_encoding(ui),
                         2 => self.help_hashing(ui),
                         3 => self.help_generate(ui),
+                        4 => self.help_cipher(ui),
+                        5 => self.help_jwt(ui),
+                        6 => self.help_data(ui),
+                        7 => self.help_time(ui),
+                        8 => self.help_otp(ui),
+                        9 => self.help_inspect(ui),
                         _ => self.help_about(ui),
                     }
                 });
@@ -786,7 +2036,7 @@ impl App {
         self.p(ui, "QX transforms text instantly. 100% offline - your data stays private.");
 
         self.h2(ui, "How to Use");
-        self.p(ui, "1. Select a tab: Encode, Hash, or Generate");
+        self.p(ui, "1. Select a tab: Encode, Hash, Generate, Cipher, or JWT");
         self.p(ui, "2. Choose an operation");
         self.p(ui, "3. Enter text and click Transform");
         self.p(ui, "4. Copy the result");
@@ -816,6 +2066,28 @@ impl App {
         self.h2(ui, "HTML Entities");
         self.p(ui, "Escapes special characters for safe HTML display.");
         self.code(ui, "<div>", "&lt;div&gt;");
+
+        self.h2(ui, "Auto");
+        self.p(ui, "Tries every decoder and peels nested layers, showing the");
+        self.p(ui, "chain it found (e.g. base64 → hex → JSON) in the status line.");
+
+        self.h2(ui, "Certificate");
+        self.p(ui, "Paste a PEM or base64 certificate, CSR or public key to see");
+        self.p(ui, "subject, issuer, SANs, validity, key size and fingerprints.");
+
+        self.h2(ui, "ASN.1");
+        self.p(ui, "Dumps DER/BER (hex, base64 or PEM) as a collapsible tree");
+        self.p(ui, "with tag names, lengths, OID names and decoded values.");
+
+        self.h2(ui, "Data URI");
+        self.p(ui, "Drop an image or font on the Encode tab to get a data: URI with");
+        self.p(ui, "its MIME type. Paste a data URI to decode it and preview images.");
+
+        self.h2(ui, "Binary & Numbers");
+        self.p(ui, "Bytes as binary octets or C arrays. The Number box converts");
+        self.p(ui, "integers between bases; set Bits for two's complement.");
+        self.code(ui, "Hi", "01001000 01101001");
+        self.code(ui, "-1 (8 bits)", "0xff");
     }
 
     fn help_hashing(&self, ui: &mut egui::Ui) {
@@ -847,15 +2119,72 @@ impl App {
         self.p(ui, "• Generate checksums");
     }
 
+    fn help_cipher(&self, ui: &mut egui::Ui) {
+        self.h1(ui, "Ciphers");
+        self.p(ui, "Classical ciphers for CTFs and obfuscated strings. Not encryption!");
+
+        self.h2(ui, "ROT / Atbash");
+        self.code(ui, "Hello", "Uryyb");
+        self.code(ui, "Hello", "Svool");
+
+        self.h2(ui, "Vigenère / Affine");
+        self.p(ui, "Vigenère takes a letter key; Affine takes a,b with a coprime to 26.");
+
+        self.h2(ui, "XOR");
+        self.p(ui, "Key as text or 0x-prefixed hex. Output is hex.");
+
+        self.h2(ui, "Crack");
+        self.p(ui, "Tries every ROT shift or single-byte XOR key (hex input)");
+        self.p(ui, "and ranks candidates by English letter frequency.");
+    }
+
+    fn help_jwt(&self, ui: &mut egui::Ui) {
+        self.h1(ui, "JSON Web Tokens");
+        self.p(ui, "Paste a token and Transform to see its header, payload and dates.");
+
+        self.h2(ui, "Verify");
+        self.p(ui, "Enter the shared secret (HS256/384/512) or load a PEM public");
+        self.p(ui, "key (RS, PS, ES, EdDSA). The status line shows expiry.");
+
+        self.h2(ui, "Sign");
+        self.p(ui, "Tick Sign claims, pick an algorithm, enter JSON claims as input");
+        self.p(ui, "and a secret or PEM private key.");
+    }
+
     fn help_generate(&self, ui: &mut egui::Ui) {
         self.h1(ui, "Generation");
 
-        self.h2(ui, "UUID v4");
-        self.p(ui, "128-bit random identifier. Great for IDs and tokens.");
+        self.h2(ui, "UUID");
+        self.p(ui, "v4 is random; v7 is time-ordered, ideal for database keys.");
+        self.p(ui, "v1/v6 embed a timestamp. v3/v5 hash a namespace (dns, url,");
+        self.p(ui, "oid, x500 or a UUID) and name, so the same input gives the");
+        self.p(ui, "same UUID. v8 is random or SHA-256 of namespace + name.");
+        self.p(ui, "Paste a UUID on the Encode tab to see its version, timestamp,");
+        self.p(ui, "node and base64/base58/integer forms.");
+
+        self.h2(ui, "Other IDs");
+        self.p(ui, "ULID and KSUID sort by creation time; NanoID uses Length;");
+        self.p(ui, "CUID2 is opaque; Snowflake uses the epoch and worker fields;");
+        self.p(ui, "ObjectId is MongoDB's. The output's timestamp and fields are");
+        self.p(ui, "shown below it, for generated or pasted IDs.");
 
         self.h2(ui, "Password");
         self.p(ui, "Secure random password with letters, numbers, symbols.");
         self.p(ui, "Alphanumeric option excludes symbols.");
+        self.p(ui, "Each ticked class appears at least once. Include/Exclude");
+        self.p(ui, "add or remove characters; No look-alikes drops 0 O 1 l I;");
+        self.p(ui, "Max run limits repeats like \"aaa\" (0 = unlimited).");
+
+        self.h2(ui, "Strength");
+        self.p(ui, "Any single-line output (generated or typed) gets a pattern-based");
+        self.p(ui, "meter: score 0-4, guesses, entropy and crack time, with hints");
+        self.p(ui, "about dictionary words, keyboard rows, sequences and dates.");
+        self.p(ui, "The built-in dictionary is small, so treat it as an upper bound.");
+
+        self.h2(ui, "Passphrase");
+        self.p(ui, "Random words from the BIP-39 English list (11 bits each),");
+        self.p(ui, "easy to type on remotes and consoles. Six words give 66 bits;");
+        self.p(ui, "+digit and +symbol add a few more. Entropy shows in the status.");
 
         self.h2(ui, "Random Hex/Base64");
         self.p(ui, "Raw random bytes encoded as hex or base64.");
@@ -863,6 +2192,92 @@ impl App {
 
         self.h2(ui, "Length");
         self.p(ui, "Password: character count. Hex/B64: byte count.");
+
+        self.h2(ui, "Bulk");
+        self.p(ui, "Count generates many values at once, as lines, a JSON array,");
+        self.p(ui, "CSV or a SQL VALUES list. Unique redraws repeats (useful for");
+        self.p(ui, "short lengths). Save to file writes the output.");
+
+        self.h2(ui, "Regex");
+        self.p(ui, "From Regex makes strings matching a pattern, for test data such");
+        self.p(ui, "as [A-Z]{4}-[0-9]{4}. Classes draw from printable ASCII and * or +");
+        self.p(ui, "repeat at most 8 extra times; use qt gen-regex to change either.");
+
+        self.h2(ui, "Seed");
+        self.p(ui, "A seed makes output reproducible for test fixtures: ChaCha20");
+        self.p(ui, "plus a fixed clock, so time-based IDs repeat too. Anyone with");
+        self.p(ui, "the seed can recreate it, so never use seeded values as secrets.");
+    }
+
+    fn help_data(&self, ui: &mut egui::Ui) {
+        self.h1(ui, "Data Formats");
+        self.p(ui, "Converts between JSON, YAML, TOML, XML, CSV, MessagePack, CBOR");
+        self.p(ui, "and BSON. Pick the same format twice to reformat it.");
+
+        self.h2(ui, "Formatting");
+        self.p(ui, "Sort keys orders objects alphabetically; Minify writes JSON on");
+        self.p(ui, "one line. Parse errors show the line and column.");
+
+        self.h2(ui, "Query");
+        self.p(ui, "The query box filters the parsed input as you type. Start with $");
+        self.p(ui, "for JSONPath, anything else is a jq filter (map, select, keys, ...).");
+        self.code(ui, "$..name", "every name at any depth");
+
+        self.h2(ui, "XML & CSV");
+        self.p(ui, "XML attributes become @name keys and text becomes #text. A CSV");
+        self.p(ui, "header row maps to an array of objects.");
+
+        self.h2(ui, "Binary Input");
+        self.p(ui, "Paste MessagePack, CBOR or BSON as hex or base64. Binary output");
+        self.p(ui, "is shown as hex.");
+
+        self.h2(ui, "CBOR Diagnostic");
+        self.p(ui, "The diag target shows CBOR in RFC 8949 notation, keeping tags");
+        self.p(ui, "and byte strings that JSON cannot represent.");
+        self.code(ui, "a1616101", "{\"a\": 1}");
+    }
+
+    fn help_time(&self, ui: &mut egui::Ui) {
+        self.h1(ui, "Time");
+        self.p(ui, "Shows a timestamp as Unix s/ms/µs/ns, UTC and local RFC 3339,");
+        self.p(ui, "RFC 2822, ISO week, FILETIME, Cocoa and .NET ticks.");
+
+        self.h2(ui, "Input");
+        self.p(ui, "Auto picks the Unix unit from the digit count and also reads");
+        self.p(ui, "dates, ISO weeks, UUID v1/v6/v7 and ULIDs. Pick a chip for");
+        self.p(ui, "other epochs such as Windows FILETIME or Discord snowflakes.");
+        self.code(ui, "1697558400", "2023-10-17T16:00:00Z");
+        self.code(ui, "2023-W42-2", "2023-10-17T00:00:00Z");
+    }
+
+    fn help_otp(&self, ui: &mut egui::Ui) {
+        self.h1(ui, "OTP");
+        self.p(ui, "Shows the current two-factor code for a base32 secret, as an");
+        self.p(ui, "authenticator app would, with a live countdown and the next code.");
+
+        self.h2(ui, "Settings");
+        self.p(ui, "Digits, period and algorithm must match the service; most use");
+        self.p(ui, "6 digits, 30 seconds and SHA1. Tick HOTP for counter-based codes.");
+
+        self.h2(ui, "otpauth:// URIs");
+        self.p(ui, "Paste the URI from a QR code into the secret field to load its");
+        self.p(ui, "settings. The URI for the current settings is shown below the code.");
+        self.code(ui, "otpauth://totp/ACME:me?secret=JBSW...", "Issuer ACME, account me");
+    }
+
+    fn help_inspect(&self, ui: &mut egui::Ui) {
+        self.h1(ui, "Inspect");
+        self.p(ui, "Lists every character with its code point, UTF-8 and UTF-16");
+        self.p(ui, "bytes, Unicode name and general category.");
+
+        self.h2(ui, "Warnings");
+        self.p(ui, "Zero-width, bidi-override, invisible format characters and");
+        self.p(ui, "look-alikes of ASCII letters (homoglyphs) are shown in red.");
+        self.code(ui, "pаypal", "U+0430 confusable with \"a\"");
+
+        self.h2(ui, "Normalize & Encode");
+        self.p(ui, "NFC/NFD/NFKC/NFKD rewrite the text. Encode shows its bytes in");
+        self.p(ui, "UTF-16, UTF-32, Latin-1 or Windows-1252; Decode reads hex back.");
     }
 
     fn help_about(&self, ui: &mut egui::Ui) {
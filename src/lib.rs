@@ -7,14 +7,43 @@
 //!
 //! ## Features
 //! - Base64, Hex, URL, HTML encoding/decoding
+//! - ASN.1 DER/BER structure dumps
+//! - Automatic detection of nested encodings
+//! - gzip, zlib, deflate, zstd, brotli, bzip2, xz and lzma compression
+//! - Binary, octal, decimal byte lists and integer base conversion
 //! - MD5, SHA1, SHA256, SHA512 hashing
-//! - UUID and password generation
+//! - JWT decode, verify and sign
+//! - X.509 certificate, CSR and public key inspection
+//! - Schema-less protobuf decoding
+//! - MessagePack, CBOR and BSON conversion to and from JSON
+//! - JSON, YAML, TOML, XML and CSV formatting and conversion
+//! - JSONPath and jq-style queries
+//! - Unix, calendar and other-epoch timestamp conversion
+//! - Unicode inspection, normalization and UTF-16/UTF-32/Latin-1 encodings
+//! - UUID, ULID, KSUID, NanoID, CUID2, Snowflake, ObjectId, password and regex-pattern generation
+//! - zxcvbn-style password strength estimation
+//! - TOTP/HOTP codes and otpauth:// URIs
+//! - ROT-N, Atbash, Vigenère, affine and XOR ciphers with brute force
 
 pub mod transforms;
 
 pub use transforms::encode;
 pub use transforms::hash;
 pub use transforms::generate;
+pub use transforms::strength;
+pub use transforms::otp;
+pub use transforms::number;
+pub use transforms::cipher;
+pub use transforms::detect;
+pub use transforms::compress;
+pub use transforms::jwt;
+pub use transforms::cert;
+pub use transforms::protobuf;
+pub use transforms::serial;
+pub use transforms::structured;
+pub use transforms::query;
+pub use transforms::time;
+pub use transforms::unicode;
 
 /// Library version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -3,10 +3,10 @@
 //! LAZYFROG-kindware.dev | MIT License
 
 use clap::{Parser, Subcommand};
-use std::io::{self, BufRead, IsTerminal};
-use std::path::PathBuf;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 
-use quicktransform::transforms::{encode, hash, generate};
+use quicktransform::transforms::{cert, cipher, compress, detect, encode, hash, generate, jwt, number, otp, protobuf, query, serial, strength, structured, time, unicode};
 use quicktransform::{BRAND, VERSION};
 
 // ============================================================================
@@ -89,6 +89,362 @@ enum Commands {
         input: Option<String>,
     },
 
+    /// Binary encode bytes (01001000 01101001)
+    Bin {
+        /// Text to encode (or pipe via stdin)
+        input: Option<String>,
+    },
+
+    /// Binary decode to raw bytes
+    Bind {
+        /// Binary octets to decode (or pipe via stdin)
+        input: Option<String>,
+    },
+
+    /// Octal encode bytes (110 151)
+    Oct {
+        /// Text to encode (or pipe via stdin)
+        input: Option<String>,
+    },
+
+    /// Octal decode to raw bytes
+    Octd {
+        /// Octal bytes to decode (or pipe via stdin)
+        input: Option<String>,
+    },
+
+    /// Decimal byte list (72 105)
+    Dec {
+        /// Text to encode (or pipe via stdin)
+        input: Option<String>,
+    },
+
+    /// Decimal byte list or C/Rust array decode to raw bytes
+    Decd {
+        /// Byte list to decode (or pipe via stdin)
+        input: Option<String>,
+    },
+
+    /// Bytes as a C array literal ({ 0x48, 0x69 })
+    Array {
+        /// Text to encode (or pipe via stdin)
+        input: Option<String>,
+        /// Rust array syntax ([0x48, 0x69])
+        #[arg(short, long)]
+        rust: bool,
+    },
+
+    /// Convert an integer between bases 2, 8, 10, 16 and 36
+    Num {
+        /// Integer (0x, 0o and 0b prefixes are detected)
+        #[arg(allow_hyphen_values = true)]
+        value: String,
+        /// Input base (2-36)
+        #[arg(short, long)]
+        from: Option<u32>,
+        /// Only print the value in this base (2-36)
+        #[arg(short, long)]
+        to: Option<u32>,
+        /// Bit width for signed / two's complement view (e.g. 8, 32, 64; at most 4096)
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=number::MAX_WIDTH as i64))]
+        width: Option<u32>,
+    },
+
+    /// Detect the encoding and peel nested layers automatically
+    Detect {
+        /// Encoded data (or pipe via stdin)
+        input: Option<String>,
+        /// Write only the decoded bytes (for pipes)
+        #[arg(short, long)]
+        raw: bool,
+        /// List every single-layer decode with its score
+        #[arg(short, long)]
+        all: bool,
+    },
+
+    /// Hex dump a file with offsets and ASCII gutter (xxd layout)
+    Hexdump {
+        /// File to dump (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// Use `hexdump -C` layout instead of xxd
+        #[arg(short = 'C', long)]
+        canonical: bool,
+        /// Start at this byte offset (decimal or 0x hex)
+        #[arg(short, long, value_parser = parse_number)]
+        skip: Option<usize>,
+        /// Only dump this many bytes (decimal or 0x hex)
+        #[arg(short = 'n', long, value_parser = parse_number)]
+        length: Option<usize>,
+        /// Reverse a dump back into raw bytes
+        #[arg(short, long)]
+        reverse: bool,
+    },
+
+    /// Encode a file as a data: URI, or decode one back into a file
+    Datauri {
+        /// File to encode, or a file holding the URI with -d (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// Decode a data URI and write its payload to a file
+        #[arg(short, long)]
+        decode: bool,
+        /// Output file for -d (default: decoded.<ext> from the MIME type); never overwritten
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// List every character with code point, bytes, name and category; flag invisibles
+    Inspect {
+        /// Text to inspect (or pipe via stdin)
+        text: Option<String>,
+        /// Normalize to nfc, nfd, nfkc or nfkd and print the result
+        #[arg(short, long, value_name = "FORM")]
+        normalize: Option<String>,
+        /// Decode the input bytes from this encoding
+        #[arg(short, long, value_name = "ENCODING")]
+        from: Option<String>,
+        /// Input is hex bytes
+        #[arg(short = 'x', long, conflicts_with = "base64")]
+        hex: bool,
+        /// Input is base64 bytes
+        #[arg(short, long)]
+        base64: bool,
+        /// Print the text's bytes (hex) in utf-8, utf-16le/be, utf-32le/be, latin-1 or windows-1252
+        #[arg(short, long, value_name = "ENCODING")]
+        to: Option<String>,
+        /// Write --to bytes raw instead of hex
+        #[arg(short, long)]
+        raw: bool,
+    },
+
+    /// Dump the ASN.1 structure of DER/BER data (raw, PEM, hex or base64)
+    Asn1 {
+        /// File to parse (or pipe via stdin)
+        file: Option<PathBuf>,
+    },
+
+    /// Decode protobuf wire format without a .proto file
+    Protodec {
+        /// Message file, raw, hex or base64 (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// .proto file for field names and types
+        #[arg(short, long, value_name = "FILE")]
+        proto: Option<PathBuf>,
+        /// Root message type in the .proto (default: first declared)
+        #[arg(short, long, requires = "proto")]
+        message: Option<String>,
+    },
+
+    // === CIPHERS ===
+    /// ROT-N / Caesar shift letters (default ROT13)
+    Rot {
+        /// Text to shift (or pipe via stdin)
+        input: Option<String>,
+        /// Shift amount (negative to shift back)
+        #[arg(short = 'n', long, default_value = "13", allow_negative_numbers = true)]
+        shift: i32,
+    },
+
+    /// Atbash cipher (mirror the alphabet)
+    Atbash {
+        /// Text to transform (or pipe via stdin)
+        input: Option<String>,
+    },
+
+    /// Vigenère cipher with a letter key
+    Vigenere {
+        /// Text to transform (or pipe via stdin)
+        input: Option<String>,
+        /// Key letters
+        #[arg(short, long)]
+        key: String,
+        /// Decrypt instead of encrypt
+        #[arg(short, long)]
+        decrypt: bool,
+    },
+
+    /// Affine cipher: E(x) = (a*x + b) mod 26
+    Affine {
+        /// Text to transform (or pipe via stdin)
+        input: Option<String>,
+        /// Multiplier (coprime with 26)
+        #[arg(short, default_value = "5")]
+        a: i32,
+        /// Shift
+        #[arg(short, default_value = "8")]
+        b: i32,
+        /// Decrypt instead of encrypt
+        #[arg(short, long)]
+        decrypt: bool,
+    },
+
+    /// XOR bytes with a repeating key
+    Xor {
+        /// Data to XOR (or pipe via stdin)
+        input: Option<String>,
+        /// Key as text (or hex with --hex-key)
+        #[arg(short, long)]
+        key: String,
+        /// Key is hex (e.g. 2a or 0xde,0xad)
+        #[arg(long)]
+        hex_key: bool,
+        /// Input is hex and output is hex
+        #[arg(short = 'x', long)]
+        hex: bool,
+    },
+
+    /// Brute-force ROT shifts or single-byte XOR keys
+    Crack {
+        /// Ciphertext (or pipe via stdin)
+        input: Option<String>,
+        /// Try single-byte XOR keys instead of ROT shifts
+        #[arg(long)]
+        xor: bool,
+        /// Input is hex (XOR mode)
+        #[arg(short = 'x', long)]
+        hex: bool,
+        /// Number of candidates to show
+        #[arg(short, long, default_value = "5")]
+        top: usize,
+    },
+
+    // === TOKENS ===
+    /// Decode a JWT, or verify / sign one
+    Jwt {
+        /// Token to decode (or pipe via stdin)
+        token: Option<String>,
+        /// Shared secret for HS256/384/512
+        #[arg(short, long, conflicts_with = "key")]
+        secret: Option<String>,
+        /// PEM key file: public key to verify (RS*, PS*, ES*, EdDSA), private key to sign
+        #[arg(short, long)]
+        key: Option<PathBuf>,
+        /// Sign these JSON claims instead of decoding (or a @file)
+        #[arg(long, value_name = "CLAIMS")]
+        sign: Option<String>,
+        /// Signing algorithm (HS256, RS256, ES256, EdDSA, ...)
+        #[arg(short, long, default_value = "HS256")]
+        alg: String,
+        /// Add `iat` and an `exp` this many seconds from now when signing
+        #[arg(long, value_name = "SECONDS")]
+        exp: Option<i64>,
+    },
+
+    /// Inspect X.509 certificates, CSRs and public keys (PEM or DER)
+    Cert {
+        /// Certificate file, or `-` / omit for stdin
+        file: Option<PathBuf>,
+        /// Convert PEM to raw DER
+        #[arg(long, conflicts_with_all = ["pem", "split"])]
+        der: bool,
+        /// Convert DER to PEM
+        #[arg(long, conflicts_with = "split")]
+        pem: bool,
+        /// Split a PEM chain into numbered files (cert-1.pem, cert-2.pem, ...); never overwrites
+        #[arg(long)]
+        split: bool,
+    },
+
+    // === DATA FORMATS ===
+    /// Convert between JSON, YAML, TOML, XML, CSV, MessagePack, CBOR and BSON
+    Convert {
+        /// Input file (or pipe via stdin); binary formats may also be hex or base64
+        file: Option<PathBuf>,
+        /// Input format: json, yaml, toml, xml, csv, msgpack, cbor, bson
+        #[arg(short, long, default_value = "json")]
+        from: String,
+        /// Output format: any input format, or diag (CBOR diagnostic notation)
+        #[arg(short, long, default_value = "json")]
+        to: String,
+        /// Print binary output as hex instead of raw bytes
+        #[arg(short = 'x', long)]
+        hex: bool,
+    },
+
+    /// Pretty-print, minify or sort keys of JSON (or reformat YAML, TOML, XML, CSV)
+    Fmt {
+        /// Input file (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// Format of the input
+        #[arg(short, long, default_value = "json")]
+        format: String,
+        /// Single-line JSON output
+        #[arg(short, long)]
+        minify: bool,
+        /// Sort object keys recursively
+        #[arg(short, long)]
+        sort_keys: bool,
+        /// Spaces per indent level
+        #[arg(short, long, default_value = "2")]
+        indent: usize,
+    },
+
+    /// Query JSON with JSONPath (`$.a[0]`) or a jq filter (`.a | map(.b)`)
+    Query {
+        /// JSONPath or jq expression
+        expr: String,
+        /// Input file (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// Format of the input (yaml, toml, msgpack, ... are converted to JSON first)
+        #[arg(short, long, default_value = "json")]
+        format: String,
+        /// One result per line, no indentation
+        #[arg(short, long)]
+        compact: bool,
+        /// Print string results without quotes
+        #[arg(short, long)]
+        raw: bool,
+    },
+
+    // === TIME ===
+    /// Convert timestamps: Unix s/ms/µs/ns, RFC 3339/2822, ISO week, FILETIME, Cocoa, .NET ticks
+    Time {
+        /// Timestamp, date, UUID v1/v6/v7 or ULID (default: now)
+        input: Option<String>,
+        /// Read numbers as: auto, s, ms, us, ns, filetime, cocoa, ticks, snowflake, discord
+        #[arg(short, long, default_value = "auto")]
+        from: String,
+        /// Print only this representation (s, ms, us, ns, rfc3339, local, rfc2822, iso-week, filetime, cocoa, ticks)
+        #[arg(short, long)]
+        to: Option<String>,
+    },
+
+    // === COMPRESSION ===
+    /// Compress data (gzip, zlib, deflate, zstd, brotli, bzip2, xz, lzma)
+    Compress {
+        /// File to compress (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// Format
+        #[arg(short, long, default_value = "gzip")]
+        format: String,
+        /// Compression level (format-specific range)
+        #[arg(short, long)]
+        level: Option<u32>,
+    },
+
+    /// Decompress data (format detected from magic bytes if omitted)
+    Decompress {
+        /// File to decompress (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// Format (required for deflate and brotli)
+        #[arg(short, long)]
+        format: Option<String>,
+    },
+
+    /// Gzip compress (shortcut for compress -f gzip)
+    Gzip {
+        /// File to compress (or pipe via stdin)
+        file: Option<PathBuf>,
+        /// Compression level (0-9)
+        #[arg(short, long)]
+        level: Option<u32>,
+    },
+
+    /// Gzip decompress (shortcut for decompress -f gzip)
+    Gunzip {
+        /// File to decompress (or pipe via stdin)
+        file: Option<PathBuf>,
+    },
+
     // === HASHING ===
     /// MD5 hash (legacy, not for security)
     Md5 {
@@ -136,8 +492,85 @@ enum Commands {
     },
 
     // === GENERATION ===
-    /// Generate random UUID v4
-    Uuid,
+    /// Generate a UUID (v4 by default; v1, v3, v5, v6, v7, v8 with --version)
+    Uuid {
+        /// UUID version: 1, 3, 4, 5, 6, 7 or 8
+        #[arg(short, long, default_value = "4")]
+        version: u8,
+        /// Namespace for v3/v5/v8: dns, url, oid, x500 or a UUID
+        #[arg(long)]
+        namespace: Option<String>,
+        /// Name to hash for v3/v5/v8
+        #[arg(long)]
+        name: Option<String>,
+        #[command(subcommand)]
+        action: Option<UuidAction>,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Generate a ULID (sortable, 48-bit ms + 80 random bits)
+    Ulid {
+        #[command(subcommand)]
+        action: Option<IdAction>,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Generate a KSUID (sortable, 32-bit seconds + 128 random bits)
+    Ksuid {
+        #[command(subcommand)]
+        action: Option<IdAction>,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Generate a NanoID (URL-safe by default)
+    Nanoid {
+        /// Number of characters
+        #[arg(short, long, default_value = "21")]
+        size: usize,
+        /// Custom alphabet
+        #[arg(short, long)]
+        alphabet: Option<String>,
+        #[command(subcommand)]
+        action: Option<IdAction>,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Generate a CUID2 (opaque, starts with a letter)
+    Cuid2 {
+        /// Number of characters (2-32)
+        #[arg(short, long, default_value = "24")]
+        length: usize,
+        #[command(subcommand)]
+        action: Option<IdAction>,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Generate a Snowflake ID (41-bit ms, 10-bit worker, 12-bit sequence)
+    Snowflake {
+        /// Epoch: twitter, discord or Unix milliseconds
+        #[arg(short, long, default_value = "twitter")]
+        epoch: String,
+        /// Worker ID (0-1023)
+        #[arg(short, long, default_value = "0")]
+        worker: u16,
+        #[command(subcommand)]
+        action: Option<IdAction>,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Generate a MongoDB ObjectId
+    Objectid {
+        #[command(subcommand)]
+        action: Option<IdAction>,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
 
     /// Generate secure random password
     Pass {
@@ -147,6 +580,77 @@ enum Commands {
         /// Alphanumeric only (no symbols)
         #[arg(short, long)]
         alpha: bool,
+        /// Leave out lowercase letters
+        #[arg(long)]
+        no_lower: bool,
+        /// Leave out uppercase letters
+        #[arg(long)]
+        no_upper: bool,
+        /// Leave out digits
+        #[arg(long)]
+        no_digits: bool,
+        /// Leave out symbols (same as --alpha)
+        #[arg(long)]
+        no_symbols: bool,
+        /// Minimum lowercase letters (default 1 when the length fits one of each class)
+        #[arg(long)]
+        min_lower: Option<usize>,
+        /// Minimum uppercase letters (default 1 when the length fits one of each class)
+        #[arg(long)]
+        min_upper: Option<usize>,
+        /// Minimum digits (default 1 when the length fits one of each class)
+        #[arg(long)]
+        min_digits: Option<usize>,
+        /// Minimum symbols (default 1 when the length fits one of each class)
+        #[arg(long)]
+        min_symbols: Option<usize>,
+        /// Extra characters to draw from
+        #[arg(long)]
+        include: Option<String>,
+        /// Characters never to use
+        #[arg(long)]
+        exclude: Option<String>,
+        /// Leave out look-alike characters 0 O 1 l I
+        #[arg(long)]
+        no_lookalikes: bool,
+        /// Use each character at most once
+        #[arg(long)]
+        no_repeats: bool,
+        /// Longest run of one repeated character (0 = unlimited)
+        #[arg(long, default_value = "0")]
+        max_run: usize,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Estimate password strength from patterns: guesses, crack-time upper bounds and feedback
+    Strength {
+        /// Password to check (or stdin)
+        password: Option<String>,
+    },
+
+    /// Generate a diceware passphrase (prints entropy to stderr)
+    Passphrase {
+        /// Number of words
+        #[arg(short, long, default_value = "6")]
+        words: usize,
+        /// Wordlist file, one word per line (EFF dice-numbered lists work as-is); default: bundled BIP-39 English
+        #[arg(long)]
+        wordlist: Option<PathBuf>,
+        /// Separator between words
+        #[arg(short, long, default_value = "-")]
+        separator: String,
+        /// Word case: lower, title, upper
+        #[arg(short, long, default_value = "lower")]
+        case: String,
+        /// Append a random digit to one word
+        #[arg(short, long)]
+        digit: bool,
+        /// Append a random symbol to one word
+        #[arg(long)]
+        symbol: bool,
+        #[command(flatten)]
+        bulk: BulkArgs,
     },
 
     /// Generate random hex bytes
@@ -154,6 +658,8 @@ enum Commands {
         /// Number of random bytes
         #[arg(default_value = "16")]
         bytes: usize,
+        #[command(flatten)]
+        bulk: BulkArgs,
     },
 
     /// Generate random base64 bytes
@@ -161,6 +667,63 @@ enum Commands {
         /// Number of random bytes
         #[arg(default_value = "16")]
         bytes: usize,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    /// Generate random strings matching a regular expression
+    GenRegex {
+        /// Pattern, e.g. '[A-Z]{4}-[0-9]{4}'
+        pattern: String,
+        /// Most extra repetitions for *, + and {n,}
+        #[arg(short, long, default_value = "8")]
+        max_repeat: u32,
+        /// Draw classes like . and \w from all of Unicode, not just printable ASCII
+        #[arg(long)]
+        unicode: bool,
+        #[command(flatten)]
+        bulk: BulkArgs,
+    },
+
+    // === ONE-TIME PASSWORDS ===
+    /// Current TOTP code for a base32 secret or otpauth:// URI (seconds left on stderr)
+    Totp {
+        /// Base32 secret or otpauth:// URI (or stdin)
+        secret: Option<String>,
+        /// Time step in seconds [default: 30]
+        #[arg(short, long)]
+        period: Option<u64>,
+        /// Unix time to compute the code for instead of now
+        #[arg(long)]
+        at: Option<u64>,
+        #[command(flatten)]
+        otp: OtpArgs,
+    },
+
+    /// HOTP code for a counter
+    Hotp {
+        /// Base32 secret or otpauth:// URI (or stdin)
+        secret: Option<String>,
+        /// Counter value [default: 0]
+        #[arg(short, long)]
+        counter: Option<u64>,
+        #[command(flatten)]
+        otp: OtpArgs,
+    },
+
+    /// Inspect an otpauth:// URI and show its current code
+    Otpauth {
+        /// otpauth:// URI (or stdin)
+        uri: Option<String>,
+    },
+
+    /// Generate a random base32 OTP secret
+    Otpsecret {
+        /// Number of random bytes
+        #[arg(short, long, default_value = "20")]
+        bytes: usize,
+        #[command(flatten)]
+        bulk: BulkArgs,
     },
 
     // === HELP & INFO ===
@@ -169,7 +732,7 @@ enum Commands {
 
     /// Comprehensive help guide (beginner to advanced)
     Guide {
-        /// Topic: all, basics, encode, hash, generate, pipe, dev
+        /// Topic: all, basics, encode, hash, generate, cipher, compress, jwt, cert, data, time, otp, pipe, dev
         #[arg(default_value = "all")]
         topic: String,
     },
@@ -179,6 +742,72 @@ enum Commands {
 // HELP SYSTEM
 // ============================================================================
 
+/// `--count`, `--format`, `--unique` and `--seed`, shared by every generator
+#[derive(clap::Args)]
+struct BulkArgs {
+    /// Number of values to generate
+    #[arg(
+        short = 'n',
+        long,
+        default_value = "1",
+        value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..=generate::MAX_COUNT as u64)
+    )]
+    count: usize,
+    /// Output format: lines, json, csv, sql
+    #[arg(short, long, default_value = "lines")]
+    format: String,
+    /// Never repeat a value (fails if the length or alphabet is too small)
+    #[arg(short, long)]
+    unique: bool,
+    /// Reproducible output from ChaCha20 and a fixed clock; NOT secret
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+/// Code settings shared by `totp` and `hotp`; flags override values from a URI
+#[derive(clap::Args)]
+struct OtpArgs {
+    /// Code length [default: 6]
+    #[arg(short, long)]
+    digits: Option<u32>,
+    /// HMAC algorithm: SHA1, SHA256, SHA512 [default: SHA1]
+    #[arg(short, long)]
+    algorithm: Option<String>,
+    /// Print the otpauth:// URI instead of the code
+    #[arg(long)]
+    uri: bool,
+    /// Issuer for --uri
+    #[arg(long)]
+    issuer: Option<String>,
+    /// Account name for --uri
+    #[arg(long, default_value = "user")]
+    account: String,
+}
+
+#[derive(Subcommand)]
+enum UuidAction {
+    /// Show version, variant, timestamp, node and alternate forms of a UUID
+    Inspect {
+        /// UUID as hyphenated, simple, braced, URN, base64 or base58 (or stdin)
+        uuid: Option<String>,
+        /// Read the input as one form: hyphenated, simple, braced, urn, base64, base58, integer
+        #[arg(short, long)]
+        from: Option<String>,
+        /// Print only one form: hyphenated, simple, braced, urn, base64, base58, integer
+        #[arg(short, long)]
+        to: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum IdAction {
+    /// Validate an ID and show its embedded timestamp and fields
+    Inspect {
+        /// ID to inspect (or stdin)
+        id: Option<String>,
+    },
+}
+
 fn print_guide(topic: &str) {
     match topic.to_lowercase().as_str() {
         "all" => print_full_guide(),
@@ -186,11 +815,18 @@ fn print_guide(topic: &str) {
         "encode" | "encoding" => print_encode_guide(),
         "hash" | "hashing" => print_hash_guide(),
         "generate" | "gen" => print_generate_guide(),
+        "cipher" | "ciphers" | "ctf" => print_cipher_guide(),
+        "compress" | "compression" | "zip" => print_compress_guide(),
+        "jwt" | "token" | "tokens" => print_jwt_guide(),
+        "cert" | "certs" | "x509" | "pem" => print_cert_guide(),
+        "data" | "convert" | "formats" => print_data_guide(),
+        "time" | "date" | "timestamp" => print_time_guide(),
+        "otp" | "totp" | "2fa" => print_otp_guide(),
         "pipe" | "pipes" | "stdin" => print_pipe_guide(),
         "dev" | "developer" | "advanced" => print_dev_guide(),
         _ => {
             eprintln!("Unknown topic: {}", topic);
-            eprintln!("Available: all, basics, encode, hash, generate, pipe, dev");
+            eprintln!("Available: all, basics, encode, hash, generate, cipher, compress, jwt, cert, data, time, otp, pipe, dev");
             std::process::exit(1);
         }
     }
@@ -220,6 +856,20 @@ fn print_full_guide() {
     println!();
     print_generate_guide();
     println!();
+    print_cipher_guide();
+    println!();
+    print_compress_guide();
+    println!();
+    print_jwt_guide();
+    println!();
+    print_cert_guide();
+    println!();
+    print_data_guide();
+    println!();
+    print_time_guide();
+    println!();
+    print_otp_guide();
+    println!();
     print_pipe_guide();
     println!();
     print_dev_guide();
@@ -246,6 +896,11 @@ fn print_basics() {
     println!("    qt guide encode             Encoding & decoding");
     println!("    qt guide hash               Hashing files & text");
     println!("    qt guide generate           Random data generation");
+    println!("    qt guide cipher             Classical ciphers & XOR");
+    println!("    qt guide compress           gzip, zstd, brotli, xz...");
+    println!("    qt guide jwt                JSON Web Tokens");
+    println!("    qt guide cert               Certificates and keys");
+    println!("    qt guide data               JSON, YAML, TOML, XML, CSV, CBOR...");
     println!("    qt guide pipe               Pipe & stdin usage");
     println!("    qt guide dev                Developer & advanced");
 }
@@ -274,6 +929,58 @@ fn print_encode_guide() {
     println!("  ─────────────────────────────────────────────────────────────");
     println!("    qt html \"<script>\"          → &lt;script&gt;");
     println!("    qt htmld \"&lt;script&gt;\"   → <script>");
+    println!();
+    println!("  AUTO DETECT - Unknown blob? Let qt peel the layers");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt detect \"NDg2NTZjNmM2Zg==\" → Chain: base64 → hex → text");
+    println!("    qt detect --all \"...\"      Every single-layer decode, scored");
+    println!("    qt detect --raw < blob      Decoded bytes only, for pipes");
+    println!();
+    println!("  BYTE LISTS - Binary, octal, decimal and array literals");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt bin \"Hi\"                 → 01001000 01101001");
+    println!("    qt dec \"Hi\"                 → 72 105");
+    println!("    qt array --rust \"Hi\"        → [0x48, 0x69]");
+    println!("    qt decd \"{{ 0x48, 0x69 }}\"    → Hi");
+    println!();
+    println!("  NUMBERS - Integer base conversion (any size)");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt num 0xFF                 Show in bases 2, 8, 10, 16, 36");
+    println!("    qt num -1 --width 16        Two's complement → 0xffff");
+    println!("    qt num zz --from 36 --to 2  Base 36 to binary");
+    println!();
+    println!("  HEX DUMP - Offsets, grouped bytes and ASCII (xxd / hexdump -C)");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt hexdump file.bin         xxd layout");
+    println!("    qt hexdump -C file.bin      hexdump -C layout");
+    println!("    qt hexdump -s 0x100 -n 64   64 bytes starting at offset 0x100");
+    println!("    qt hexdump -r dump.txt      Reverse a dump back into bytes");
+    println!();
+    println!("  UNICODE - Invisible characters, homoglyphs and text encodings");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt inspect \"pаypal\"        Code points, names; flags Cyrillic а");
+    println!("    qt inspect < config.yml     Find zero-width and bidi characters");
+    println!("    qt inspect -n nfc \"é\"       Normalize (nfc, nfd, nfkc, nfkd)");
+    println!("    qt inspect -t utf-16le Hi   → 48006900");
+    println!("    qt inspect -f windows-1252 -x \"93 80 94\"  → “€”");
+    println!();
+    println!("  DATA URI - Inline images and fonts in CSS/HTML");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt datauri logo.png         → data:image/png;base64,iVBOR...");
+    println!("    qt datauri -d uri.txt       Writes decoded.png (type from MIME)");
+    println!("    qt datauri -d -o out.svg < uri.txt");
+    println!();
+    println!("  ASN.1 - DER/BER structure of keys, certificates, CMS, SNMP");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt asn1 key.der             Offset, length, tag and value tree");
+    println!("    qt asn1 cert.pem            PEM, hex and base64 work too");
+    println!("    echo 300602010502012a | qt asn1");
+    println!();
+    println!("  PROTOBUF - Wire format without the .proto");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt protodec msg.bin         Field-number tree, guessed types");
+    println!("    echo CJYB | qt protodec     Hex and base64 input work too");
+    println!("    qt protodec msg.bin -p a.proto -m Person   Named fields");
 }
 
 fn print_hash_guide() {
@@ -313,12 +1020,39 @@ fn print_generate_guide() {
     println!("  │  GENERATION                                                     │");
     println!("  └─────────────────────────────────────────────────────────────────┘");
     println!();
-    println!("  UUID - Universally Unique Identifier (v4 random)");
-    println!("  ─────────────────────────────────────────────────");
+    println!("  UUID - Universally Unique Identifier (v4 random by default)");
+    println!("  ───────────────────────────────────────────────────────────");
     println!("    qt uuid                     → 550e8400-e29b-41d4-a716-446655440000");
+    println!("    qt uuid -v 7                Time-ordered, sorts by creation (DB keys)");
+    println!("    qt uuid -v 1 / -v 6         Timestamp + random node ID");
+    println!("    qt uuid -v 5 --namespace dns --name example.com   Deterministic (SHA-1)");
+    println!("    qt uuid -v 3 --namespace url --name https://x.io  Deterministic (MD5)");
+    println!("    qt uuid -v 8 [--name ...]   Custom: random, or SHA-256 of namespace + name");
     println!();
+    println!("    Namespaces: dns, url, oid, x500 or any UUID");
     println!("    Use for: Database IDs, session tokens, unique filenames");
     println!();
+    println!("  UUID INSPECT - Version, variant, timestamp, node, other forms");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt uuid inspect <uuid>      Full report");
+    println!("    qt uuid -v 7 | qt uuid inspect   When was it created?");
+    println!("    qt uuid inspect <uuid> -t base58   Convert to one form");
+    println!("    qt uuid inspect -f integer <n>     Read one form (base58, base64, ...)");
+    println!();
+    println!("    Forms: hyphenated, simple, braced, urn, base64, base58, integer");
+    println!("    Input accepts any of these forms.");
+    println!();
+    println!("  OTHER IDS - Generate, or add 'inspect <id>' to validate and decode");
+    println!("  ─────────────────────────────────────────────────────────────────");
+    println!("    qt ulid                     01ARZ3NDEKTSV4RRFFQ69G5FAV (sortable, ms)");
+    println!("    qt ksuid                    0ujtsYcgvSTl8PAuAdqWYSMnLOv (sortable, s)");
+    println!("    qt nanoid -s 12 -a abc123   Custom size and alphabet");
+    println!("    qt cuid2 -l 24              Opaque, starts with a letter");
+    println!("    qt snowflake -e discord -w 3   Epoch: twitter, discord or Unix ms");
+    println!("    qt objectid                 MongoDB ObjectId");
+    println!("    qt ulid inspect <id>        Timestamp and randomness");
+    println!("    qt snowflake -e discord inspect <id>   Time, worker, sequence");
+    println!();
     println!("  PASSWORD - Cryptographically secure random password");
     println!("  ────────────────────────────────────────────────────");
     println!("    qt pass                     16 chars (default)");
@@ -327,6 +1061,34 @@ fn print_generate_guide() {
     println!();
     println!("    Default charset: a-z A-Z 0-9 !@#$%^&*()-_=+");
     println!("    Alpha charset:   a-z A-Z 0-9");
+    println!("    Every enabled class appears at least once.");
+    println!();
+    println!("  PASSWORD POLICY - Shape the character pool");
+    println!("  ──────────────────────────────────────────");
+    println!("    qt pass 20 --min-digits 4 --min-symbols 2   Minimum counts");
+    println!("    qt pass 12 --no-symbols --no-upper          Drop classes");
+    println!("    qt pass 16 --no-lookalikes                  No 0 O 1 l I");
+    println!("    qt pass 16 --exclude '\"`$' --include '~?'   Custom sets");
+    println!("    qt pass 16 --no-repeats / --max-run 2       Repetition limits");
+    println!();
+    println!("  STRENGTH - Pattern-based estimate with crack times and feedback");
+    println!("  ───────────────────────────────────────────────────────────────");
+    println!("    qt strength 'P@ssw0rd1'     Score 0-4, guesses, crack times");
+    println!("    qt pass 20 | qt strength    Check a generated password");
+    println!();
+    println!("    Finds dictionary words (also l33t and reversed), keyboard rows,");
+    println!("    sequences, repeats and dates. The dictionary is small (top-100");
+    println!("    passwords, BIP-39 words), so crack times are upper bounds.");
+    println!("    'qt pass' prints its entropy to stderr.");
+    println!();
+    println!("  PASSPHRASE - Diceware words, easy to type (entropy on stderr)");
+    println!("  ─────────────────────────────────────────────────────────────");
+    println!("    qt passphrase               6 words → ozone-kidney-dwarf-...");
+    println!("    qt passphrase -w 8 -s ' ' -c title      Separator and case");
+    println!("    qt passphrase -d --symbol               Add a digit and symbol");
+    println!("    qt passphrase --wordlist eff_large_wordlist.txt   Custom list");
+    println!();
+    println!("    Bundled list: BIP-39 English (2048 words, 11 bits per word)");
     println!();
     println!("  RANDOM BYTES - Raw entropy as hex or base64");
     println!("  ────────────────────────────────────────────");
@@ -334,6 +1096,223 @@ fn print_generate_guide() {
     println!("    qt randb64 32               32 bytes as base64");
     println!();
     println!("    Use for: API keys, encryption keys, nonces");
+    println!();
+    println!("  REGEX - Random strings matching a pattern (test data)");
+    println!("  ─────────────────────────────────────────────────────");
+    println!("    qt gen-regex '[A-Z]{{4}}-[0-9]{{4}}'      → QXTB-4821");
+    println!("    qt gen-regex '(admin|user)_\\w{{3,8}}' -n 5");
+    println!("    qt gen-regex 'a+b*' -m 3             Cap * and + at 3 extra repeats");
+    println!();
+    println!("    Classes, quantifiers, alternation and groups; ^ and $ only at the ends; no \\b.");
+    println!("    Classes like . and \\w draw from printable ASCII unless --unicode.");
+    println!();
+    println!("  BULK - Every generator takes --count, --format and --unique");
+    println!("  ──────────────────────────────────────────────────────────");
+    println!("    qt uuid -v 7 -n 10000 > ids.txt     One per line");
+    println!("    qt pass 20 -n 5 -f json             JSON array");
+    println!("    qt ulid -n 100 -f csv               CSV column");
+    println!("    qt nanoid -n 50 -f sql              SQL VALUES list");
+    println!("    qt nanoid -s 4 -a abc -n 50 -u      No repeats (fails if impossible)");
+    println!();
+    println!("  SEED - Reproducible output for test fixtures (NOT secret)");
+    println!("  ─────────────────────────────────────────────────────────");
+    println!("    qt uuid --seed 42 -n 3      Same 3 UUIDs on every machine");
+    println!("    qt pass 20 --seed 7         Same password every run");
+    println!();
+    println!("    Uses ChaCha20 and a fake clock from 2024-01-01 that ticks 1 ms per");
+    println!("    read, so time-based IDs repeat too. Anyone with the seed can");
+    println!("    recreate the output: never use seeded values as real secrets.");
+}
+
+fn print_cipher_guide() {
+    println!("  ┌─────────────────────────────────────────────────────────────────┐");
+    println!("  │  CIPHERS & XOR                                                  │");
+    println!("  └─────────────────────────────────────────────────────────────────┘");
+    println!();
+    println!("  Classical ciphers for CTFs and obfuscated strings. These are");
+    println!("  puzzles, not encryption - never use them to protect real data.");
+    println!();
+    println!("  SUBSTITUTION:");
+    println!("  ─────────────");
+    println!("    qt rot \"Hello\"               → Uryyb (ROT13)");
+    println!("    qt rot -n 3 \"Hello\"          → Khoor (Caesar)");
+    println!("    qt atbash \"Hello\"            → Svool");
+    println!("    qt vigenere -k LEMON \"ATTACK\" → LXFOPV");
+    println!("    qt affine -a 5 -b 8 \"affine\" → ihhwvc");
+    println!("    Add -d to vigenere/affine to decrypt.");
+    println!();
+    println!("  XOR:");
+    println!("  ────");
+    println!("    qt xor -k secret < file.bin     Repeating text key, raw output");
+    println!("    qt xor -x --hex-key -k 2a 1f4e  Hex in, hex key, hex out");
+    println!();
+    println!("  BRUTE FORCE (ranked by English letter frequency):");
+    println!("  ─────────────────────────────────────────────────");
+    println!("    qt crack \"Uryyb Jbeyq\"       All 25 ROT shifts");
+    println!("    qt crack --xor -x 1b37373331   All 256 single-byte XOR keys");
+    println!("    qt crack --top 10 ...          Show more candidates");
+}
+
+fn print_jwt_guide() {
+    println!("  ┌─────────────────────────────────────────────────────────────────┐");
+    println!("  │  JSON WEB TOKENS                                                │");
+    println!("  └─────────────────────────────────────────────────────────────────┘");
+    println!();
+    println!("  DECODE (no key needed - JWTs are signed, not encrypted):");
+    println!("  ────────────────────────────────────────────────────────");
+    println!("    qt jwt eyJhbGciOi...        Header, payload, dates, expiry");
+    println!();
+    println!("  VERIFY:");
+    println!("  ───────");
+    println!("    qt jwt TOKEN --secret s3cr3t      HS256 / HS384 / HS512");
+    println!("    qt jwt TOKEN --key public.pem     RS*, PS*, ES256/384, EdDSA");
+    println!("    Exit code 1 if the signature does not match.");
+    println!();
+    println!("  SIGN:");
+    println!("  ─────");
+    println!("    qt jwt --sign '{{\"sub\":\"42\"}}' --secret s3cr3t --exp 3600");
+    println!("    qt jwt --sign @claims.json -a RS256 --key private.pem");
+}
+
+fn print_cert_guide() {
+    println!("  ┌─────────────────────────────────────────────────────────────────┐");
+    println!("  │  CERTIFICATES & KEYS                                            │");
+    println!("  └─────────────────────────────────────────────────────────────────┘");
+    println!();
+    println!("  INSPECT (PEM, DER or bare base64):");
+    println!("  ──────────────────────────────────");
+    println!("    qt cert server.pem          Subject, issuer, SANs, validity, key");
+    println!("    qt cert request.csr         Certificate signing requests");
+    println!("    qt cert public.pem          Public key type, size, fingerprint");
+    println!("    qt cert chain.pem           Every certificate in a bundle");
+    println!();
+    println!("  CONVERT:");
+    println!("  ────────");
+    println!("    qt cert --der cert.pem > cert.der");
+    println!("    qt cert --pem cert.der > cert.pem");
+    println!("    qt cert --split chain.pem   Writes cert-1.pem, cert-2.pem, ... (never overwrites)");
+}
+
+fn print_data_guide() {
+    println!("  ┌─────────────────────────────────────────────────────────────────┐");
+    println!("  │  DATA FORMATS                                                   │");
+    println!("  └─────────────────────────────────────────────────────────────────┘");
+    println!();
+    println!("  FORMAT:");
+    println!("  ───────");
+    println!("    qt fmt data.json            Pretty-print (2-space indent)");
+    println!("    qt fmt -m -s data.json      Minify and sort keys");
+    println!("    qt fmt -f yaml config.yml   Reformat YAML (also toml, xml, csv)");
+    println!("    Parse errors show the line and column.");
+    println!();
+    println!("  CONVERT (json, yaml, toml, xml, csv, msgpack, cbor, bson):");
+    println!("  ──────────────────────────────────────────────────────────");
+    println!("    qt convert -f yaml -t toml config.yml");
+    println!("    qt convert -f csv users.csv       Header row → array of objects");
+    println!("    qt convert -f cbor msg.cbor       CBOR to pretty JSON");
+    println!("    qt convert -t msgpack -x data.json   JSON to MessagePack hex");
+    println!("    echo 81a16101 | qt convert -f msgpack   Hex/base64 input works");
+    println!();
+    println!("  QUERY (JSONPath starts with $, anything else is jq):");
+    println!("  ─────────────────────────────────────────────────────");
+    println!("    qt query '$.items[?(@.price < 10)].name' data.json");
+    println!("    qt query '$..id' data.json        Every id at any depth");
+    println!("    qt query '.items[0].name' data.json");
+    println!("    qt query '.items | map(.price) | add' data.json");
+    println!("    qt query -r '.[] | select(.ok) | .name' data.json");
+    println!("    qt query -f yaml '.services | keys' compose.yml");
+    println!("    jq built-ins: map select keys length has sort_by group_by add");
+    println!("    first last min max unique reverse to_entries join split test ...");
+    println!();
+    println!("  CBOR DIAGNOSTIC NOTATION:");
+    println!("  ─────────────────────────");
+    println!("    qt convert -f cbor -t diag msg.cbor   {{\"t\": 1(1700000000)}}");
+    println!();
+    println!("  Byte strings become base64 in JSON; BSON needs a top-level object.");
+}
+
+fn print_time_guide() {
+    println!("  ┌─────────────────────────────────────────────────────────────────┐");
+    println!("  │  TIMESTAMPS                                                     │");
+    println!("  └─────────────────────────────────────────────────────────────────┘");
+    println!();
+    println!("  WHAT TIME IS IT?");
+    println!("  ────────────────");
+    println!("    qt time                     Now, in every format");
+    println!("    qt time 1697558400          Unix seconds (ms/µs/ns by digit count)");
+    println!("    qt time 2023-10-17T16:00:00Z");
+    println!("    qt time \"Tue, 17 Oct 2023 16:00:00 +0000\"");
+    println!("    qt time 2023-W42-2          ISO week date");
+    println!();
+    println!("  OTHER EPOCHS (-f):");
+    println!("  ──────────────────");
+    println!("    qt time -f filetime 133420320000000000   Windows (100 ns since 1601)");
+    println!("    qt time -f cocoa 719251200               Apple (seconds since 2001)");
+    println!("    qt time -f ticks 638331552000000000      .NET (100 ns since year 1)");
+    println!("    qt time -f discord 175928847299117063    Snowflake IDs (also twitter)");
+    println!();
+    println!("  EMBEDDED TIMESTAMPS:");
+    println!("  ────────────────────");
+    println!("    qt time 017f22e2-79b0-7cc3-98c4-dc0c0c07398f   UUID v1, v6, v7");
+    println!("    qt time 01ARZ3NDEKTSV4RRFFQ69G5FAV             ULID");
+    println!();
+    println!("  ONE VALUE (-t):");
+    println!("  ───────────────");
+    println!("    qt time -t ms               Current Unix milliseconds");
+    println!("    qt time -f ms -t rfc3339 1697558400000");
+}
+
+fn print_otp_guide() {
+    println!("  ┌─────────────────────────────────────────────────────────────────┐");
+    println!("  │  ONE-TIME PASSWORDS (2FA)                                       │");
+    println!("  └─────────────────────────────────────────────────────────────────┘");
+    println!();
+    println!("  TOTP - Time-based codes, as in authenticator apps (RFC 6238)");
+    println!("  ────────────────────────────────────────────────────────────");
+    println!("    qt totp JBSWY3DPEHPK3PXP    Current code; seconds left on stderr");
+    println!("    qt totp <secret> -d 8 -p 60 -a sha256   Digits, period, algorithm");
+    println!("    qt totp <secret> --at 1700000000        Code at a given Unix time");
+    println!("    qt totp 'otpauth://totp/...'            Settings from a URI");
+    println!();
+    println!("  HOTP - Counter-based codes (RFC 4226)");
+    println!("  ─────────────────────────────────────");
+    println!("    qt hotp <secret> -c 42      Code for counter 42");
+    println!();
+    println!("  URIS & SECRETS - What QR codes contain");
+    println!("  ──────────────────────────────────────");
+    println!("    qt otpsecret                New 160-bit base32 secret");
+    println!("    qt totp <secret> --uri --issuer ACME --account me@acme.io");
+    println!("    qt otpauth 'otpauth://totp/ACME:me?secret=...'   Inspect a URI");
+    println!();
+    println!("    Secrets are base32; case, spaces and padding are ignored.");
+    println!("    Flags override settings read from a URI.");
+}
+
+fn print_compress_guide() {
+    println!("  ┌─────────────────────────────────────────────────────────────────┐");
+    println!("  │  COMPRESSION                                                    │");
+    println!("  └─────────────────────────────────────────────────────────────────┘");
+    println!();
+    println!("  FORMATS (level range, default):");
+    println!("  ───────────────────────────────");
+    println!("    gzip, zlib, deflate   0-9   (6)");
+    println!("    zstd                  1-22  (3)");
+    println!("    brotli                0-11  (6)");
+    println!("    bzip2                 1-9   (9)");
+    println!("    xz, lzma              0-9   (6)");
+    println!();
+    println!("  USAGE:");
+    println!("  ──────");
+    println!("    qt gzip file.json > file.json.gz");
+    println!("    qt compress -f zstd -l 19 big.log > big.log.zst");
+    println!("    qt decompress file.xz         Format detected from magic bytes");
+    println!("    qt decompress -f brotli x.br  deflate/brotli need -f");
+    println!("    Output stops with an error past 256 MiB (zip-bomb guard).");
+    println!();
+    println!("  PIPES:");
+    println!("  ──────");
+    println!("    echo \"$BLOB\" | qt b64d | qt gunzip    Inflate base64 gzip JSON");
+    println!("    qt detect \"$BLOB\"                     Or let qt find the chain");
 }
 
 fn print_pipe_guide() {
@@ -398,7 +1377,7 @@ fn print_dev_guide() {
     println!();
     println!("    let encoded = encode::base64_encode(\"hello\");");
     println!("    let hashed = hash::hash_string(\"data\", \"sha256\")?;");
-    println!("    let uuid = generate::uuid_v4();");
+    println!("    let uuid = generate::uuid_v4(&mut generate::Source::system());");
     println!();
     println!("  BUILD FROM SOURCE:");
     println!("  ──────────────────");
@@ -427,10 +1406,8 @@ fn get_input(arg: Option<String>) -> String {
     } else if !io::stdin().is_terminal() {
         let stdin = io::stdin();
         let mut input = String::new();
-        for line in stdin.lock().lines() {
-            if let Ok(line) = line {
-                input.push_str(&line);
-            }
+        for line in stdin.lock().lines().map_while(Result::ok) {
+            input.push_str(&line);
         }
         input
     } else {
@@ -441,6 +1418,79 @@ fn get_input(arg: Option<String>) -> String {
     }
 }
 
+/// Read raw bytes from a file, or from stdin when no file is given
+fn get_input_bytes(file: Option<PathBuf>) -> Result<Vec<u8>, String> {
+    if let Some(path) = file {
+        std::fs::read(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))
+    } else if !io::stdin().is_terminal() {
+        let mut buffer = Vec::new();
+        io::stdin()
+            .lock()
+            .read_to_end(&mut buffer)
+            .map_err(|e| format!("Read error: {}", e))?;
+        Ok(buffer)
+    } else {
+        Err("No input provided. Pass a file or pipe data via stdin.".to_string())
+    }
+}
+
+/// Raw bytes of a text argument, or of stdin when no argument is given
+fn get_input_raw(arg: Option<String>) -> Result<Vec<u8>, String> {
+    match arg {
+        Some(input) => Ok(input.into_bytes()),
+        None => get_input_bytes(None),
+    }
+}
+
+/// Text of an argument, or of stdin with its line breaks kept
+fn get_input_text(arg: Option<String>) -> Result<String, String> {
+    get_input_raw(arg).map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Write `data` to a new file, refusing to replace one that exists
+fn write_new_file(path: &Path, data: &[u8]) -> Result<(), String> {
+    let mut file = std::fs::OpenOptions::new().write(true).create_new(true).open(path).map_err(|e| match e.kind() {
+        io::ErrorKind::AlreadyExists => format!("{} already exists; not overwriting", path.display()),
+        _ => format!("Cannot write {}: {}", path.display(), e),
+    })?;
+    file.write_all(data).map_err(|e| format!("Cannot write {}: {}", path.display(), e))
+}
+
+/// Write raw bytes to stdout, or report the error and exit
+fn write_bytes(result: Result<Vec<u8>, String>) {
+    let written = result.and_then(|bytes| {
+        let mut stdout = io::stdout().lock();
+        stdout
+            .write_all(&bytes)
+            .and_then(|_| stdout.flush())
+            .map_err(|e| format!("Write error: {}", e))
+    });
+    if let Err(e) = written {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Print decoded text with a newline, or write raw bytes if it is binary
+fn print_decoded(result: Result<Vec<u8>, String>) {
+    match result {
+        Ok(bytes) => match String::from_utf8(bytes) {
+            Ok(text) => println!("{}", text),
+            Err(e) => write_bytes(Ok(e.into_bytes())),
+        },
+        Err(e) => write_bytes(Err(e)),
+    }
+}
+
+/// Parse a decimal or `0x`-prefixed hex number
+fn parse_number(s: &str) -> Result<usize, String> {
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => usize::from_str_radix(hex, 16),
+        None => s.parse(),
+    }
+    .map_err(|_| format!("Invalid number: {}", s))
+}
+
 // ============================================================================
 // MAIN
 // ============================================================================
@@ -454,13 +1504,15 @@ fn main() {
             Ok(encode::base64_encode(&get_input(input)))
         }
         Commands::Base64Decode { input } => {
-            encode::base64_decode(&get_input(input))
+            print_decoded(encode::base64_decode_bytes(&get_input(input)));
+            return;
         }
         Commands::Hex { input } => {
             Ok(encode::hex_encode(&get_input(input)))
         }
         Commands::Hexd { input } => {
-            encode::hex_decode(&get_input(input))
+            print_decoded(encode::hex_decode_bytes(&get_input(input)));
+            return;
         }
         Commands::Url { input } => {
             Ok(encode::url_encode(&get_input(input)))
@@ -474,6 +1526,157 @@ fn main() {
         Commands::Htmld { input } => {
             Ok(encode::html_decode(&get_input(input)))
         }
+        Commands::Bin { input } => get_input_raw(input).map(|b| encode::binary_encode(&b)),
+        Commands::Bind { input } => {
+            write_bytes(get_input_text(input).and_then(|text| encode::binary_decode(&text)));
+            return;
+        }
+        Commands::Oct { input } => get_input_raw(input).map(|b| encode::octal_encode(&b)),
+        Commands::Octd { input } => {
+            write_bytes(get_input_text(input).and_then(|text| encode::octal_decode(&text)));
+            return;
+        }
+        Commands::Dec { input } => get_input_raw(input).map(|b| encode::decimal_encode(&b)),
+        Commands::Decd { input } => {
+            write_bytes(get_input_text(input).and_then(|text| encode::decimal_decode(&text)));
+            return;
+        }
+        Commands::Array { input, rust } => get_input_raw(input).map(|b| {
+            if rust { encode::rust_array_encode(&b) } else { encode::c_array_encode(&b) }
+        }),
+        Commands::Num { value, from, to, width } => num_cmd(&value, from, to, width),
+        Commands::Detect { input, raw, all } => {
+            if raw {
+                write_bytes(get_input_raw(input).map(|data| detect::detect(&data).output));
+                return;
+            }
+            get_input_raw(input).map(|data| detect_cmd(&data, all))
+        }
+        Commands::Datauri { file, decode, output } => datauri_cmd(file, decode, output),
+        Commands::Inspect { text, normalize, from, hex, base64, to, raw } => {
+            match inspect_cmd(text, normalize, from, hex, base64, to.clone()) {
+                Ok((bytes, _)) if to.is_some() && raw => write_bytes(Ok(bytes)),
+                Ok((bytes, _)) if to.is_some() => println!("{}", hex::encode(bytes)),
+                Ok((_, output)) => println!("{}", output),
+                Err(e) => write_bytes(Err(e)),
+            }
+            return;
+        }
+        Commands::Asn1 { file } => get_input_bytes(file)
+            .and_then(|data| encode::asn1_input(&data))
+            .and_then(|der| encode::asn1_parse(&der))
+            .map(|nodes| encode::asn1_dump(&nodes)),
+        Commands::Protodec { file, proto, message } => protodec_cmd(file, proto, message),
+        Commands::Hexdump { file, canonical, skip, length, reverse } => {
+            if reverse {
+                write_bytes(get_input_bytes(file).and_then(|dump| {
+                    encode::hexdump_reverse(&String::from_utf8_lossy(&dump))
+                }));
+                return;
+            }
+            hexdump_cmd(file, canonical, skip, length)
+        }
+
+        // Ciphers
+        Commands::Rot { input, shift } => Ok(cipher::rot(&get_input(input), shift)),
+        Commands::Atbash { input } => Ok(cipher::atbash(&get_input(input))),
+        Commands::Vigenere { input, key, decrypt } => {
+            let text = get_input(input);
+            if decrypt {
+                cipher::vigenere_decrypt(&text, &key)
+            } else {
+                cipher::vigenere_encrypt(&text, &key)
+            }
+        }
+        Commands::Affine { input, a, b, decrypt } => {
+            let text = get_input(input);
+            if decrypt {
+                cipher::affine_decrypt(&text, a, b)
+            } else {
+                cipher::affine_encrypt(&text, a, b)
+            }
+        }
+        Commands::Xor { input, key, hex_key, hex } => {
+            let key = if hex_key { encode::hex_decode_bytes(&key) } else { Ok(key.into_bytes()) };
+            let data = if hex {
+                encode::hex_decode_bytes(&get_input(input))
+            } else {
+                get_input_raw(input)
+            };
+            let result = key.and_then(|k| data.and_then(|d| cipher::xor(&d, &k)));
+            if hex {
+                result.map(hex::encode)
+            } else {
+                write_bytes(result);
+                return;
+            }
+        }
+        Commands::Crack { input, xor, hex, top } => crack_cmd(input, xor, hex, top),
+
+        // Tokens
+        Commands::Jwt { token, secret, key, sign, alg, exp } => jwt_cmd(token, secret, key, sign, &alg, exp),
+        Commands::Cert { file, der, pem, split } => {
+            let file = file.filter(|f| f.as_os_str() != "-");
+            if der {
+                write_bytes(get_input_bytes(file).and_then(|data| {
+                    cert::read_blocks(&data).map(|blocks| blocks.into_iter().flat_map(|b| b.der).collect())
+                }));
+                return;
+            }
+            cert_cmd(file, pem, split)
+        }
+
+        // Data formats
+        Commands::Convert { file, from, to, hex } => {
+            let converted = convert_cmd(file, &from, &to);
+            match converted {
+                Ok((bytes, true)) if !hex => write_bytes(Ok(bytes)),
+                Ok((bytes, true)) => println!("{}", hex::encode(bytes)),
+                Ok((bytes, false)) => print_decoded(Ok(bytes)),
+                Err(e) => write_bytes(Err(e)),
+            }
+            return;
+        }
+
+        Commands::Fmt { file, format, minify, sort_keys, indent } => {
+            let options = structured::FmtOptions { minify, sort_keys, indent };
+            serial::Format::from_name(&format).and_then(|f| {
+                let data = get_input_bytes(file)?;
+                let out = serial::convert_with(&data, f, f, options)?;
+                Ok(String::from_utf8_lossy(&out).trim_end().to_string())
+            })
+        }
+        Commands::Query { expr, file, format, compact, raw } => query_cmd(file, &expr, &format, compact, raw),
+
+        // Time
+        Commands::Time { input, from, to } => time_cmd(input, &from, to),
+
+        // Compression
+        Commands::Compress { file, format, level } => {
+            write_bytes(compress::Format::from_name(&format).and_then(|f| {
+                get_input_bytes(file).and_then(|data| compress::compress(&data, f, level))
+            }));
+            return;
+        }
+        Commands::Decompress { file, format } => {
+            write_bytes(get_input_bytes(file).and_then(|data| match format {
+                Some(name) => compress::decompress(&data, compress::Format::from_name(&name)?),
+                None => compress::decompress_auto(&data).map(|(_, out)| out),
+            }));
+            return;
+        }
+        Commands::Gzip { file, level } => {
+            write_bytes(get_input_bytes(file).and_then(|data| {
+                compress::compress(&data, compress::Format::Gzip, level)
+            }));
+            return;
+        }
+        Commands::Gunzip { file } => {
+            write_bytes(get_input_bytes(file).and_then(|data| {
+                compress::decompress(&data, compress::Format::Gzip)
+            }));
+            return;
+        }
 
         // Hashing
         Commands::Md5 { file, string } => hash_cmd(file, string, "md5"),
@@ -483,16 +1686,85 @@ fn main() {
         Commands::Hash { file, string } => hash_all_cmd(file, string),
 
         // Generation
-        Commands::Uuid => Ok(generate::uuid_v4()),
-        Commands::Pass { length, alpha } => {
-            if alpha {
-                Ok(generate::alphanum_password(length))
+        Commands::Uuid { action: Some(UuidAction::Inspect { uuid, from, to }), .. } => uuid_inspect_cmd(uuid, from, to),
+        Commands::Uuid { version, namespace, name, action: None, bulk } => {
+            bulk_cmd(&bulk, |src| generate::uuid(src, version, namespace.as_deref(), name.as_deref()))
+        }
+        Commands::Ulid { action, bulk } => id_cmd(action, &bulk, generate::ulid, generate::ulid_inspect),
+        Commands::Ksuid { action, bulk } => id_cmd(action, &bulk, generate::ksuid, generate::ksuid_inspect),
+        Commands::Nanoid { size, alphabet, action: None, bulk } => bulk_cmd(&bulk, |src| generate::nanoid(src, size, alphabet.as_deref())),
+        Commands::Nanoid { alphabet, action: Some(IdAction::Inspect { id }), .. } => {
+            generate::nanoid_inspect(&get_input(id), alphabet.as_deref()).map(|r| r.summary())
+        }
+        Commands::Cuid2 { length, action: None, bulk } => bulk_cmd(&bulk, |src| generate::cuid2(src, length)),
+        Commands::Cuid2 { action: Some(IdAction::Inspect { id }), .. } => {
+            generate::cuid2_inspect(&get_input(id)).map(|r| r.summary())
+        }
+        Commands::Snowflake { epoch, worker, action, bulk } => generate::snowflake_epoch(&epoch).and_then(|epoch| match action {
+            None => bulk_cmd(&bulk, |src| generate::snowflake(src, epoch, worker)),
+            Some(IdAction::Inspect { id }) => generate::snowflake_inspect(&get_input(id), epoch).map(|r| r.summary()),
+        }),
+        Commands::Objectid { action, bulk } => id_cmd(action, &bulk, generate::object_id, generate::object_id_inspect),
+        Commands::Pass {
+            length, alpha, no_lower, no_upper, no_digits, no_symbols, min_lower, min_upper, min_digits, min_symbols,
+            include, exclude, no_lookalikes, no_repeats, max_run, bulk,
+        } => {
+            let defaults = generate::PasswordPolicy {
+                length,
+                lowercase: !no_lower,
+                uppercase: !no_upper,
+                digits: !no_digits,
+                symbols: !(alpha || no_symbols),
+                ..Default::default()
+            }
+            .one_of_each();
+            let policy = generate::PasswordPolicy {
+                min_lowercase: min_lower.unwrap_or(defaults.min_lowercase),
+                min_uppercase: min_upper.unwrap_or(defaults.min_uppercase),
+                min_digits: min_digits.unwrap_or(defaults.min_digits),
+                min_symbols: min_symbols.unwrap_or(defaults.min_symbols),
+                include: include.unwrap_or_default(),
+                exclude: exclude.unwrap_or_default(),
+                no_lookalikes,
+                no_repeats,
+                max_run,
+                ..defaults
+            };
+            let result = bulk_cmd(&bulk, |src| policy.generate(src));
+            if result.is_ok() {
+                eprintln!("Entropy: {:.1} bits (length × log2 charset)", policy.entropy());
+            }
+            result
+        }
+        Commands::Strength { password } => {
+            let password = match password {
+                Some(p) => p,
+                None => get_input(None).trim_end_matches(['\r', '\n']).to_string(),
+            };
+            Ok(strength::estimate(&password).summary())
+        }
+        Commands::Passphrase { words, wordlist, separator, case, digit, symbol, bulk } => {
+            passphrase_cmd(words, wordlist, separator, &case, digit, symbol, &bulk)
+        }
+        Commands::Randhex { bytes, bulk } => bulk_cmd(&bulk, |src| Ok(generate::random_hex(src, bytes))),
+        Commands::Randb64 { bytes, bulk } => bulk_cmd(&bulk, |src| Ok(generate::random_base64(src, bytes))),
+        Commands::GenRegex { pattern, max_repeat, unicode, bulk } => generate::RegexPattern::new(&pattern, unicode)
+            .and_then(|pattern| {
+                let pattern = pattern.max_repeat(max_repeat);
+                bulk_cmd(&bulk, |src| pattern.generate(src))
+            }),
+
+        // One-time passwords
+        Commands::Totp { secret, period, at, otp } => otp_cmd(secret, otp, period, false, None, at),
+        Commands::Hotp { secret, counter, otp } => otp_cmd(secret, otp, None, true, counter, None),
+        Commands::Otpauth { uri } => otp::parse_uri(&get_input(uri)).map(|auth| auth.summary(otp::unix_now())),
+        Commands::Otpsecret { bytes, bulk } => {
+            if bytes == 0 {
+                Err("Secret needs at least 1 byte".to_string())
             } else {
-                Ok(generate::strong_password(length))
+                bulk_cmd(&bulk, |src| Ok(otp::generate_secret(src, bytes)))
             }
         }
-        Commands::Randhex { bytes } => Ok(generate::random_hex(bytes)),
-        Commands::Randb64 { bytes } => Ok(generate::random_base64(bytes)),
 
         // Help & Info
         Commands::Info => {
@@ -551,3 +1823,420 @@ fn hash_all_cmd(file: Option<PathBuf>, string: Option<String>) -> Result<String,
         result.md5, result.sha1, result.sha256, result.sha512
     ))
 }
+
+// ============================================================================
+// JWT HELPERS
+// ============================================================================
+
+fn jwt_cmd(
+    token: Option<String>,
+    secret: Option<String>,
+    key: Option<PathBuf>,
+    sign: Option<String>,
+    alg: &str,
+    exp: Option<i64>,
+) -> Result<String, String> {
+    let is_pem = key.is_some();
+    let key_bytes = match (secret, key) {
+        (Some(s), _) => Some(s.into_bytes()),
+        (None, Some(path)) => Some(
+            std::fs::read(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?,
+        ),
+        (None, None) => None,
+    };
+
+    if let Some(claims) = sign {
+        let key = key_bytes.ok_or("Signing needs --secret or --key")?;
+        let claims = match claims.strip_prefix('@') {
+            Some(path) => std::fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path, e))?,
+            None => claims,
+        };
+        let claims = match exp {
+            Some(secs) => {
+                let mut value: serde_json::Value =
+                    serde_json::from_str(&claims).map_err(|e| format!("Claims are not JSON: {}", e))?;
+                let map = value.as_object_mut().ok_or("JWT claims must be a JSON object")?;
+                let now = chrono::Utc::now().timestamp();
+                map.insert("iat".to_string(), now.into());
+                map.insert("exp".to_string(), now.saturating_add(secs).into());
+                value.to_string()
+            }
+            None => claims,
+        };
+        return jwt::sign(&claims, alg, &key);
+    }
+
+    let token = get_input(token);
+    let mut out = jwt::decode(&token)?.summary();
+    if let Some(key) = key_bytes {
+        let key = if is_pem { jwt::VerifyKey::Pem(&key) } else { jwt::VerifyKey::Secret(&key) };
+        if jwt::verify(&token, key)? {
+            out.push_str("\nVERIFIED:  signature OK");
+        } else {
+            println!("{}", out);
+            return Err("Signature verification FAILED".to_string());
+        }
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// PROTOBUF HELPERS
+// ============================================================================
+
+fn protodec_cmd(file: Option<PathBuf>, proto: Option<PathBuf>, message: Option<String>) -> Result<String, String> {
+    let data = encode::binary_input(&get_input_bytes(file)?)?;
+    let fields = match proto {
+        Some(path) => {
+            let source = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+            let schema = protobuf::parse_proto(&source)?;
+            protobuf::decode_with(&data, &schema, message.as_deref())?
+        }
+        None => protobuf::decode(&data)?,
+    };
+    Ok(protobuf::render(&fields))
+}
+
+// ============================================================================
+// TIME HELPERS
+// ============================================================================
+
+fn time_cmd(input: Option<String>, from: &str, to: Option<String>) -> Result<String, String> {
+    let kind = time::Kind::from_name(from)?;
+    let input = match input {
+        Some(input) => input,
+        None if !io::stdin().is_terminal() => get_input(None),
+        None => "now".to_string(),
+    };
+    match to {
+        Some(target) => time::parse(&input, kind).and_then(|(nanos, _)| time::format(nanos, &target)),
+        None => time::inspect(&input, kind).map(|info| info.summary()),
+    }
+}
+
+// ============================================================================
+// UUID HELPERS
+// ============================================================================
+
+fn uuid_inspect_cmd(uuid: Option<String>, from: Option<String>, to: Option<String>) -> Result<String, String> {
+    let report = generate::uuid_inspect(&get_input(uuid), from.as_deref())?;
+    let Some(to) = to else {
+        return Ok(report.summary());
+    };
+    report
+        .forms
+        .iter()
+        .find(|(label, _)| label.eq_ignore_ascii_case(&to))
+        .map(|(_, value)| value.clone())
+        .ok_or_else(|| format!("Unknown UUID form: {} (use hyphenated, simple, braced, urn, base64, base58, integer)", to))
+}
+
+/// Generate `--count` values from `--seed` (or the system RNG) and render them in `--format`
+fn bulk_cmd(bulk: &BulkArgs, mut next: impl FnMut(&mut generate::Source) -> Result<String, String>) -> Result<String, String> {
+    let format = generate::ListFormat::from_name(&bulk.format)?;
+    let mut src = generate::Source::new(bulk.seed);
+    let values = generate::generate_many(bulk.count, bulk.unique, || next(&mut src))?;
+    if let Some(seed) = bulk.seed {
+        eprintln!("Warning: seeded output (seed {}) is reproducible by anyone; do not use it as a secret", seed);
+    }
+    Ok(generate::format_list(&values, format))
+}
+
+fn passphrase_cmd(
+    words: usize,
+    wordlist: Option<PathBuf>,
+    separator: String,
+    case: &str,
+    digit: bool,
+    symbol: bool,
+    bulk: &BulkArgs,
+) -> Result<String, String> {
+    let options = generate::PassphraseOptions { words, separator, case: generate::WordCase::from_name(case)?, digit, symbol };
+    let text = match &wordlist {
+        Some(path) => std::fs::read_to_string(path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?,
+        None => String::new(),
+    };
+    let list = match &wordlist {
+        Some(path) => {
+            let list = generate::parse_wordlist(&text);
+            if list.is_empty() {
+                return Err(format!("{} contains no words", path.display()));
+            }
+            list
+        }
+        None => generate::default_wordlist(),
+    };
+    let mut entropy = 0.0;
+    let result = bulk_cmd(bulk, |src| {
+        let (phrase, bits) = generate::passphrase(src, &list, &options)?;
+        entropy = bits;
+        Ok(phrase)
+    })?;
+    eprintln!("Entropy: {:.1} bits ({} words from a {}-word list)", entropy, words, list.len());
+    Ok(result)
+}
+
+/// TOTP or HOTP code; a URI supplies defaults that flags override
+fn otp_cmd(
+    secret: Option<String>,
+    args: OtpArgs,
+    period: Option<u64>,
+    hotp: bool,
+    counter: Option<u64>,
+    at: Option<u64>,
+) -> Result<String, String> {
+    let input = get_input(secret);
+    let input = input.trim();
+    let mut auth = if input.starts_with("otpauth://") {
+        otp::parse_uri(input)?
+    } else {
+        otp::base32_decode(input)?;
+        otp::OtpAuth { secret: input.to_string(), issuer: args.issuer.clone(), account: args.account.clone(), ..Default::default() }
+    };
+    if let Some(digits) = args.digits {
+        auth.digits = digits;
+    }
+    if let Some(name) = &args.algorithm {
+        auth.algorithm = otp::Algorithm::from_name(name)?;
+    }
+    if let Some(period) = period {
+        auth.period = period;
+    }
+    auth.counter = if hotp { counter.or(auth.counter).or(Some(0)) } else { None };
+    if args.uri {
+        return Ok(auth.to_uri());
+    }
+    let (code, remaining) = auth.code(at.unwrap_or_else(otp::unix_now))?;
+    if let Some(remaining) = remaining {
+        eprintln!("Valid for {}s", remaining);
+    }
+    Ok(code)
+}
+
+/// Generate fresh IDs, or inspect one with `inspect`
+fn id_cmd(
+    action: Option<IdAction>,
+    bulk: &BulkArgs,
+    generate: fn(&mut generate::Source) -> String,
+    inspect: fn(&str) -> Result<generate::IdReport, String>,
+) -> Result<String, String> {
+    match action {
+        None => bulk_cmd(bulk, |src| Ok(generate(src))),
+        Some(IdAction::Inspect { id }) => inspect(&get_input(id)).map(|r| r.summary()),
+    }
+}
+
+// ============================================================================
+// UNICODE HELPERS
+// ============================================================================
+
+/// Encoded bytes (with `--to`) and the text or table to print otherwise
+fn inspect_cmd(
+    text: Option<String>,
+    normalize: Option<String>,
+    from: Option<String>,
+    hex: bool,
+    base64: bool,
+    to: Option<String>,
+) -> Result<(Vec<u8>, String), String> {
+    let mut data = match text {
+        Some(t) => t.into_bytes(),
+        None => get_input_bytes(None)?,
+    };
+    if hex {
+        data = encode::hex_decode_bytes(&String::from_utf8_lossy(&data))?;
+    } else if base64 {
+        data = encode::base64_decode_bytes(&String::from_utf8_lossy(&data))?;
+    }
+    let mut text = match from {
+        Some(enc) => unicode::TextEncoding::from_name(&enc)?.decode(&data)?,
+        None => String::from_utf8(data).map_err(|e| format!("Input is not UTF-8 ({}); pass --from", e))?,
+    };
+    if let Some(ref form) = normalize {
+        text = unicode::Form::from_name(form)?.apply(&text);
+    }
+    if let Some(enc) = to {
+        return Ok((unicode::TextEncoding::from_name(&enc)?.encode(&text)?, String::new()));
+    }
+    if normalize.is_some() {
+        return Ok((Vec::new(), text));
+    }
+    Ok((Vec::new(), unicode::inspect_table(&text)))
+}
+
+// ============================================================================
+// DATA URI HELPERS
+// ============================================================================
+
+fn datauri_cmd(file: Option<PathBuf>, decode: bool, output: Option<PathBuf>) -> Result<String, String> {
+    let data = get_input_bytes(file)?;
+    if !decode {
+        return Ok(encode::data_uri_encode(&data));
+    }
+    let (mime, payload) = encode::data_uri_decode(&String::from_utf8_lossy(&data))?;
+    let path = output.unwrap_or_else(|| PathBuf::from(format!("decoded.{}", encode::mime_extension(&mime))));
+    write_new_file(&path, &payload)?;
+    Ok(format!("Wrote {} ({}, {} bytes)", path.display(), mime, payload.len()))
+}
+
+// ============================================================================
+// DATA FORMAT HELPERS
+// ============================================================================
+
+/// Converted bytes, and whether they are in a binary format
+fn convert_cmd(file: Option<PathBuf>, from: &str, to: &str) -> Result<(Vec<u8>, bool), String> {
+    let from = serial::Format::from_name(from)?;
+    let to = serial::Format::from_name(to)?;
+    let mut data = get_input_bytes(file)?;
+    if from.is_binary() {
+        data = encode::binary_input(&data)?;
+    }
+    let mut out = serial::convert(&data, from, to)?;
+    if !to.is_binary() {
+        while out.last() == Some(&b'\n') {
+            out.pop();
+        }
+    }
+    Ok((out, to.is_binary()))
+}
+
+fn query_cmd(file: Option<PathBuf>, expr: &str, format: &str, compact: bool, raw: bool) -> Result<String, String> {
+    let format = serial::Format::from_name(format)?;
+    let mut data = get_input_bytes(file)?;
+    if format.is_binary() {
+        data = encode::binary_input(&data)?;
+    }
+    let value = serial::to_json(&data, format)?;
+    let options = structured::FmtOptions { minify: compact, ..Default::default() };
+    let lines: Vec<String> = query::query(&value, expr)?
+        .iter()
+        .map(|v| match v {
+            serde_json::Value::String(s) if raw => s.clone(),
+            other => structured::write_json(other, options),
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+// ============================================================================
+// CERT HELPERS
+// ============================================================================
+
+fn cert_cmd(file: Option<PathBuf>, pem: bool, split: bool) -> Result<String, String> {
+    let data = get_input_bytes(file)?;
+    if pem {
+        let blocks = cert::read_blocks(&data)?;
+        return Ok(blocks.iter().map(|b| cert::to_pem(&b.der, &b.label)).collect::<Vec<_>>().join("\n"));
+    }
+    if split {
+        let pems = cert::split_chain(&data)?;
+        let mut written = Vec::new();
+        for (i, pem) in pems.iter().enumerate() {
+            let name = format!("cert-{}.pem", i + 1);
+            write_new_file(Path::new(&name), format!("{}\n", pem).as_bytes())?;
+            written.push(name);
+        }
+        return Ok(format!("Wrote {}", written.join(", ")));
+    }
+    let infos = cert::inspect(&data)?;
+    Ok(infos.iter().map(|i| i.summary()).collect::<Vec<_>>().join("\n\n"))
+}
+
+// ============================================================================
+// DETECT HELPERS
+// ============================================================================
+
+fn detect_cmd(data: &[u8], all: bool) -> String {
+    if all {
+        let lines: Vec<String> = detect::candidates(data)
+            .iter()
+            .map(|c| format!("{:<10} {:>5.2}  {}", c.encoding, c.score, preview(&c.output)))
+            .collect();
+        return if lines.is_empty() { "No decoder matched".to_string() } else { lines.join("\n") };
+    }
+
+    let d = detect::detect(data);
+    let body = if d.kind == "binary" || std::str::from_utf8(&d.output).is_err() {
+        encode::hexdump(&d.output, encode::DumpStyle::Xxd, 0)
+    } else {
+        String::from_utf8_lossy(&d.output).into_owned()
+    };
+    format!("Chain:  {}\n\n{}", d.chain(), body)
+}
+
+/// Single-line preview of decoded bytes
+fn preview(data: &[u8]) -> String {
+    String::from_utf8_lossy(data)
+        .chars()
+        .map(|c| if c.is_control() { '.' } else { c })
+        .take(60)
+        .collect()
+}
+
+// ============================================================================
+// CIPHER HELPERS
+// ============================================================================
+
+fn crack_cmd(input: Option<String>, xor: bool, hex: bool, top: usize) -> Result<String, String> {
+    let candidates = if xor {
+        let data = if hex {
+            encode::hex_decode_bytes(&get_input(input))?
+        } else {
+            get_input_raw(input)?
+        };
+        cipher::brute_force_xor(&data)
+    } else {
+        cipher::brute_force_rot(&get_input(input))
+    };
+
+    Ok(candidates
+        .iter()
+        .take(top)
+        .map(|c| format!("{:<6} {:>6.2}  {}", c.key, c.score, c.plaintext))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+// ============================================================================
+// NUMBER HELPERS
+// ============================================================================
+
+fn num_cmd(value: &str, from: Option<u32>, to: Option<u32>, width: Option<u32>) -> Result<String, String> {
+    let n = number::parse_int(value, from)?;
+    let r = number::convert(&n, width)?;
+    if let Some(base) = to {
+        // With a width, negative values print as their two's complement pattern
+        let pattern = match &r.unsigned {
+            Some(u) => number::parse_int(u, Some(10))?,
+            None => n,
+        };
+        return number::format_int(&pattern, base);
+    }
+
+    let mut out = format!(
+        "DEC:    {}\nHEX:    {}\nOCT:    {}\nBIN:    {}\nBASE36: {}",
+        r.decimal, r.hex, r.octal, r.binary, r.base36
+    );
+    if let (Some(signed), Some(unsigned)) = (r.signed, r.unsigned) {
+        out.push_str(&format!("\nSIGNED: {}\nUNSIGN: {}", signed, unsigned));
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// HEXDUMP HELPERS
+// ============================================================================
+
+fn hexdump_cmd(
+    file: Option<PathBuf>,
+    canonical: bool,
+    skip: Option<usize>,
+    length: Option<usize>,
+) -> Result<String, String> {
+    let data = get_input_bytes(file)?;
+    let start = skip.unwrap_or(0).min(data.len());
+    let end = length.map_or(data.len(), |n| start.saturating_add(n).min(data.len()));
+    let style = if canonical { encode::DumpStyle::Canonical } else { encode::DumpStyle::Xxd };
+    Ok(encode::hexdump(&data[start..end], style, start))
+}
@@ -0,0 +1,325 @@
+//! PEM / DER handling and X.509 certificate, CSR and public key inspection
+
+use x509_parser::certification_request::X509CertificationRequest;
+use x509_parser::extensions::{GeneralName, ParsedExtension};
+use x509_parser::objects::{oid2sn, oid_registry};
+use x509_parser::prelude::{FromDer, X509Certificate};
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+use super::{encode, hash};
+
+/// A DER object with its PEM label (`CERTIFICATE`, `PUBLIC KEY`, ...)
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub label: String,
+    pub der: Vec<u8>,
+}
+
+/// Inspection result as ordered label / value pairs
+#[derive(Debug, Clone)]
+pub struct CertInfo {
+    /// `Certificate`, `Certificate Request` or `Public Key`
+    pub kind: &'static str,
+    pub fields: Vec<(String, String)>,
+}
+
+impl CertInfo {
+    /// Aligned `Label: value` lines
+    pub fn summary(&self) -> String {
+        let mut out = format!("[{}]", self.kind);
+        for (label, value) in &self.fields {
+            out.push_str(&format!("\n  {:<24} {}", format!("{}:", label), value));
+        }
+        out
+    }
+}
+
+/// Read every PEM block, or treat the input as a single DER / base64 blob
+pub fn read_blocks(input: &[u8]) -> Result<Vec<Block>, String> {
+    let text = String::from_utf8_lossy(input);
+    let mut blocks = Vec::new();
+    let mut rest: &str = &text;
+
+    while let Some(start) = rest.find("-----BEGIN ") {
+        let after = &rest[start + 11..];
+        let label_end = after.find("-----").ok_or("Malformed PEM header")?;
+        let label = after[..label_end].to_string();
+        let footer = format!("-----END {}-----", label);
+        let after = &after[label_end + 5..];
+        let body_end = after.find(&footer).ok_or_else(|| format!("Malformed PEM: missing {}", footer))?;
+        let body: String = after[..body_end]
+            .lines()
+            .filter(|l| !l.contains(':'))
+            .collect();
+        let der = encode::base64_decode_bytes(&body).map_err(|e| format!("{}: {}", label, e))?;
+        blocks.push(Block { label, der });
+        rest = &after[body_end + footer.len()..];
+    }
+    if !blocks.is_empty() {
+        return Ok(blocks);
+    }
+
+    // DER starts with a SEQUENCE tag; anything else may be bare base64
+    let der = if input.first() == Some(&0x30) {
+        input.to_vec()
+    } else {
+        encode::base64_decode_bytes(text.trim()).map_err(|_| "Input is not PEM, DER or base64 DER".to_string())?
+    };
+    Ok(vec![Block { label: guess_label(&der).to_string(), der }])
+}
+
+fn guess_label(der: &[u8]) -> &'static str {
+    if X509Certificate::from_der(der).is_ok() {
+        "CERTIFICATE"
+    } else if X509CertificationRequest::from_der(der).is_ok() {
+        "CERTIFICATE REQUEST"
+    } else if SubjectPublicKeyInfo::from_der(der).is_ok() {
+        "PUBLIC KEY"
+    } else {
+        "UNKNOWN"
+    }
+}
+
+/// Wrap DER bytes in a PEM block with 64-column base64 lines
+pub fn to_pem(der: &[u8], label: &str) -> String {
+    let b64 = encode::base64_encode_bytes(der);
+    let lines: Vec<&str> = b64
+        .as_bytes()
+        .chunks(64)
+        .map(|c| std::str::from_utf8(c).unwrap_or_default())
+        .collect();
+    format!("-----BEGIN {label}-----\n{}\n-----END {label}-----", lines.join("\n"))
+}
+
+/// Split a PEM bundle into one PEM string per object
+pub fn split_chain(input: &[u8]) -> Result<Vec<String>, String> {
+    Ok(read_blocks(input)?.iter().map(|b| to_pem(&b.der, &b.label)).collect())
+}
+
+fn fingerprint(hex: &str) -> String {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|c| std::str::from_utf8(c).unwrap_or_default().to_uppercase())
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+fn key_description(spki: &SubjectPublicKeyInfo) -> String {
+    let registry = oid_registry();
+    let alg = &spki.algorithm.algorithm;
+    let name = oid2sn(alg, registry).map(str::to_string).unwrap_or_else(|_| alg.to_id_string());
+    let curve = spki
+        .algorithm
+        .parameters
+        .as_ref()
+        .and_then(|p| p.as_oid().ok())
+        .filter(|oid| !oid.as_bytes().is_empty())
+        .map(|oid| oid2sn(&oid, registry).map(str::to_string).unwrap_or_else(|_| oid.to_id_string()));
+    let bits = spki.parsed().map(|k| k.key_size()).unwrap_or(0);
+
+    let mut out = name;
+    if let Some(curve) = curve {
+        out.push_str(&format!(" ({})", curve));
+    }
+    if bits > 0 {
+        out.push_str(&format!(", {} bits", bits));
+    }
+    out
+}
+
+fn general_name(name: &GeneralName) -> String {
+    match name {
+        GeneralName::DNSName(s) => format!("DNS:{}", s),
+        GeneralName::RFC822Name(s) => format!("email:{}", s),
+        GeneralName::URI(s) => format!("URI:{}", s),
+        GeneralName::IPAddress(ip) => match ip.len() {
+            4 => format!("IP:{}", std::net::Ipv4Addr::new(ip[0], ip[1], ip[2], ip[3])),
+            16 => {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(ip);
+                format!("IP:{}", std::net::Ipv6Addr::from(octets))
+            }
+            _ => format!("IP:{}", hex::encode(ip)),
+        },
+        other => other.to_string(),
+    }
+}
+
+fn extension_value(ext: &ParsedExtension) -> String {
+    match ext {
+        ParsedExtension::BasicConstraints(bc) => match bc.path_len_constraint {
+            Some(n) => format!("CA:{}, pathlen:{}", bc.ca, n),
+            None => format!("CA:{}", bc.ca),
+        },
+        ParsedExtension::KeyUsage(ku) => ku.to_string(),
+        ParsedExtension::ExtendedKeyUsage(eku) => {
+            let flags = [
+                (eku.server_auth, "serverAuth"),
+                (eku.client_auth, "clientAuth"),
+                (eku.code_signing, "codeSigning"),
+                (eku.email_protection, "emailProtection"),
+                (eku.time_stamping, "timeStamping"),
+                (eku.ocsp_signing, "OCSPSigning"),
+                (eku.any, "any"),
+            ];
+            let mut names: Vec<String> = flags.iter().filter(|(on, _)| *on).map(|(_, n)| n.to_string()).collect();
+            names.extend(eku.other.iter().map(|o| o.to_id_string()));
+            names.join(", ")
+        }
+        ParsedExtension::SubjectAlternativeName(san) => {
+            san.general_names.iter().map(general_name).collect::<Vec<_>>().join(", ")
+        }
+        ParsedExtension::SubjectKeyIdentifier(id) => fingerprint(&hex::encode(id.0)),
+        ParsedExtension::AuthorityKeyIdentifier(aki) => aki
+            .key_identifier
+            .as_ref()
+            .map(|id| fingerprint(&hex::encode(id.0)))
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+fn inspect_certificate(der: &[u8]) -> Result<CertInfo, String> {
+    let (_, cert) = X509Certificate::from_der(der).map_err(|e| format!("Certificate parse error: {}", e))?;
+    let registry = oid_registry();
+    let validity = cert.validity();
+    let now = chrono::Utc::now().timestamp();
+    let status = if now < validity.not_before.timestamp() {
+        "NOT YET VALID"
+    } else if now > validity.not_after.timestamp() {
+        "EXPIRED"
+    } else {
+        "VALID"
+    };
+
+    let mut fields = vec![
+        ("Subject".to_string(), cert.subject().to_string()),
+        ("Issuer".to_string(), cert.issuer().to_string()),
+        ("Serial".to_string(), cert.raw_serial_as_string()),
+        ("Version".to_string(), format!("v{}", cert.version().0 + 1)),
+        ("Not Before".to_string(), validity.not_before.to_string()),
+        ("Not After".to_string(), validity.not_after.to_string()),
+        ("Status".to_string(), status.to_string()),
+        ("Public Key".to_string(), key_description(cert.public_key())),
+        (
+            "Signature".to_string(),
+            oid2sn(&cert.signature_algorithm.algorithm, registry)
+                .map(str::to_string)
+                .unwrap_or_else(|_| cert.signature_algorithm.algorithm.to_id_string()),
+        ),
+    ];
+
+    if let Ok(Some(san)) = cert.subject_alternative_name() {
+        let names: Vec<String> = san.value.general_names.iter().map(general_name).collect();
+        fields.push(("SANs".to_string(), names.join(", ")));
+    }
+    // SANs are listed above, everything else goes by its short name
+    for ext in cert.extensions() {
+        if let ParsedExtension::SubjectAlternativeName(_) = ext.parsed_extension() {
+            continue;
+        }
+        let name = oid2sn(&ext.oid, registry).map(str::to_string).unwrap_or_else(|_| ext.oid.to_id_string());
+        let critical = if ext.critical { " (critical)" } else { "" };
+        fields.push((format!("{}{}", name, critical), extension_value(ext.parsed_extension())));
+    }
+
+    let hashes = hash::hash_all(der);
+    fields.push(("SHA-1".to_string(), fingerprint(&hashes.sha1)));
+    fields.push(("SHA-256".to_string(), fingerprint(&hashes.sha256)));
+    Ok(CertInfo { kind: "Certificate", fields })
+}
+
+fn inspect_csr(der: &[u8]) -> Result<CertInfo, String> {
+    let (_, csr) = X509CertificationRequest::from_der(der).map_err(|e| format!("CSR parse error: {}", e))?;
+    let info = &csr.certification_request_info;
+    let mut fields = vec![
+        ("Subject".to_string(), info.subject.to_string()),
+        ("Public Key".to_string(), key_description(&info.subject_pki)),
+        (
+            "Signature".to_string(),
+            oid2sn(&csr.signature_algorithm.algorithm, oid_registry())
+                .map(str::to_string)
+                .unwrap_or_else(|_| csr.signature_algorithm.algorithm.to_id_string()),
+        ),
+    ];
+    if let Some(exts) = csr.requested_extensions() {
+        for ext in exts {
+            if let ParsedExtension::SubjectAlternativeName(_) = ext {
+                fields.push(("SANs".to_string(), extension_value(ext)));
+            }
+        }
+    }
+    fields.push(("SHA-256".to_string(), fingerprint(&hash::hash_all(der).sha256)));
+    Ok(CertInfo { kind: "Certificate Request", fields })
+}
+
+fn inspect_public_key(der: &[u8]) -> Result<CertInfo, String> {
+    let (_, spki) = SubjectPublicKeyInfo::from_der(der).map_err(|e| format!("Public key parse error: {}", e))?;
+    let hashes = hash::hash_all(der);
+    Ok(CertInfo {
+        kind: "Public Key",
+        fields: vec![
+            ("Type".to_string(), key_description(&spki)),
+            ("SHA-256".to_string(), fingerprint(&hashes.sha256)),
+        ],
+    })
+}
+
+/// Inspect every certificate, CSR or public key in the input
+pub fn inspect(input: &[u8]) -> Result<Vec<CertInfo>, String> {
+    read_blocks(input)?
+        .iter()
+        .map(|block| match block.label.as_str() {
+            "CERTIFICATE" | "TRUSTED CERTIFICATE" | "X509 CERTIFICATE" => inspect_certificate(&block.der),
+            "CERTIFICATE REQUEST" | "NEW CERTIFICATE REQUEST" => inspect_csr(&block.der),
+            "PUBLIC KEY" => inspect_public_key(&block.der),
+            other => Err(format!("Unsupported PEM type: {}", other)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERT: &str = "-----BEGIN CERTIFICATE-----
+MIIB+jCCAaCgAwIBAgIUL5czDu5XBSljUziPm2sHOI8P0/gwCgYIKoZIzj0EAwIw
+MTEcMBoGA1UEAwwTcXVpY2t0cmFuc2Zvcm0udGVzdDERMA8GA1UECgwITEFaWUZS
+T0cwIBcNMjYxMDE4MjAzNjM2WhgPMjEyNjA5MjQyMDM2MzZaMDExHDAaBgNVBAMM
+E3F1aWNrdHJhbnNmb3JtLnRlc3QxETAPBgNVBAoMCExBWllGUk9HMFkwEwYHKoZI
+zj0CAQYIKoZIzj0DAQcDQgAEB41HOnLHOEkdOTJSd9Csn+8L1ZkadKheanAJvcQ6
+asEnjrYPeh8hijRl60nE4jgQopKLLXjaCoP5pJoqn/n9vKOBkzCBkDAdBgNVHQ4E
+FgQUdfMmTOKMgBMzG8CyPCVCUiOpghkwHwYDVR0jBBgwFoAUdfMmTOKMgBMzG8Cy
+PCVCUiOpghkwDwYDVR0TAQH/BAUwAwEB/zA9BgNVHREENjA0ghNxdWlja3RyYW5z
+Zm9ybS50ZXN0ghd3d3cucXVpY2t0cmFuc2Zvcm0udGVzdIcEfwAAATAKBggqhkjO
+PQQDAgNIADBFAiAkn4uNx9f171+7wLqPQVQJZdCgSGWG/8icAVBTe2K2jgIhAN53
+26uXWhK4u53dik7Oh1uf62WSNRTuKdI3+l+94Wep
+-----END CERTIFICATE-----
+";
+
+    #[test]
+    fn test_inspect_certificate() {
+        let info = &inspect(CERT.as_bytes()).unwrap()[0];
+        let field = |name: &str| info.fields.iter().find(|(l, _)| l == name).map(|(_, v)| v.as_str());
+        assert_eq!(field("Subject"), Some("CN=quicktransform.test, O=LAZYFROG"));
+        assert_eq!(
+            field("SANs"),
+            Some("DNS:quicktransform.test, DNS:www.quicktransform.test, IP:127.0.0.1")
+        );
+        assert!(field("Public Key").unwrap().contains("256 bits"));
+        assert!(field("SHA-256").unwrap().starts_with("51:CF:06:54"));
+    }
+
+    #[test]
+    fn test_pem_der_roundtrip() {
+        let blocks = read_blocks(CERT.as_bytes()).unwrap();
+        let pem = to_pem(&blocks[0].der, "CERTIFICATE");
+        assert_eq!(pem.trim(), CERT.trim());
+        // Raw DER is recognised without a label
+        assert_eq!(read_blocks(&blocks[0].der).unwrap()[0].label, "CERTIFICATE");
+        let bundle = format!("{}{}", CERT, CERT);
+        assert_eq!(split_chain(bundle.as_bytes()).unwrap().len(), 2);
+        // The footer overlaps the header
+        assert_eq!(read_blocks(b"-----BEGIN X-----END X-----").unwrap_err(), "Malformed PEM: missing -----END X-----");
+    }
+}
@@ -0,0 +1,216 @@
+//! Classical ciphers, XOR and brute-force helpers
+
+/// Brute-force candidate ranked by English likeness
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    /// Key that produced this candidate (`13` for ROT13, `0x2a` for XOR)
+    pub key: String,
+    /// English likeness score (higher is better)
+    pub score: f64,
+    /// Decoded text, with non-printable bytes shown as `.`
+    pub plaintext: String,
+}
+
+/// English letter frequencies (percent), a-z
+const ENGLISH_FREQ: [f64; 26] = [
+    8.2, 1.5, 2.8, 4.3, 12.7, 2.2, 2.0, 6.1, 7.0, 0.15, 0.77, 4.0, 2.4,
+    6.7, 7.5, 1.9, 0.095, 6.0, 6.3, 9.1, 2.8, 0.98, 2.4, 0.15, 2.0, 0.074,
+];
+
+fn shift_letter(c: char, shift: i32) -> char {
+    let base = if c.is_ascii_lowercase() { b'a' } else if c.is_ascii_uppercase() { b'A' } else { return c };
+    let offset = (c as u8 - base) as i32;
+    (base + (offset + shift.rem_euclid(26)).rem_euclid(26) as u8) as char
+}
+
+/// Rotate letters by `shift` positions (Caesar cipher)
+pub fn rot(input: &str, shift: i32) -> String {
+    input.chars().map(|c| shift_letter(c, shift)).collect()
+}
+
+/// ROT13 (its own inverse)
+pub fn rot13(input: &str) -> String {
+    rot(input, 13)
+}
+
+/// Atbash: mirror the alphabet (a ↔ z)
+pub fn atbash(input: &str) -> String {
+    input
+        .chars()
+        .map(|c| match c {
+            'a'..='z' => (b'z' - (c as u8 - b'a')) as char,
+            'A'..='Z' => (b'Z' - (c as u8 - b'A')) as char,
+            _ => c,
+        })
+        .collect()
+}
+
+fn vigenere(input: &str, key: &str, decrypt: bool) -> Result<String, String> {
+    let shifts: Vec<i32> = key
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| (c.to_ascii_lowercase() as u8 - b'a') as i32)
+        .collect();
+    if shifts.is_empty() {
+        return Err("Vigenère key must contain letters".to_string());
+    }
+
+    // Only letters consume key positions
+    let mut i = 0;
+    Ok(input
+        .chars()
+        .map(|c| {
+            if !c.is_ascii_alphabetic() {
+                return c;
+            }
+            let s = shifts[i % shifts.len()];
+            i += 1;
+            shift_letter(c, if decrypt { -s } else { s })
+        })
+        .collect())
+}
+
+/// Vigenère encrypt with a letter key
+pub fn vigenere_encrypt(input: &str, key: &str) -> Result<String, String> {
+    vigenere(input, key, false)
+}
+
+/// Vigenère decrypt with a letter key
+pub fn vigenere_decrypt(input: &str, key: &str) -> Result<String, String> {
+    vigenere(input, key, true)
+}
+
+fn mod_inverse_26(a: i32) -> Option<i32> {
+    (1..26).find(|x| (a.rem_euclid(26) * x).rem_euclid(26) == 1)
+}
+
+/// Affine encrypt: `E(x) = (a·x + b) mod 26`
+pub fn affine_encrypt(input: &str, a: i32, b: i32) -> Result<String, String> {
+    if mod_inverse_26(a).is_none() {
+        return Err(format!("Affine key a={} must be coprime with 26", a));
+    }
+    // Keys reduced mod 26 so large values can't overflow
+    let (a, b) = (a.rem_euclid(26), b.rem_euclid(26));
+    Ok(input.chars().map(|c| affine_map(c, |x| a * x + b)).collect())
+}
+
+/// Affine decrypt: `D(y) = a⁻¹·(y - b) mod 26`
+pub fn affine_decrypt(input: &str, a: i32, b: i32) -> Result<String, String> {
+    let inv = mod_inverse_26(a)
+        .ok_or_else(|| format!("Affine key a={} must be coprime with 26", a))?;
+    let b = b.rem_euclid(26);
+    Ok(input.chars().map(|c| affine_map(c, |y| inv * (y - b))).collect())
+}
+
+fn affine_map(c: char, f: impl Fn(i32) -> i32) -> char {
+    let base = if c.is_ascii_lowercase() { b'a' } else if c.is_ascii_uppercase() { b'A' } else { return c };
+    let x = (c as u8 - base) as i32;
+    (base + f(x).rem_euclid(26) as u8) as char
+}
+
+/// XOR data with a repeating key (single or multi-byte)
+pub fn xor(data: &[u8], key: &[u8]) -> Result<Vec<u8>, String> {
+    if key.is_empty() {
+        return Err("XOR key must not be empty".to_string());
+    }
+    Ok(data.iter().zip(key.iter().cycle()).map(|(d, k)| d ^ k).collect())
+}
+
+/// Score how English-like some bytes are (higher is better)
+///
+/// Letters add their frequency, spaces count as a common letter, and
+/// control or non-ASCII bytes are penalised.
+pub fn english_score(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
+    }
+    let total: f64 = data
+        .iter()
+        .map(|&b| match b {
+            b'a'..=b'z' => ENGLISH_FREQ[(b - b'a') as usize],
+            b'A'..=b'Z' => ENGLISH_FREQ[(b - b'A') as usize] * 0.8,
+            b' ' => 13.0,
+            b'0'..=b'9' | b'.' | b',' | b'\'' | b'"' | b'!' | b'?' | b'-' => 1.0,
+            b'\n' | b'\r' | b'\t' => 0.5,
+            _ if b.is_ascii_graphic() => -1.0,
+            _ => -10.0,
+        })
+        .sum();
+    total / data.len() as f64
+}
+
+fn printable(data: &[u8]) -> String {
+    data.iter()
+        .map(|&b| if (0x20..0x7f).contains(&b) { b as char } else { '.' })
+        .collect()
+}
+
+fn ranked(mut candidates: Vec<Candidate>) -> Vec<Candidate> {
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates
+}
+
+/// Try all 25 ROT shifts, best English match first
+pub fn brute_force_rot(input: &str) -> Vec<Candidate> {
+    ranked(
+        (1..26)
+            .map(|shift| {
+                let text = rot(input, shift);
+                Candidate { key: shift.to_string(), score: english_score(text.as_bytes()), plaintext: text }
+            })
+            .collect(),
+    )
+}
+
+/// Try all 256 single-byte XOR keys, best English match first
+pub fn brute_force_xor(data: &[u8]) -> Vec<Candidate> {
+    ranked(
+        (0..=255u8)
+            .map(|k| {
+                let plain: Vec<u8> = data.iter().map(|b| b ^ k).collect();
+                Candidate { key: format!("0x{:02x}", k), score: english_score(&plain), plaintext: printable(&plain) }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rot_and_atbash() {
+        assert_eq!(rot13("Hello, World!"), "Uryyb, Jbeyq!");
+        assert_eq!(rot(&rot("abcxyz", 3), -3), "abcxyz");
+        assert_eq!(atbash("Hello"), "Svool");
+    }
+
+    #[test]
+    fn test_vigenere_and_affine() {
+        let enc = vigenere_encrypt("ATTACK AT DAWN", "LEMON").unwrap();
+        assert_eq!(enc, "LXFOPV EF RNHR");
+        assert_eq!(vigenere_decrypt(&enc, "LEMON").unwrap(), "ATTACK AT DAWN");
+
+        let enc = affine_encrypt("affine cipher", 5, 8).unwrap();
+        assert_eq!(enc, "ihhwvc swfrcp");
+        assert_eq!(affine_decrypt(&enc, 5, 8).unwrap(), "affine cipher");
+        assert!(affine_encrypt("x", 13, 1).is_err());
+        // Large keys wrap mod 26 instead of overflowing
+        assert_eq!(rot("abc", i32::MAX), rot("abc", i32::MAX % 26));
+        assert_eq!(rot("abc", i32::MIN), rot("abc", i32::MIN.rem_euclid(26)));
+        let enc = affine_encrypt("affine cipher", 5 + 26 * 80_000_000, i32::MAX).unwrap();
+        assert_eq!(enc, affine_encrypt("affine cipher", 5, i32::MAX % 26).unwrap());
+        assert_eq!(affine_decrypt(&enc, 5 + 26 * 80_000_000, i32::MAX).unwrap(), "affine cipher");
+    }
+
+    #[test]
+    fn test_brute_force() {
+        let secret = rot("the quick brown fox jumps over the lazy dog", 7);
+        assert_eq!(brute_force_rot(&secret)[0].key, "19");
+
+        let data = xor(b"Cooking MC's like a pound of bacon", &[0x58]).unwrap();
+        let best = &brute_force_xor(&data)[0];
+        assert_eq!(best.key, "0x58");
+        assert_eq!(best.plaintext, "Cooking MC's like a pound of bacon");
+    }
+}
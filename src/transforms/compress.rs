@@ -0,0 +1,230 @@
+//! Compression and decompression: gzip, zlib, deflate, zstd, brotli, bzip2, xz, lzma
+
+use std::io::{Read, Write};
+
+/// Supported compression formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Gzip,
+    Zlib,
+    /// Raw deflate stream (no header)
+    Deflate,
+    Zstd,
+    Brotli,
+    Bzip2,
+    Xz,
+    /// Legacy `.lzma` (LZMA-alone) stream
+    Lzma,
+}
+
+/// All formats, in the order they are listed to users
+pub const FORMATS: [Format; 8] = [
+    Format::Gzip,
+    Format::Zlib,
+    Format::Deflate,
+    Format::Zstd,
+    Format::Brotli,
+    Format::Bzip2,
+    Format::Xz,
+    Format::Lzma,
+];
+
+impl Format {
+    /// Short lowercase name (`gzip`, `zstd`, ...)
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Gzip => "gzip",
+            Format::Zlib => "zlib",
+            Format::Deflate => "deflate",
+            Format::Zstd => "zstd",
+            Format::Brotli => "brotli",
+            Format::Bzip2 => "bzip2",
+            Format::Xz => "xz",
+            Format::Lzma => "lzma",
+        }
+    }
+
+    /// Parse a format name or common alias (`gz`, `zst`, `br`, `bz2`)
+    pub fn from_name(name: &str) -> Result<Format, String> {
+        match name.to_lowercase().as_str() {
+            "gzip" | "gz" => Ok(Format::Gzip),
+            "zlib" => Ok(Format::Zlib),
+            "deflate" | "raw" => Ok(Format::Deflate),
+            "zstd" | "zst" => Ok(Format::Zstd),
+            "brotli" | "br" => Ok(Format::Brotli),
+            "bzip2" | "bz2" => Ok(Format::Bzip2),
+            "xz" => Ok(Format::Xz),
+            "lzma" => Ok(Format::Lzma),
+            _ => Err(format!("Unknown compression format: {}", name)),
+        }
+    }
+
+    /// Valid compression levels (inclusive) and the default level
+    pub fn levels(self) -> (u32, u32, u32) {
+        match self {
+            Format::Gzip | Format::Zlib | Format::Deflate => (0, 9, 6),
+            Format::Zstd => (1, 22, 3),
+            Format::Brotli => (0, 11, 6),
+            Format::Bzip2 => (1, 9, 9),
+            Format::Xz | Format::Lzma => (0, 9, 6),
+        }
+    }
+}
+
+/// Largest output [`decompress`] produces (256 MiB), so a small bomb can't exhaust memory
+pub const MAX_DECOMPRESSED: u64 = 256 * 1024 * 1024;
+
+/// zlib header: deflate with a 32K window (CMF 0x78), no preset dictionary,
+/// and CMF/FLG divisible by 31. Some text passes too, so callers that guess
+/// should also check that the stream inflates.
+fn is_zlib_header(data: &[u8]) -> bool {
+    data.len() > 2 && data[0] == 0x78 && data[1] & 0x20 == 0 && (u16::from(data[0]) << 8 | u16::from(data[1])) % 31 == 0
+}
+
+/// Identify a compressed stream by its magic bytes
+///
+/// Raw deflate and brotli have no magic number and are never detected.
+pub fn detect_format(data: &[u8]) -> Option<Format> {
+    if data.starts_with(&[0x1f, 0x8b]) {
+        Some(Format::Gzip)
+    } else if data.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+        Some(Format::Zstd)
+    } else if data.starts_with(b"BZh") {
+        Some(Format::Bzip2)
+    } else if data.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+        Some(Format::Xz)
+    } else if is_zlib_header(data) {
+        Some(Format::Zlib)
+    } else if data.len() > 13 && data[0] == 0x5d && data[1] == 0x00 && data[2] == 0x00 {
+        Some(Format::Lzma)
+    } else {
+        None
+    }
+}
+
+/// Compress data, using the format's default level when `level` is `None`
+pub fn compress(data: &[u8], format: Format, level: Option<u32>) -> Result<Vec<u8>, String> {
+    let (min, max, default) = format.levels();
+    let level = level.unwrap_or(default);
+    if !(min..=max).contains(&level) {
+        return Err(format!("{} level must be {}-{}", format.name(), min, max));
+    }
+    let err = |e: std::io::Error| format!("{} compress error: {}", format.name(), e);
+
+    match format {
+        Format::Gzip => {
+            let mut enc = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::new(level));
+            enc.write_all(data).map_err(err)?;
+            enc.finish().map_err(err)
+        }
+        Format::Zlib => {
+            let mut enc = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::new(level));
+            enc.write_all(data).map_err(err)?;
+            enc.finish().map_err(err)
+        }
+        Format::Deflate => {
+            let mut enc = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::new(level));
+            enc.write_all(data).map_err(err)?;
+            enc.finish().map_err(err)
+        }
+        Format::Zstd => zstd::encode_all(data, level as i32).map_err(err),
+        Format::Brotli => {
+            let mut out = Vec::new();
+            {
+                let mut enc = brotli::CompressorWriter::new(&mut out, 4096, level, 22);
+                enc.write_all(data).map_err(err)?;
+            }
+            Ok(out)
+        }
+        Format::Bzip2 => {
+            let mut enc = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::new(level));
+            enc.write_all(data).map_err(err)?;
+            enc.finish().map_err(err)
+        }
+        Format::Xz => {
+            let mut enc = xz2::write::XzEncoder::new(Vec::new(), level);
+            enc.write_all(data).map_err(err)?;
+            enc.finish().map_err(err)
+        }
+        Format::Lzma => {
+            let options = xz2::stream::LzmaOptions::new_preset(level)
+                .map_err(|e| format!("lzma compress error: {}", e))?;
+            let stream = xz2::stream::Stream::new_lzma_encoder(&options)
+                .map_err(|e| format!("lzma compress error: {}", e))?;
+            let mut enc = xz2::write::XzEncoder::new_stream(Vec::new(), stream);
+            enc.write_all(data).map_err(err)?;
+            enc.finish().map_err(err)
+        }
+    }
+}
+
+/// Decompress data in the given format, up to [`MAX_DECOMPRESSED`] bytes
+pub fn decompress(data: &[u8], format: Format) -> Result<Vec<u8>, String> {
+    decompress_limited(data, format, MAX_DECOMPRESSED)
+}
+
+fn decompress_limited(data: &[u8], format: Format, limit: u64) -> Result<Vec<u8>, String> {
+    let err = |e: std::io::Error| format!("{} decompress error: {}", format.name(), e);
+    let reader: Box<dyn Read + '_> = match format {
+        Format::Gzip => Box::new(flate2::read::MultiGzDecoder::new(data)),
+        Format::Zlib => Box::new(flate2::read::ZlibDecoder::new(data)),
+        Format::Deflate => Box::new(flate2::read::DeflateDecoder::new(data)),
+        Format::Zstd => Box::new(zstd::stream::read::Decoder::new(data).map_err(err)?),
+        Format::Brotli => Box::new(brotli::Decompressor::new(data, 4096)),
+        Format::Bzip2 => Box::new(bzip2::read::MultiBzDecoder::new(data)),
+        Format::Xz => Box::new(xz2::read::XzDecoder::new_multi_decoder(data)),
+        Format::Lzma => {
+            let stream = xz2::stream::Stream::new_lzma_decoder(u64::MAX).map_err(|e| err(e.into()))?;
+            Box::new(xz2::read::XzDecoder::new_stream(data, stream))
+        }
+    };
+    // One byte past the limit tells a full-size output from a truncated one
+    let mut out = Vec::new();
+    reader.take(limit + 1).read_to_end(&mut out).map_err(err)?;
+    if out.len() as u64 > limit {
+        return Err(format!("{} output exceeds the {} MiB limit", format.name(), limit / (1024 * 1024)));
+    }
+    Ok(out)
+}
+
+/// Detect the format from magic bytes and decompress
+pub fn decompress_auto(data: &[u8]) -> Result<(Format, Vec<u8>), String> {
+    let format = detect_format(data).ok_or_else(|| {
+        "Unknown compression format (deflate and brotli need an explicit format)".to_string()
+    })?;
+    decompress(data, format).map(|out| (format, out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_all_formats() {
+        let data = br#"{"message":"hello hello hello hello hello"}"#;
+        for format in FORMATS {
+            let packed = compress(data, format, None).unwrap();
+            assert_eq!(decompress(&packed, format).unwrap(), data, "{}", format.name());
+        }
+    }
+
+    #[test]
+    fn test_detect_format() {
+        for format in [Format::Gzip, Format::Zlib, Format::Zstd, Format::Bzip2, Format::Xz, Format::Lzma] {
+            let packed = compress(b"detect me", format, Some(1)).unwrap();
+            assert_eq!(detect_format(&packed), Some(format));
+            assert_eq!(decompress_auto(&packed).unwrap().1, b"detect me");
+        }
+        assert!(compress(b"x", Format::Gzip, Some(10)).is_err());
+        assert_eq!(detect_format(b"x marks the spot"), None);
+    }
+
+    #[test]
+    fn test_output_limit() {
+        let bomb = compress(&vec![0u8; 4 * 1024 * 1024], Format::Gzip, None).unwrap();
+        assert!(bomb.len() < 16 * 1024);
+        let err = decompress_limited(&bomb, Format::Gzip, 1024 * 1024).unwrap_err();
+        assert!(err.contains("exceeds the 1 MiB limit"), "{}", err);
+        assert_eq!(decompress_limited(&bomb, Format::Gzip, 4 * 1024 * 1024).unwrap().len(), 4 * 1024 * 1024);
+    }
+}
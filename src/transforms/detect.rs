@@ -0,0 +1,249 @@
+//! Automatic encoding detection and recursive "magic" decoding
+
+use super::{compress, encode};
+
+/// Maximum number of layers peeled before giving up
+const MAX_DEPTH: usize = 16;
+
+/// One decoding step in a detected chain
+#[derive(Debug, Clone)]
+pub struct Layer {
+    /// Decoder name (`base64`, `hex`, `url`, ...)
+    pub encoding: &'static str,
+    /// Bytes produced by this step
+    pub output: Vec<u8>,
+}
+
+/// Result of peeling every recognisable layer
+#[derive(Debug, Clone)]
+pub struct Detection {
+    /// Decoders applied, outermost first
+    pub layers: Vec<Layer>,
+    /// What the innermost data looks like (`text`, `JSON`, `gzip`, ...)
+    pub kind: &'static str,
+    /// Innermost data
+    pub output: Vec<u8>,
+}
+
+impl Detection {
+    /// Human-readable chain such as `base64 → hex → text`
+    pub fn chain(&self) -> String {
+        let mut parts: Vec<&str> = self.layers.iter().map(|l| l.encoding).collect();
+        parts.push(self.kind);
+        parts.join(" → ")
+    }
+}
+
+/// Single-layer decode attempt with its score
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub encoding: &'static str,
+    pub output: Vec<u8>,
+    pub score: f64,
+}
+
+/// Identify binary formats and structured text by magic bytes or shape
+pub fn sniff(data: &[u8]) -> Option<&'static str> {
+    const MAGIC: &[(&[u8], &str)] = &[
+        (&[0x1f, 0x8b], "gzip"),
+        (&[0x28, 0xb5, 0x2f, 0xfd], "zstd"),
+        (b"BZh", "bzip2"),
+        (&[0xfd, b'7', b'z', b'X', b'Z', 0x00], "xz"),
+        (&[0x89, b'P', b'N', b'G'], "PNG"),
+        (&[0xff, 0xd8, 0xff], "JPEG"),
+        (b"GIF8", "GIF"),
+        (b"%PDF", "PDF"),
+        (b"PK\x03\x04", "ZIP"),
+    ];
+    if let Some((_, name)) = MAGIC.iter().find(|(m, _)| data.starts_with(m)) {
+        return Some(name);
+    }
+    if is_zlib(data) {
+        return Some("zlib");
+    }
+
+    let text = std::str::from_utf8(data).ok()?.trim();
+    let wrapped = |open: char, close: char| text.starts_with(open) && text.ends_with(close);
+    if text.len() >= 2 && (wrapped('{', '}') || wrapped('[', ']')) {
+        Some("JSON")
+    } else if text.len() >= 2 && wrapped('<', '>') {
+        Some("XML/HTML")
+    } else {
+        None
+    }
+}
+
+/// zlib has no real magic number, so text like "x^..." passes the header
+/// check; only a stream that also inflates counts
+fn is_zlib(data: &[u8]) -> bool {
+    compress::detect_format(data) == Some(compress::Format::Zlib) && compress::decompress(data, compress::Format::Zlib).is_ok()
+}
+
+/// Fraction of the data that is readable text (0.0 - 1.0)
+pub fn printability(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
+    }
+    match std::str::from_utf8(data) {
+        Ok(text) => {
+            let total = text.chars().count();
+            let readable = text
+                .chars()
+                .filter(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+                .count();
+            readable as f64 / total as f64
+        }
+        Err(_) => {
+            let readable = data
+                .iter()
+                .filter(|&&b| (0x20..0x7f).contains(&b) || matches!(b, b'\n' | b'\r' | b'\t'))
+                .count();
+            // Invalid UTF-8 is never fully readable
+            readable as f64 / data.len() as f64 * 0.9
+        }
+    }
+}
+
+fn score(data: &[u8]) -> f64 {
+    match sniff(data) {
+        Some(_) => 1.5,
+        None => printability(data),
+    }
+}
+
+fn is_hex_text(s: &str) -> bool {
+    let digits: Vec<char> = s.chars().filter(|c| !c.is_whitespace() && *c != ':').collect();
+    !digits.is_empty() && digits.len() % 2 == 0 && digits.iter().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_base64_text(s: &str) -> bool {
+    let body = s.trim_end_matches('=');
+    body.len() >= 4
+        && body.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '-' | '_'))
+}
+
+/// Try every decoder once, best-scoring result first
+pub fn candidates(input: &[u8]) -> Vec<Candidate> {
+    let Ok(text) = std::str::from_utf8(input) else {
+        return Vec::new();
+    };
+    let text = text.trim();
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let mut found: Vec<(&'static str, Vec<u8>)> = Vec::new();
+    if text.chars().all(|c| matches!(c, '0' | '1' | ' ')) && text.len() >= 8 {
+        if let Ok(b) = encode::binary_decode(text) {
+            found.push(("binary", b));
+        }
+    }
+    if is_hex_text(text) {
+        if let Ok(b) = encode::hex_decode_bytes(text) {
+            found.push(("hex", b));
+        }
+    }
+    let compact: String = text.split_whitespace().collect();
+    if is_base64_text(&compact) {
+        if let Ok(b) = encode::base64_decode_bytes(&compact) {
+            let name = if compact.contains(['-', '_']) { "base64url" } else { "base64" };
+            found.push((name, b));
+        }
+    }
+    if text.contains('%') {
+        if let Ok(s) = encode::url_decode(text) {
+            if s != text {
+                found.push(("url", s.into_bytes()));
+            }
+        }
+    }
+    if text.contains('&') && text.contains(';') {
+        let s = encode::html_decode(text);
+        if s != text {
+            found.push(("html", s.into_bytes()));
+        }
+    }
+    if text.split([' ', ',']).filter(|t| !t.is_empty()).count() >= 2
+        && text.chars().all(|c| c.is_ascii_digit() || matches!(c, ' ' | ','))
+    {
+        if let Ok(b) = encode::decimal_decode(text) {
+            found.push(("decimal", b));
+        }
+    }
+
+    let mut ranked: Vec<Candidate> = found
+        .into_iter()
+        .filter(|(_, out)| !out.is_empty())
+        .map(|(encoding, output)| Candidate { encoding, score: score(&output), output })
+        .collect();
+    // Stable sort keeps the more specific decoder first on ties
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked
+}
+
+/// Peel nested encodings until nothing decodes to something more readable
+pub fn detect(input: &[u8]) -> Detection {
+    let mut layers = Vec::new();
+    let mut current = input.to_vec();
+
+    while layers.len() < MAX_DEPTH {
+        // Compressed streams are inflated and peeling continues inside them
+        // A magic-byte match that fails to inflate is treated as plain data
+        let inflated = compress::detect_format(&current)
+            .and_then(|format| Some((format, compress::decompress(&current, format).ok()?)));
+        if let Some((format, inflated)) = inflated {
+            current = inflated.clone();
+            layers.push(Layer { encoding: format.name(), output: inflated });
+            continue;
+        }
+        if sniff(&current).is_some() {
+            break;
+        }
+
+        let Some(best) = candidates(&current)
+            .into_iter()
+            .find(|c| c.score >= 0.95 || sniff(&c.output).is_some())
+        else {
+            break;
+        };
+        current = best.output.clone();
+        layers.push(Layer { encoding: best.encoding, output: best.output });
+    }
+
+    let kind = sniff(&current).unwrap_or(if printability(&current) >= 0.95 { "text" } else { "binary" });
+    Detection { layers, kind, output: current }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_layers() {
+        let inner = encode::hex_encode(r#"{"user":"frog"}"#);
+        let outer = encode::base64_encode(&inner);
+        let d = detect(outer.as_bytes());
+        assert_eq!(d.chain(), "base64 → hex → JSON");
+        assert_eq!(d.output, br#"{"user":"frog"}"#);
+    }
+
+    #[test]
+    fn test_single_layers() {
+        assert_eq!(detect(b"48656c6c6f20776f726c64").chain(), "hex → text");
+        assert_eq!(detect(b"hello%20world%21").chain(), "url → text");
+        assert_eq!(detect(b"plain words").chain(), "text");
+        // Text whose first two bytes pass the zlib header checksum
+        assert_eq!(detect(b"x marks the spot").chain(), "text");
+        assert_eq!(detect(b"x^ is not compressed").chain(), "text");
+        assert_eq!(detect(&encode::base64_decode_bytes("H4sIAAAAAAAA").unwrap()).kind, "gzip");
+    }
+
+    #[test]
+    fn test_compressed_layer() {
+        let packed = compress::compress(br#"{"ok":true}"#, compress::Format::Gzip, None).unwrap();
+        let blob = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, packed);
+        let d = detect(blob.as_bytes());
+        assert_eq!(d.chain(), "base64 → gzip → JSON");
+        assert_eq!(d.output, br#"{"ok":true}"#);
+    }
+}
@@ -7,14 +7,36 @@ pub fn base64_encode(input: &str) -> String {
     BASE64.encode(input.as_bytes())
 }
 
+/// Base64 encode raw bytes
+pub fn base64_encode_bytes(input: &[u8]) -> String {
+    BASE64.encode(input)
+}
+
 /// Base64 decode a string
 pub fn base64_decode(input: &str) -> Result<String, String> {
-    BASE64
-        .decode(input.trim())
-        .map_err(|e| format!("Base64 decode error: {}", e))
-        .and_then(|bytes| {
-            String::from_utf8(bytes).map_err(|e| format!("UTF-8 error: {}", e))
+    base64_decode_bytes(input).and_then(|bytes| {
+        String::from_utf8(bytes).map_err(|e| format!("UTF-8 error: {}", e))
+    })
+}
+
+/// Base64 decode to raw bytes
+///
+/// Accepts both the standard and URL-safe alphabets, with or without
+/// padding, and ignores embedded whitespace.
+pub fn base64_decode_bytes(input: &str) -> Result<Vec<u8>, String> {
+    use base64::engine::general_purpose::STANDARD_NO_PAD;
+    let cleaned: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| match c {
+            '-' => '+',
+            '_' => '/',
+            c => c,
         })
+        .collect();
+    STANDARD_NO_PAD
+        .decode(cleaned.trim_end_matches('='))
+        .map_err(|e| format!("Base64 decode error: {}", e))
 }
 
 /// Hex encode a string
@@ -24,11 +46,567 @@ pub fn hex_encode(input: &str) -> String {
 
 /// Hex decode a string
 pub fn hex_decode(input: &str) -> Result<String, String> {
-    hex::decode(input.trim())
-        .map_err(|e| format!("Hex decode error: {}", e))
-        .and_then(|bytes| {
-            String::from_utf8(bytes).map_err(|e| format!("UTF-8 error: {}", e))
-        })
+    hex_decode_bytes(input).and_then(|bytes| {
+        String::from_utf8(bytes).map_err(|e| format!("UTF-8 error: {}", e))
+    })
+}
+
+/// Hex decode to raw bytes
+///
+/// Accepts plain hex (`48656c6c6f`) as well as `0x`/`\x` prefixes and
+/// bytes separated by spaces, colons, commas or wrapped in a C array
+/// (`{ 0x48, 0x65 }`).
+pub fn hex_decode_bytes(input: &str) -> Result<Vec<u8>, String> {
+    let cleaned = input.trim().replace("\\x", " 0x").replace("\\X", " 0x");
+    let mut bytes = Vec::new();
+    for token in cleaned
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | ':' | ';' | '{' | '}' | '[' | ']'))
+        .filter(|t| !t.is_empty())
+    {
+        let (digits, prefixed) = match token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+            Some(rest) => (rest, true),
+            None => (token, false),
+        };
+        if prefixed && digits.is_empty() {
+            return Err(format!("Hex decode error: No digits after '{}'", token));
+        }
+        // A prefixed single digit such as `0x5` is a whole byte
+        if prefixed && digits.len() == 1 {
+            let b = u8::from_str_radix(digits, 16)
+                .map_err(|_| format!("Hex decode error: Invalid token '{}'", token))?;
+            bytes.push(b);
+            continue;
+        }
+        let decoded = hex::decode(digits)
+            .map_err(|e| format!("Hex decode error: {} in '{}'", e, token))?;
+        bytes.extend(decoded);
+    }
+    Ok(bytes)
+}
+
+// ============================================================================
+// BYTE REPRESENTATIONS
+// ============================================================================
+
+/// Bytes as space-separated binary octets (`01001000 01101001`)
+pub fn binary_encode(input: &[u8]) -> String {
+    input.iter().map(|b| format!("{:08b}", b)).collect::<Vec<_>>().join(" ")
+}
+
+/// Bytes as space-separated octal (`110 151`)
+pub fn octal_encode(input: &[u8]) -> String {
+    input.iter().map(|b| format!("{:03o}", b)).collect::<Vec<_>>().join(" ")
+}
+
+/// Bytes as space-separated decimal (`72 105`)
+pub fn decimal_encode(input: &[u8]) -> String {
+    input.iter().map(|b| b.to_string()).collect::<Vec<_>>().join(" ")
+}
+
+/// Bytes as a C array literal (`{ 0x48, 0x69 }`)
+pub fn c_array_encode(input: &[u8]) -> String {
+    let items: Vec<String> = input.iter().map(|b| format!("0x{:02x}", b)).collect();
+    format!("{{ {} }}", items.join(", "))
+}
+
+/// Bytes as a Rust array literal (`[0x48, 0x69]`)
+pub fn rust_array_encode(input: &[u8]) -> String {
+    let items: Vec<String> = input.iter().map(|b| format!("0x{:02x}", b)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// Split a byte list on whitespace, commas and array brackets
+fn byte_tokens(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split(|c: char| c.is_whitespace() || matches!(c, ',' | ';' | '{' | '}' | '[' | ']'))
+        .filter(|t| !t.is_empty())
+}
+
+fn parse_byte_list(input: &str, radix: u32, name: &str, prefixes: &[&str]) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    for token in byte_tokens(input) {
+        let digits = prefixes
+            .iter()
+            .find_map(|p| token.strip_prefix(p))
+            .unwrap_or(token)
+            .trim_end_matches("u8");
+        // Unseparated binary such as `0100100001101001` is split into octets
+        if radix == 2 && digits.len() > 8 && digits.len() % 8 == 0 {
+            for chunk in digits.as_bytes().chunks(8) {
+                let octet = std::str::from_utf8(chunk).unwrap_or_default();
+                bytes.push(u8::from_str_radix(octet, 2)
+                    .map_err(|_| format!("{} decode error: Invalid token '{}'", name, token))?);
+            }
+            continue;
+        }
+        bytes.push(u8::from_str_radix(digits, radix)
+            .map_err(|_| format!("{} decode error: Invalid byte '{}'", name, token))?);
+    }
+    Ok(bytes)
+}
+
+/// Decode binary octets back into bytes
+pub fn binary_decode(input: &str) -> Result<Vec<u8>, String> {
+    parse_byte_list(input, 2, "Binary", &["0b", "0B"])
+}
+
+/// Decode octal bytes back into bytes
+pub fn octal_decode(input: &str) -> Result<Vec<u8>, String> {
+    parse_byte_list(input, 8, "Octal", &["0o", "0O", "\\"])
+}
+
+/// Decode a decimal byte list or array literal back into bytes
+///
+/// Tokens with a `0x` prefix are read as hex, so C and Rust array
+/// literals produced by [`c_array_encode`] and [`rust_array_encode`]
+/// decode here too.
+pub fn decimal_decode(input: &str) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    for token in byte_tokens(input) {
+        let token = token.trim_end_matches("u8");
+        let parsed = match token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+            Some(hex) => u8::from_str_radix(hex, 16),
+            None => token.parse(),
+        };
+        bytes.push(parsed.map_err(|_| format!("Decimal decode error: Invalid byte '{}'", token))?);
+    }
+    Ok(bytes)
+}
+
+// ============================================================================
+// HEX DUMP
+// ============================================================================
+
+/// Hex dump layout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DumpStyle {
+    /// `xxd` layout: `00000000: 4865 6c6c  He..`
+    #[default]
+    Xxd,
+    /// `hexdump -C` layout: `00000000  48 65 6c 6c  |He..|`
+    Canonical,
+}
+
+const DUMP_WIDTH: usize = 16;
+
+fn dump_ascii(chunk: &[u8]) -> String {
+    chunk
+        .iter()
+        .map(|&b| if (0x20..0x7f).contains(&b) { b as char } else { '.' })
+        .collect()
+}
+
+/// Render bytes as a hex dump
+///
+/// `base_offset` is added to the printed offsets, so a slice taken from the
+/// middle of a file keeps its real positions.
+pub fn hexdump(data: &[u8], style: DumpStyle, base_offset: usize) -> String {
+    let mut lines = Vec::new();
+    match style {
+        DumpStyle::Xxd => {
+            for (i, chunk) in data.chunks(DUMP_WIDTH).enumerate() {
+                let groups: Vec<String> = chunk.chunks(2).map(hex::encode).collect();
+                lines.push(format!(
+                    "{:08x}: {:<39}  {}",
+                    base_offset + i * DUMP_WIDTH,
+                    groups.join(" "),
+                    dump_ascii(chunk)
+                ));
+            }
+        }
+        DumpStyle::Canonical => {
+            let mut previous: Option<&[u8]> = None;
+            let mut squeezing = false;
+            for (i, chunk) in data.chunks(DUMP_WIDTH).enumerate() {
+                // Identical full lines collapse into a single `*`
+                if chunk.len() == DUMP_WIDTH && previous == Some(chunk) {
+                    if !squeezing {
+                        lines.push("*".to_string());
+                        squeezing = true;
+                    }
+                    continue;
+                }
+                squeezing = false;
+                previous = Some(chunk);
+
+                let mut hex_part = String::new();
+                for (j, b) in chunk.iter().enumerate() {
+                    if j == 8 {
+                        hex_part.push(' ');
+                    }
+                    hex_part.push_str(&format!("{:02x} ", b));
+                }
+                lines.push(format!(
+                    "{:08x}  {:<49} |{}|",
+                    base_offset + i * DUMP_WIDTH,
+                    hex_part,
+                    dump_ascii(chunk)
+                ));
+            }
+            lines.push(format!("{:08x}", base_offset + data.len()));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Reverse a hex dump (`xxd` or `hexdump -C` layout) back into bytes
+///
+/// Offsets are honoured relative to the first line, so `*` repeat markers
+/// and gaps are restored.
+pub fn hexdump_reverse(dump: &str) -> Result<Vec<u8>, String> {
+    let mut out: Vec<u8> = Vec::new();
+    let mut first_offset: Option<usize> = None;
+    let mut last_line: Vec<u8> = Vec::new();
+    let mut repeat_pending = false;
+
+    for (n, raw) in dump.lines().enumerate() {
+        let line = raw.trim_end();
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line.trim() == "*" {
+            repeat_pending = true;
+            continue;
+        }
+
+        // Offset column ends at ':' (xxd) or the first whitespace (hexdump -C)
+        let (offset_str, rest, is_xxd) = match line.split_once(':') {
+            Some((o, r)) if o.trim().chars().all(|c| c.is_ascii_hexdigit()) => (o.trim(), r, true),
+            _ => match line.trim_start().split_once(char::is_whitespace) {
+                Some((o, r)) => (o, r, false),
+                None => (line.trim(), "", false),
+            },
+        };
+        let offset = usize::from_str_radix(offset_str, 16)
+            .map_err(|_| format!("Line {}: invalid offset '{}'", n + 1, offset_str))?;
+        let base = *first_offset.get_or_insert(offset);
+        let pos = offset
+            .checked_sub(base)
+            .ok_or_else(|| format!("Line {}: offset goes backwards", n + 1))?;
+
+        if repeat_pending {
+            while !last_line.is_empty() && out.len() + last_line.len() <= pos {
+                out.extend_from_slice(&last_line);
+            }
+            repeat_pending = false;
+        }
+        if out.len() < pos {
+            out.resize(pos, 0);
+        }
+
+        // Hex area ends at the ASCII gutter: two spaces (xxd) or '|' (hexdump -C)
+        let hex_area = if is_xxd {
+            let trimmed = rest.trim_start();
+            trimmed.find("  ").map_or(trimmed, |i| &trimmed[..i])
+        } else {
+            rest.find('|').map_or(rest, |i| &rest[..i])
+        };
+        let digits: String = hex_area.chars().filter(|c| !c.is_whitespace()).collect();
+        let bytes = hex::decode(&digits)
+            .map_err(|e| format!("Line {}: {}", n + 1, e))?;
+
+        out.truncate(pos);
+        out.extend_from_slice(&bytes);
+        if !bytes.is_empty() {
+            last_line = bytes;
+        }
+    }
+    Ok(out)
+}
+
+// ============================================================================
+// ASN.1
+// ============================================================================
+
+/// Nesting limit so hostile input cannot overflow the stack
+const ASN1_MAX_DEPTH: usize = 64;
+
+/// Longest primitive value shown in full before it is truncated
+const ASN1_PREVIEW_BYTES: usize = 32;
+
+/// One TLV element of a DER/BER structure
+#[derive(Debug, Clone)]
+pub struct Asn1Node {
+    /// Offset of the tag byte in the input
+    pub offset: usize,
+    /// Tag and length bytes
+    pub header_len: usize,
+    /// Content length (for BER indefinite length, excluding end-of-contents)
+    pub length: usize,
+    /// Encoded with BER indefinite length
+    pub indefinite: bool,
+    /// Tag name: `SEQUENCE`, `INTEGER`, `[0]`, `[APPLICATION 1]`, ...
+    pub tag: String,
+    pub constructed: bool,
+    /// Decoded primitive value (OID name, integer, string, hex preview)
+    pub value: Option<String>,
+    /// Nested elements, including DER encapsulated in OCTET / BIT STRINGs
+    pub children: Vec<Asn1Node>,
+}
+
+impl Asn1Node {
+    /// One-line description such as `INTEGER 65537` or `SEQUENCE (3 elem)`
+    pub fn label(&self) -> String {
+        match &self.value {
+            Some(v) => format!("{} {}", self.tag, v),
+            None if !self.children.is_empty() => format!("{} ({} elem)", self.tag, self.children.len()),
+            None => self.tag.clone(),
+        }
+    }
+}
+
+fn universal_name(number: u32) -> Option<&'static str> {
+    Some(match number {
+        0 => "END-OF-CONTENTS",
+        1 => "BOOLEAN",
+        2 => "INTEGER",
+        3 => "BIT STRING",
+        4 => "OCTET STRING",
+        5 => "NULL",
+        6 => "OBJECT IDENTIFIER",
+        7 => "ObjectDescriptor",
+        8 => "EXTERNAL",
+        9 => "REAL",
+        10 => "ENUMERATED",
+        12 => "UTF8String",
+        13 => "RELATIVE-OID",
+        16 => "SEQUENCE",
+        17 => "SET",
+        18 => "NumericString",
+        19 => "PrintableString",
+        20 => "T61String",
+        21 => "VideotexString",
+        22 => "IA5String",
+        23 => "UTCTime",
+        24 => "GeneralizedTime",
+        25 => "GraphicString",
+        26 => "VisibleString",
+        27 => "GeneralString",
+        28 => "UniversalString",
+        30 => "BMPString",
+        _ => return None,
+    })
+}
+
+fn hex_preview(data: &[u8]) -> String {
+    if data.len() > ASN1_PREVIEW_BYTES {
+        format!("{}… ({} bytes)", hex::encode_upper(&data[..ASN1_PREVIEW_BYTES]), data.len())
+    } else {
+        hex::encode_upper(data)
+    }
+}
+
+fn text_or_hex(data: &[u8]) -> String {
+    match std::str::from_utf8(data) {
+        Ok(s) if !s.is_empty() && s.chars().all(|c| !c.is_control()) => format!("'{}'", s),
+        _ => hex_preview(data),
+    }
+}
+
+fn oid_value(content: &[u8]) -> String {
+    let oid = x509_parser::der_parser::oid::Oid::new(std::borrow::Cow::Borrowed(content));
+    let dotted = oid.to_id_string();
+    match x509_parser::objects::oid2sn(&oid, x509_parser::objects::oid_registry()) {
+        Ok(name) => format!("{} ({})", dotted, name),
+        Err(_) => dotted,
+    }
+}
+
+fn primitive_value(number: u32, content: &[u8]) -> Option<String> {
+    Some(match number {
+        1 => if content.iter().any(|&b| b != 0) { "TRUE" } else { "FALSE" }.to_string(),
+        2 | 10 if content.len() <= 16 => num_bigint::BigInt::from_signed_bytes_be(content).to_string(),
+        2 | 10 => hex_preview(content),
+        3 => match content.split_first() {
+            Some((0, bits)) => hex_preview(bits),
+            Some((unused, bits)) => format!("{} ({} unused bits)", hex_preview(bits), unused),
+            None => String::new(),
+        },
+        5 | 0 => return None,
+        6 => oid_value(content),
+        30 => {
+            let units: Vec<u16> = content.chunks(2).map(|c| u16::from_be_bytes([c[0], *c.get(1).unwrap_or(&0)])).collect();
+            format!("'{}'", String::from_utf16_lossy(&units))
+        }
+        12 | 18 | 19 | 20 | 22 | 23 | 24 | 25 | 26 | 27 => format!("'{}'", String::from_utf8_lossy(content)),
+        _ => hex_preview(content),
+    })
+}
+
+/// DER nested in an OCTET STRING or BIT STRING (extension values, public keys)
+fn encapsulated(content: &[u8], offset: usize, depth: usize) -> Option<Vec<Asn1Node>> {
+    if !matches!(content.first(), Some(0x30 | 0x31)) {
+        return None;
+    }
+    asn1_nodes(content, offset, depth).ok()
+}
+
+fn asn1_nodes(data: &[u8], offset: usize, depth: usize) -> Result<Vec<Asn1Node>, String> {
+    let mut nodes = Vec::new();
+    let mut pos = 0;
+    while pos < data.len() {
+        let (node, used) = asn1_node(&data[pos..], offset + pos, depth)?;
+        nodes.push(node);
+        pos += used;
+    }
+    Ok(nodes)
+}
+
+fn asn1_node(data: &[u8], offset: usize, depth: usize) -> Result<(Asn1Node, usize), String> {
+    let err = |msg: &str| format!("{} at offset {}", msg, offset);
+    if depth > ASN1_MAX_DEPTH {
+        return Err(err("Nesting too deep"));
+    }
+
+    let first = data[0];
+    let class = first >> 6;
+    let constructed = first & 0x20 != 0;
+    let mut pos = 1;
+    let mut number = u32::from(first & 0x1f);
+    if number == 0x1f {
+        // High tag number: base-128 digits
+        number = 0;
+        loop {
+            let b = *data.get(pos).ok_or_else(|| err("Truncated tag"))?;
+            pos += 1;
+            if pos > 5 {
+                return Err(err("Tag number too large"));
+            }
+            number = number << 7 | u32::from(b & 0x7f);
+            if b & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let len_byte = *data.get(pos).ok_or_else(|| err("Truncated length"))?;
+    pos += 1;
+    let length = match len_byte {
+        0x80 => None,
+        b if b < 0x80 => Some(usize::from(b)),
+        b => {
+            let n = usize::from(b & 0x7f);
+            let bytes = data.get(pos..pos + n).filter(|_| n <= 8).ok_or_else(|| err("Bad length"))?;
+            pos += n;
+            Some(bytes.iter().fold(0usize, |acc, &b| acc.saturating_mul(256).saturating_add(usize::from(b))))
+        }
+    };
+    let header_len = pos;
+
+    let tag = match class {
+        0 => universal_name(number).map(str::to_string).unwrap_or_else(|| format!("[UNIVERSAL {}]", number)),
+        1 => format!("[APPLICATION {}]", number),
+        2 => format!("[{}]", number),
+        _ => format!("[PRIVATE {}]", number),
+    };
+
+    let (content_len, used, children, value) = match length {
+        Some(len) => {
+            let end = pos.checked_add(len).filter(|&e| e <= data.len()).ok_or_else(|| err("Length exceeds data"))?;
+            let content = &data[pos..end];
+            let content_offset = offset + pos;
+            let (children, value) = if constructed {
+                (asn1_nodes(content, content_offset, depth + 1)?, None)
+            } else if class == 0 && number == 4 {
+                match encapsulated(content, content_offset, depth + 1) {
+                    Some(nodes) => (nodes, None),
+                    None => (Vec::new(), Some(hex_preview(content))),
+                }
+            } else if class == 0 && number == 3 && content.first() == Some(&0) {
+                match encapsulated(&content[1..], content_offset + 1, depth + 1) {
+                    Some(nodes) => (nodes, None),
+                    None => (Vec::new(), primitive_value(number, content)),
+                }
+            } else if class == 0 {
+                (Vec::new(), primitive_value(number, content))
+            } else {
+                (Vec::new(), Some(text_or_hex(content)))
+            };
+            (len, end, children, value)
+        }
+        None => {
+            if !constructed {
+                return Err(err("Indefinite length on a primitive"));
+            }
+            let mut children = Vec::new();
+            let mut p = pos;
+            while !data[p..].starts_with(&[0, 0]) {
+                if p >= data.len() {
+                    return Err(err("Missing end-of-contents"));
+                }
+                let (child, n) = asn1_node(&data[p..], offset + p, depth + 1)?;
+                children.push(child);
+                p += n;
+            }
+            (p - pos, p + 2, children, None)
+        }
+    };
+
+    let node = Asn1Node {
+        offset,
+        header_len,
+        length: content_len,
+        indefinite: length.is_none(),
+        tag,
+        constructed,
+        value,
+        children,
+    };
+    Ok((node, used))
+}
+
+/// Parse DER or BER bytes into a TLV tree
+pub fn asn1_parse(data: &[u8]) -> Result<Vec<Asn1Node>, String> {
+    if data.is_empty() {
+        return Err("No ASN.1 data".to_string());
+    }
+    asn1_nodes(data, 0, 0)
+}
+
+/// Raw bytes from hex or base64 text, or the input itself if it is binary
+///
+/// Text that is neither hex nor base64 is returned unchanged.
+pub fn binary_input(data: &[u8]) -> Result<Vec<u8>, String> {
+    let text = match std::str::from_utf8(data) {
+        Ok(s) if s.chars().all(|c| !c.is_control() || c.is_whitespace()) => s.trim(),
+        _ => return Ok(data.to_vec()),
+    };
+    let is_hex = text.chars().all(|c| c.is_ascii_hexdigit() || c.is_whitespace() || c == ':');
+    if is_hex {
+        hex_decode_bytes(text)
+    } else {
+        Ok(base64_decode_bytes(text).unwrap_or_else(|_| data.to_vec()))
+    }
+}
+
+/// Raw DER from PEM, hex, base64 or binary input
+pub fn asn1_input(data: &[u8]) -> Result<Vec<u8>, String> {
+    let text = String::from_utf8_lossy(data);
+    if !text.contains("-----BEGIN") {
+        return binary_input(data);
+    }
+    let body: String = text
+        .lines()
+        .skip_while(|l| !l.starts_with("-----BEGIN"))
+        .skip(1)
+        .take_while(|l| !l.starts_with("-----END"))
+        .filter(|l| !l.contains(':'))
+        .collect();
+    base64_decode_bytes(&body)
+}
+
+/// Indented dump in the style of `dumpasn1`: offset, length, tag, value
+pub fn asn1_dump(nodes: &[Asn1Node]) -> String {
+    fn walk(nodes: &[Asn1Node], depth: usize, out: &mut Vec<String>) {
+        for node in nodes {
+            let len = if node.indefinite { "NDEF".to_string() } else { node.length.to_string() };
+            out.push(format!("{:>6} {:>5}: {}{}", node.offset, len, "  ".repeat(depth), node.label()));
+            walk(&node.children, depth + 1, out);
+        }
+    }
+    let mut out = Vec::new();
+    walk(nodes, 0, &mut out);
+    out.join("\n")
 }
 
 /// URL encode a string
@@ -53,6 +631,136 @@ pub fn html_decode(input: &str) -> String {
     html_escape::decode_html_entities(input).into_owned()
 }
 
+// ============================================================================
+// DATA URI
+// ============================================================================
+
+/// MIME type from magic bytes, falling back to text/plain or octet-stream
+pub fn mime_type(data: &[u8]) -> &'static str {
+    const MAGIC: &[(&[u8], &str)] = &[
+        (&[0x89, b'P', b'N', b'G'], "image/png"),
+        (&[0xff, 0xd8, 0xff], "image/jpeg"),
+        (b"GIF8", "image/gif"),
+        (&[0x00, 0x00, 0x01, 0x00], "image/x-icon"),
+        (b"%PDF", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+        (&[0x1f, 0x8b], "application/gzip"),
+        (b"\x00asm", "application/wasm"),
+        (b"wOFF", "font/woff"),
+        (b"wOF2", "font/woff2"),
+        (&[0x00, 0x01, 0x00, 0x00], "font/ttf"),
+        (b"OTTO", "font/otf"),
+        (b"ID3", "audio/mpeg"),
+        (b"OggS", "audio/ogg"),
+        (b"fLaC", "audio/flac"),
+    ];
+    if let Some((_, mime)) = MAGIC.iter().find(|(m, _)| data.starts_with(m)) {
+        return mime;
+    }
+    // BITMAPFILEHEADER: size, two zero reserved words, pixel offset past the headers
+    if data.len() >= 14 && data.starts_with(b"BM") {
+        let word = |i: usize| u32::from_le_bytes([data[i], data[i + 1], data[i + 2], data[i + 3]]);
+        let (size, offset) = (word(2), word(10));
+        if word(6) == 0 && size >= 26 && (26..size).contains(&offset) {
+            return "image/bmp";
+        }
+    }
+    if data.len() >= 12 && data.starts_with(b"RIFF") {
+        match &data[8..12] {
+            b"WEBP" => return "image/webp",
+            b"WAVE" => return "audio/wav",
+            _ => {}
+        }
+    }
+    if data.len() >= 12 && &data[4..8] == b"ftyp" {
+        return if matches!(&data[8..12], b"avif" | b"avis") { "image/avif" } else { "video/mp4" };
+    }
+
+    let Ok(text) = std::str::from_utf8(data) else {
+        return "application/octet-stream";
+    };
+    let text = text.trim_start();
+    let mut end = text.len().min(512);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let head = &text[..end];
+    if head.contains("<svg") {
+        "image/svg+xml"
+    } else if head.to_ascii_lowercase().starts_with("<!doctype html") || head.starts_with("<html") {
+        "text/html"
+    } else if text.starts_with('{') || text.starts_with('[') {
+        "application/json"
+    } else {
+        "text/plain"
+    }
+}
+
+/// Usual file extension for a MIME type
+pub fn mime_extension(mime: &str) -> &'static str {
+    match mime {
+        "image/png" => "png",
+        "image/jpeg" => "jpg",
+        "image/gif" => "gif",
+        "image/bmp" => "bmp",
+        "image/x-icon" => "ico",
+        "image/webp" => "webp",
+        "image/avif" => "avif",
+        "image/svg+xml" => "svg",
+        "application/pdf" => "pdf",
+        "application/zip" => "zip",
+        "application/gzip" => "gz",
+        "application/wasm" => "wasm",
+        "application/json" => "json",
+        "font/woff" => "woff",
+        "font/woff2" => "woff2",
+        "font/ttf" => "ttf",
+        "font/otf" => "otf",
+        "audio/mpeg" => "mp3",
+        "audio/ogg" => "ogg",
+        "audio/flac" => "flac",
+        "audio/wav" => "wav",
+        "video/mp4" => "mp4",
+        "text/html" => "html",
+        "text/css" => "css",
+        "text/plain" => "txt",
+        _ => "bin",
+    }
+}
+
+/// `data:<mime>;base64,...` with the MIME type sniffed from the content
+pub fn data_uri_encode(data: &[u8]) -> String {
+    format!("data:{};base64,{}", mime_type(data), BASE64.encode(data))
+}
+
+/// Split a data URI into its MIME type and payload (base64 or percent-encoded)
+pub fn data_uri_decode(uri: &str) -> Result<(String, Vec<u8>), String> {
+    let uri = uri.trim();
+    // Also accept CSS `url("data:...")`
+    let uri = uri
+        .strip_prefix("url(")
+        .and_then(|u| u.strip_suffix(')'))
+        .map(|u| u.trim_matches(|c| c == '"' || c == '\''))
+        .unwrap_or(uri);
+    let rest = uri
+        .get(..5)
+        .filter(|p| p.eq_ignore_ascii_case("data:"))
+        .map(|_| &uri[5..])
+        .ok_or("Not a data URI (expected data:...)")?;
+    let (meta, payload) = rest.split_once(',').ok_or("Data URI has no ',' before the payload")?;
+    let mut params = meta.split(';');
+    let mime = match params.next() {
+        Some(m) if !m.is_empty() => m.to_string(),
+        _ => "text/plain".to_string(),
+    };
+    let bytes = if params.any(|p| p.eq_ignore_ascii_case("base64")) {
+        base64_decode_bytes(payload)?
+    } else {
+        urlencoding::decode_binary(payload.as_bytes()).into_owned()
+    };
+    Ok((mime, bytes))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,6 +773,12 @@ mod tests {
         assert_eq!(input, decoded);
     }
 
+    #[test]
+    fn test_base64_url_safe_unpadded() {
+        assert_eq!(base64_decode("eyJhbGciOiJIUzI1NiJ9").unwrap(), r#"{"alg":"HS256"}"#);
+        assert_eq!(base64_decode_bytes("-_8").unwrap(), vec![0xfb, 0xff]);
+    }
+
     #[test]
     fn test_hex_roundtrip() {
         let input = "Test123";
@@ -73,6 +787,86 @@ mod tests {
         assert_eq!(input, decoded);
     }
 
+    #[test]
+    fn test_hex_decode_lenient() {
+        let expected = vec![0x48, 0x69, 0x05];
+        assert_eq!(hex_decode_bytes("48 69 05").unwrap(), expected);
+        assert_eq!(hex_decode_bytes("48:69:05").unwrap(), expected);
+        assert_eq!(hex_decode_bytes("{ 0x48, 0x69, 0x5 }").unwrap(), expected);
+        assert_eq!(hex_decode_bytes("\\x48\\x69\\x05").unwrap(), expected);
+        assert!(hex_decode_bytes("4g").is_err());
+        for bare in ["0x", "0x,0x", "48 0X 69", "\\x"] {
+            assert!(hex_decode_bytes(bare).unwrap_err().contains("No digits"), "{}", bare);
+        }
+    }
+
+    #[test]
+    fn test_byte_representations() {
+        let data = b"Hi";
+        assert_eq!(binary_encode(data), "01001000 01101001");
+        assert_eq!(octal_encode(data), "110 151");
+        assert_eq!(decimal_encode(data), "72 105");
+        assert_eq!(c_array_encode(data), "{ 0x48, 0x69 }");
+        assert_eq!(rust_array_encode(data), "[0x48, 0x69]");
+
+        assert_eq!(binary_decode("01001000 01101001").unwrap(), data);
+        assert_eq!(binary_decode("0100100001101001").unwrap(), data);
+        assert_eq!(octal_decode("110 151").unwrap(), data);
+        assert_eq!(decimal_decode("[72, 105]").unwrap(), data);
+        assert_eq!(decimal_decode(&c_array_encode(data)).unwrap(), data);
+        assert!(decimal_decode("256").is_err());
+        assert_eq!(decimal_decode("72\n105\n").unwrap(), data);
+        assert_eq!(octal_decode("110\r\n151").unwrap(), data);
+        assert_eq!(binary_decode("01001000\n01101001").unwrap(), data);
+    }
+
+    #[test]
+    fn test_hexdump_xxd_layout() {
+        let dump = hexdump(b"Hello, World! This is a test\x00\x01", DumpStyle::Xxd, 0);
+        assert_eq!(
+            dump,
+            "00000000: 4865 6c6c 6f2c 2057 6f72 6c64 2120 5468  Hello, World! Th\n\
+             00000010: 6973 2069 7320 6120 7465 7374 0001       is is a test.."
+        );
+    }
+
+    #[test]
+    fn test_hexdump_roundtrip() {
+        let mut data: Vec<u8> = (0..=255).collect();
+        data.extend([0u8; 64]);
+        data.extend(b"tail");
+        for style in [DumpStyle::Xxd, DumpStyle::Canonical] {
+            let dump = hexdump(&data, style, 0);
+            assert_eq!(hexdump_reverse(&dump).unwrap(), data);
+        }
+        assert!(hexdump(&[0u8; 64], DumpStyle::Canonical, 0).contains("\n*\n"));
+    }
+
+    #[test]
+    fn test_asn1_parse() {
+        // SEQUENCE { OID sha256WithRSAEncryption, OCTET STRING { SEQUENCE { INTEGER -1, UTF8String "frog" } } }
+        let der = hex_decode_bytes("301806092a864886f70d01010b040b30090201ff0c0466726f67").unwrap();
+        let nodes = asn1_parse(&der).unwrap();
+        let dump = asn1_dump(&nodes);
+        assert!(dump.contains("OBJECT IDENTIFIER 1.2.840.113549.1.1.11 (sha256WithRSAEncryption)"));
+        assert!(dump.contains("    17     1:       INTEGER -1"));
+        assert_eq!(nodes[0].children[1].children[0].children[1].label(), "UTF8String 'frog'");
+        assert!(asn1_parse(&der[..10]).is_err());
+    }
+
+    #[test]
+    fn test_asn1_ber_and_inputs() {
+        let nodes = asn1_parse(&[0x30, 0x80, 0x01, 0x01, 0xff, 0x00, 0x00]).unwrap();
+        assert!(nodes[0].indefinite);
+        assert_eq!(nodes[0].children[0].label(), "BOOLEAN TRUE");
+
+        let der = vec![0x30, 0x03, 0x02, 0x01, 0x05];
+        assert_eq!(asn1_input(b"30 03 02 01 05").unwrap(), der);
+        assert_eq!(asn1_input(b"MAMCAQU=").unwrap(), der);
+        assert_eq!(asn1_input(b"-----BEGIN X-----\nMAMCAQU=\n-----END X-----").unwrap(), der);
+        assert_eq!(asn1_input(&der).unwrap(), der);
+    }
+
     #[test]
     fn test_url_roundtrip() {
         let input = "hello world & more";
@@ -80,4 +874,20 @@ mod tests {
         let decoded = url_decode(&encoded).unwrap();
         assert_eq!(input, decoded);
     }
+
+    #[test]
+    fn test_data_uri() {
+        let png = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+        let uri = data_uri_encode(&png);
+        assert_eq!(uri, "data:image/png;base64,iVBORw0KGgo=");
+        assert_eq!(data_uri_decode(&uri).unwrap(), ("image/png".to_string(), png.to_vec()));
+        assert_eq!(data_uri_decode("url('data:,a%20b')").unwrap(), ("text/plain".to_string(), b"a b".to_vec()));
+        assert_eq!(mime_type(b"RIFF\0\0\0\0WEBPVP8 "), "image/webp");
+        assert_eq!(mime_type(b"BM\x46\0\0\0\0\0\0\0\x36\0\0\0\x28\0\0\0"), "image/bmp");
+        assert_eq!(mime_type(b"BMW owners club minutes"), "text/plain");
+        assert_eq!(mime_type(b"<svg xmlns='http://www.w3.org/2000/svg'/>"), "image/svg+xml");
+        // Byte 512 falls inside a two-byte character
+        assert_eq!(mime_type(format!("a{}", "é".repeat(300)).as_bytes()), "text/plain");
+        assert!(data_uri_decode("hello").is_err());
+    }
 }
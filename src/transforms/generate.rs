@@ -1,7 +1,8 @@
-//! Generation functions for UUIDs, passwords, and random data
+//! Generation functions for UUIDs, other IDs, passwords, passphrases, regex matches, and random data
 
-use rand::Rng;
-use uuid::Uuid;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use uuid::{Builder, Uuid};
 
 /// Character sets for password generation
 pub const CHARS_ALPHA: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
@@ -10,42 +11,1260 @@ pub const CHARS_SYMBOL: &str = "!@#$%^&*()-_=+[]{}|;:,.<>?";
 pub const CHARS_ALPHANUMERIC: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
 pub const CHARS_ALL: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789!@#$%^&*()-_=+";
 
+// ============================================================================
+// RANDOMNESS
+// ============================================================================
+
+/// Unix milliseconds a seeded [`Source`] clock starts at (2024-01-01T00:00:00Z)
+pub const SEEDED_EPOCH_MS: u64 = 1_704_067_200_000;
+
+/// Randomness and clock for every generator
+///
+/// [`Source::system`] uses the OS-seeded thread RNG and the real clock.
+/// [`Source::seeded`] uses ChaCha20 (`rand_chacha`, value-stable across
+/// platforms and releases) and a fake clock that starts at [`SEEDED_EPOCH_MS`]
+/// and ticks 1 ms per read, so the same seed gives the same output everywhere.
+/// Seeded output is predictable: use it for test fixtures, never for secrets.
+pub struct Source {
+    rng: SourceRng,
+    clock_ms: Option<u64>,
+}
+
+enum SourceRng {
+    System(rand::rngs::ThreadRng),
+    Seeded(Box<ChaCha20Rng>),
+}
+
+impl Source {
+    pub fn system() -> Self {
+        Source { rng: SourceRng::System(rand::thread_rng()), clock_ms: None }
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        Source { rng: SourceRng::Seeded(Box::new(ChaCha20Rng::seed_from_u64(seed))), clock_ms: Some(SEEDED_EPOCH_MS) }
+    }
+
+    /// [`Source::seeded`] when a seed is given, else [`Source::system`]
+    pub fn new(seed: Option<u64>) -> Self {
+        seed.map_or_else(Source::system, Source::seeded)
+    }
+
+    pub fn is_seeded(&self) -> bool {
+        self.clock_ms.is_some()
+    }
+
+    /// Unix milliseconds: the real clock, or the seeded clock (which then ticks)
+    pub fn now_millis(&mut self) -> u64 {
+        match &mut self.clock_ms {
+            Some(ms) => {
+                *ms += 1;
+                *ms - 1
+            }
+            None => std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+        }
+    }
+
+    /// Uniform index below `len`; sampled as u64 so 32- and 64-bit targets agree
+    pub fn index(&mut self, len: usize) -> usize {
+        self.gen_range(0..len as u64) as usize
+    }
+
+    /// A uniformly chosen element of a non-empty slice
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.index(items.len())]
+    }
+}
+
+impl RngCore for Source {
+    fn next_u32(&mut self) -> u32 {
+        match &mut self.rng {
+            SourceRng::System(rng) => rng.next_u32(),
+            SourceRng::Seeded(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match &mut self.rng {
+            SourceRng::System(rng) => rng.next_u64(),
+            SourceRng::Seeded(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match &mut self.rng {
+            SourceRng::System(rng) => rng.fill_bytes(dest),
+            SourceRng::Seeded(rng) => rng.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
 /// Generate a new UUID v4
-pub fn uuid_v4() -> String {
-    Uuid::new_v4().to_string()
+pub fn uuid_v4(src: &mut Source) -> String {
+    Builder::from_random_bytes(src.gen()).into_uuid().to_string()
+}
+
+/// UUID versions [`uuid`] can generate
+pub const UUID_VERSIONS: [u8; 7] = [1, 3, 4, 5, 6, 7, 8];
+
+/// `dns`, `url`, `oid`, `x500` or any UUID as a namespace for v3/v5/v8
+pub fn uuid_namespace(name: &str) -> Result<Uuid, String> {
+    match name.to_ascii_lowercase().as_str() {
+        "dns" => Ok(Uuid::NAMESPACE_DNS),
+        "url" => Ok(Uuid::NAMESPACE_URL),
+        "oid" => Ok(Uuid::NAMESPACE_OID),
+        "x500" => Ok(Uuid::NAMESPACE_X500),
+        other => Uuid::parse_str(other)
+            .map_err(|_| format!("Unknown namespace '{}'. Use dns, url, oid, x500 or a UUID", name)),
+    }
+}
+
+/// Random node ID with the multicast bit set, as RFC 9562 asks when no MAC is used
+fn random_node(src: &mut Source) -> [u8; 6] {
+    let mut node: [u8; 6] = src.gen();
+    node[0] |= 0x01;
+    node
+}
+
+/// Generate a UUID of the given version
+///
+/// v3 and v5 need a namespace and name. v8 is random, or the SHA-256
+/// name-based layout from RFC 9562 when a name is given.
+pub fn uuid(src: &mut Source, version: u8, namespace: Option<&str>, name: Option<&str>) -> Result<String, String> {
+    let named = || -> Result<(Uuid, &str), String> {
+        let name = name.ok_or_else(|| format!("UUID v{} needs --name", version))?;
+        Ok((uuid_namespace(namespace.unwrap_or("dns"))?, name))
+    };
+    // The uuid crate's own clock keeps v1/v6/v7 ordered within a millisecond;
+    // seeded sources build them from the fake clock instead
+    let gregorian = |ms: u64| (i128::from(ms) * 10_000 + super::time::UUID_OFFSET) as u64;
+    let id = match version {
+        1 if src.is_seeded() => {
+            let (ticks, counter) = (gregorian(src.now_millis()), src.gen());
+            Builder::from_gregorian_timestamp(ticks, counter, &random_node(src)).into_uuid()
+        }
+        1 => Uuid::now_v1(&random_node(src)),
+        3 => named().map(|(ns, name)| Uuid::new_v3(&ns, name.as_bytes()))?,
+        4 => Builder::from_random_bytes(src.gen()).into_uuid(),
+        5 => named().map(|(ns, name)| Uuid::new_v5(&ns, name.as_bytes()))?,
+        6 if src.is_seeded() => {
+            let (ticks, counter) = (gregorian(src.now_millis()), src.gen());
+            Builder::from_sorted_gregorian_timestamp(ticks, counter, &random_node(src)).into_uuid()
+        }
+        6 => Uuid::now_v6(&random_node(src)),
+        7 if src.is_seeded() => Builder::from_unix_timestamp_millis(src.now_millis(), &src.gen()).into_uuid(),
+        7 => Uuid::now_v7(),
+        8 => {
+            let mut bytes = [0u8; 16];
+            if name.is_some() {
+                use sha2::{Digest, Sha256};
+                let (ns, name) = named()?;
+                let digest = Sha256::new().chain_update(ns.as_bytes()).chain_update(name.as_bytes()).finalize();
+                bytes.copy_from_slice(&digest[..16]);
+            } else {
+                src.fill(&mut bytes);
+            }
+            Uuid::new_v8(bytes)
+        }
+        v => return Err(format!("Unsupported UUID version {}. Use 1, 3, 4, 5, 6, 7 or 8", v)),
+    };
+    Ok(id.to_string())
+}
+
+// ============================================================================
+// UUID INSPECTION
+// ============================================================================
+
+/// Version, variant, embedded fields and alternate forms of a UUID
+#[derive(Debug, Clone)]
+pub struct UuidReport {
+    pub uuid: Uuid,
+    pub version: usize,
+    pub version_name: &'static str,
+    pub variant: &'static str,
+    /// Nanoseconds since the Unix epoch (v1, v6, v7)
+    pub timestamp: Option<i128>,
+    /// 14-bit clock sequence (v1, v6)
+    pub clock_seq: Option<u16>,
+    /// Node ID as `aa:bb:cc:dd:ee:ff` (v1, v6)
+    pub node: Option<String>,
+    /// Hyphenated, simple, braced, URN, base64, base58 and integer forms
+    pub forms: Vec<(&'static str, String)>,
+}
+
+/// RFC 3339 time plus Unix milliseconds
+fn timestamp_field(nanos: i128) -> String {
+    let utc = super::time::format(nanos, "rfc3339").unwrap_or_else(|e| e);
+    format!("{} (Unix ms {})", utc, nanos.div_euclid(1_000_000))
+}
+
+fn aligned(fields: &[(&str, String)]) -> String {
+    fields.iter().map(|(label, value)| format!("{:<16} {}", format!("{}:", label), value)).collect::<Vec<_>>().join("\n")
+}
+
+impl UuidReport {
+    /// Aligned `Label: value` lines
+    pub fn summary(&self) -> String {
+        let mut fields = vec![
+            ("Version", format!("{} ({})", self.version, self.version_name)),
+            ("Variant", self.variant.to_string()),
+        ];
+        if let Some(nanos) = self.timestamp {
+            fields.push(("Timestamp", timestamp_field(nanos)));
+        }
+        if let Some(seq) = self.clock_seq {
+            fields.push(("Clock sequence", seq.to_string()));
+        }
+        if let Some(ref node) = self.node {
+            fields.push(("Node", node.clone()));
+        }
+        fields.extend(self.forms.iter().map(|(label, value)| (*label, value.clone())));
+        aligned(&fields)
+    }
+}
+
+const BASE58: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Bitcoin-alphabet base58 of the 128-bit value
+fn base58_encode(uuid: &Uuid) -> String {
+    let mut n = uuid.as_u128();
+    let mut out = Vec::new();
+    while n > 0 {
+        out.push(BASE58[(n % 58) as usize]);
+        n /= 58;
+    }
+    let zeros = uuid.as_bytes().iter().take_while(|&&b| b == 0).count();
+    out.extend(std::iter::repeat(b'1').take(zeros));
+    out.reverse();
+    String::from_utf8(out).unwrap_or_default()
+}
+
+fn base58_decode(text: &str) -> Option<Uuid> {
+    text.bytes().try_fold(0u128, |n, c| {
+        let digit = BASE58.iter().position(|&b| b == c)? as u128;
+        n.checked_mul(58)?.checked_add(digit)
+    }).map(Uuid::from_u128)
+}
+
+/// Read a UUID from any of the forms [`uuid_inspect`] prints
+///
+/// `from` names the form; without it every form but the integer one is
+/// tried, and text that is valid base64 and base58 alike is refused.
+pub fn uuid_parse(text: &str, from: Option<&str>) -> Result<Uuid, String> {
+    let text = text.trim();
+    let not_uuid = || format!("Not a UUID: {}", text);
+    let base64 = || {
+        super::encode::base64_decode_bytes(text).ok().and_then(|bytes| Uuid::from_slice(&bytes).ok())
+    };
+    // Base58 of a 128-bit value with a non-zero first byte
+    let base58 = || Some(text).filter(|t| (21..=22).contains(&t.len())).and_then(base58_decode);
+    match from.map(str::to_ascii_lowercase).as_deref() {
+        Some("hyphenated" | "simple" | "braced" | "urn") => Uuid::parse_str(text).map_err(|_| not_uuid()),
+        Some("base64") => base64().ok_or_else(not_uuid),
+        Some("base58") => base58().ok_or_else(not_uuid),
+        Some("integer") => {
+            text.parse::<u128>().map(Uuid::from_u128).map_err(|_| format!("Not a 128-bit integer: {}", text))
+        }
+        Some(other) => Err(format!(
+            "Unknown UUID form: {} (use hyphenated, simple, braced, urn, base64, base58, integer)",
+            other
+        )),
+        None => {
+            if let Ok(id) = Uuid::parse_str(text) {
+                return Ok(id);
+            }
+            match (base64(), base58()) {
+                (Some(a), Some(b)) if a != b => {
+                    Err(format!("{} is valid base64 and base58; pass --from base64 or --from base58", text))
+                }
+                (Some(id), _) | (None, Some(id)) => Ok(id),
+                (None, None) => Err(not_uuid()),
+            }
+        }
+    }
+}
+
+/// Structured report for a UUID in any supported form
+pub fn uuid_inspect(text: &str, from: Option<&str>) -> Result<UuidReport, String> {
+    use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+    let uuid = uuid_parse(text, from)?;
+    let version = uuid.get_version_num();
+    let version_name = match version {
+        0 if uuid.is_nil() => "nil",
+        1 => "Gregorian time + node",
+        2 => "DCE security",
+        3 => "name-based, MD5",
+        4 => "random",
+        5 => "name-based, SHA-1",
+        6 => "reordered Gregorian time + node",
+        7 => "Unix time-ordered",
+        8 => "custom",
+        15 if uuid.is_max() => "max",
+        _ => "unknown",
+    };
+    let variant = match uuid.get_variant() {
+        uuid::Variant::NCS => "NCS (reserved)",
+        uuid::Variant::RFC4122 => "RFC 9562",
+        uuid::Variant::Microsoft => "Microsoft (reserved)",
+        _ => "future (reserved)",
+    };
+    let timestamp = uuid.get_timestamp().map(|ts| {
+        let (secs, nanos) = ts.to_unix();
+        i128::from(secs) * 1_000_000_000 + i128::from(nanos)
+    });
+    let bytes = uuid.as_bytes();
+    let gregorian = matches!(version, 1 | 6);
+    let clock_seq = gregorian.then(|| u16::from_be_bytes([bytes[8], bytes[9]]) & 0x3fff);
+    let node = gregorian.then(|| {
+        let node = bytes[10..].iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(":");
+        if bytes[10] & 0x01 == 1 { format!("{} (random)", node) } else { format!("{} (MAC)", node) }
+    });
+    let forms = vec![
+        ("Hyphenated", uuid.hyphenated().to_string()),
+        ("Simple", uuid.simple().to_string()),
+        ("Braced", uuid.braced().to_string()),
+        ("URN", uuid.urn().to_string()),
+        ("Base64", URL_SAFE_NO_PAD.encode(bytes)),
+        ("Base58", base58_encode(&uuid)),
+        ("Integer", uuid.as_u128().to_string()),
+    ];
+    Ok(UuidReport { uuid, version, version_name, variant, timestamp, clock_seq, node, forms })
+}
+
+// ============================================================================
+// OTHER IDS
+// ============================================================================
+
+/// Type, embedded timestamp and decoded fields of a non-UUID identifier
+#[derive(Debug, Clone)]
+pub struct IdReport {
+    pub kind: &'static str,
+    /// Nanoseconds since the Unix epoch, when the ID carries one
+    pub timestamp: Option<i128>,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl IdReport {
+    /// Aligned `Label: value` lines
+    pub fn summary(&self) -> String {
+        let mut fields = vec![("Type", self.kind.to_string())];
+        if let Some(nanos) = self.timestamp {
+            fields.push(("Timestamp", timestamp_field(nanos)));
+        }
+        fields.extend(self.fields.iter().map(|(label, value)| (*label, value.clone())));
+        aligned(&fields)
+    }
+}
+
+/// ULID: 48-bit Unix milliseconds + 80 random bits, Crockford base32
+pub fn ulid(src: &mut Source) -> String {
+    let value = (u128::from(src.now_millis()) << 80) | (src.gen::<u128>() >> 48);
+    let mut out: Vec<u8> = (0..26).map(|i| super::time::CROCKFORD[((value >> (i * 5)) & 31) as usize]).collect();
+    out.reverse();
+    String::from_utf8(out).unwrap_or_default()
+}
+
+pub fn ulid_inspect(text: &str) -> Result<IdReport, String> {
+    let text = text.trim();
+    if text.len() != 26 {
+        return Err("ULID must be 26 characters".to_string());
+    }
+    let value = text.chars().try_fold(0u128, |acc, c| {
+        let c = match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        };
+        let digit = super::time::CROCKFORD.iter().position(|&b| b as char == c).ok_or_else(|| format!("Invalid ULID character '{}'", c))?;
+        acc.checked_mul(32).map(|n| n + digit as u128).ok_or_else(|| "ULID overflows 128 bits (first character must be 0-7)".to_string())
+    })?;
+    let millis = (value >> 80) as i128;
+    Ok(IdReport {
+        kind: "ULID",
+        timestamp: Some(millis * 1_000_000),
+        fields: vec![
+            ("Randomness", format!("{:020x}", value & ((1 << 80) - 1))),
+            ("As UUID", Uuid::from_u128(value).to_string()),
+        ],
+    })
+}
+
+const BASE62: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+/// Unix seconds at KSUID time zero (2014-05-13)
+const KSUID_EPOCH: u64 = 1_400_000_000;
+
+/// KSUID: 32-bit seconds since 2014-05-13 + 128 random bits, base62
+pub fn ksuid(src: &mut Source) -> String {
+    let mut bytes = [0u8; 20];
+    let secs = (src.now_millis() / 1000).saturating_sub(KSUID_EPOCH) as u32;
+    bytes[..4].copy_from_slice(&secs.to_be_bytes());
+    src.fill(&mut bytes[4..]);
+    let digits: String = num_bigint::BigUint::from_bytes_be(&bytes)
+        .to_radix_be(62)
+        .into_iter()
+        .map(|d| BASE62[d as usize] as char)
+        .collect();
+    format!("{:0>27}", digits)
+}
+
+pub fn ksuid_inspect(text: &str) -> Result<IdReport, String> {
+    let text = text.trim();
+    if text.len() != 27 {
+        return Err("KSUID must be 27 characters".to_string());
+    }
+    let digits = text
+        .bytes()
+        .map(|c| BASE62.iter().position(|&b| b == c).map(|d| d as u8).ok_or_else(|| format!("Invalid KSUID character '{}'", c as char)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let raw = num_bigint::BigUint::from_radix_be(&digits, 62).ok_or("Invalid KSUID")?.to_bytes_be();
+    if raw.len() > 20 {
+        return Err("KSUID overflows 160 bits".to_string());
+    }
+    let mut bytes = [0u8; 20];
+    bytes[20 - raw.len()..].copy_from_slice(&raw);
+    let secs = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    Ok(IdReport {
+        kind: "KSUID",
+        timestamp: Some((i128::from(secs) + KSUID_EPOCH as i128) * 1_000_000_000),
+        fields: vec![("KSUID time", secs.to_string()), ("Payload", hex::encode(&bytes[4..])), ("Raw", hex::encode(bytes))],
+    })
+}
+
+/// NanoID's default URL-safe alphabet
+pub const NANOID_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789_-";
+
+fn nanoid_chars(alphabet: Option<&str>) -> Result<Vec<char>, String> {
+    let chars: Vec<char> = alphabet.unwrap_or(NANOID_ALPHABET).chars().collect();
+    if !(2..=256).contains(&chars.len()) {
+        return Err("Alphabet needs 2 to 256 characters".to_string());
+    }
+    if let Some(c) = chars.iter().enumerate().find_map(|(i, c)| chars[..i].contains(c).then_some(c)) {
+        return Err(format!("Alphabet repeats '{}'", c));
+    }
+    Ok(chars)
+}
+
+/// NanoID of `size` characters drawn uniformly from `alphabet` (URL-safe by default)
+pub fn nanoid(src: &mut Source, size: usize, alphabet: Option<&str>) -> Result<String, String> {
+    if size == 0 {
+        return Err("Size must be at least 1".to_string());
+    }
+    let chars = nanoid_chars(alphabet)?;
+    Ok((0..size).map(|_| src.pick(&chars)).collect())
+}
+
+/// Validate a NanoID against its alphabet; NanoIDs carry no timestamp
+pub fn nanoid_inspect(text: &str, alphabet: Option<&str>) -> Result<IdReport, String> {
+    let chars = nanoid_chars(alphabet)?;
+    let text = text.trim();
+    if let Some(c) = text.chars().find(|c| !chars.contains(c)) {
+        return Err(format!("'{}' is not in the NanoID alphabet", c));
+    }
+    let length = text.chars().count();
+    Ok(IdReport {
+        kind: "NanoID",
+        timestamp: None,
+        fields: vec![
+            ("Length", length.to_string()),
+            ("Alphabet", format!("{} characters", chars.len())),
+            ("Entropy", format!("{:.0} bits", length as f64 * (chars.len() as f64).log2())),
+        ],
+    })
+}
+
+/// Base36 of SHA3-512, dropping the first (biased) digit, as CUID2 does
+fn cuid2_hash(input: &str) -> String {
+    use sha3::{Digest, Sha3_512};
+    let digits = num_bigint::BigUint::from_bytes_be(&Sha3_512::digest(input.as_bytes())).to_str_radix(36);
+    digits[1..].to_string()
+}
+
+fn base36_entropy(src: &mut Source, length: usize) -> String {
+    (0..length).map(|_| std::char::from_digit(src.gen_range(0..36), 36).unwrap_or('0')).collect()
+}
+
+/// CUID2 counter start and host fingerprint
+fn cuid2_session(src: &mut Source, host: &str) -> (u64, String) {
+    let seed = format!("{}{}", host, base36_entropy(src, 32));
+    (src.gen_range(0..476_782_367), cuid2_hash(&seed)[..32].to_string())
+}
+
+/// CUID2: a random letter followed by a SHA3 hash of time, entropy, a counter and a host fingerprint
+pub fn cuid2(src: &mut Source, length: usize) -> Result<String, String> {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::OnceLock;
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    static SESSION: OnceLock<(u64, String)> = OnceLock::new();
+
+    if !(2..=32).contains(&length) {
+        return Err("CUID2 length must be 2 to 32".to_string());
+    }
+    // Seeded IDs can't depend on the process, so they draw a fresh session each time
+    let (count, fingerprint) = if src.is_seeded() {
+        cuid2_session(src, "seeded")
+    } else {
+        let session = SESSION.get_or_init(|| cuid2_session(src, &std::process::id().to_string()));
+        (session.0 + COUNTER.fetch_add(1, Ordering::Relaxed), session.1.clone())
+    };
+    let input = format!(
+        "{}{}{}{}",
+        num_bigint::BigUint::from(src.now_millis()).to_str_radix(36),
+        base36_entropy(src, length),
+        num_bigint::BigUint::from(count).to_str_radix(36),
+        fingerprint
+    );
+    let letter = (b'a' + src.gen_range(0..26)) as char;
+    Ok(format!("{}{}", letter, &cuid2_hash(&input)[1..length]))
+}
+
+/// Validate a CUID2; the hash hides when and where it was made
+pub fn cuid2_inspect(text: &str) -> Result<IdReport, String> {
+    let text = text.trim();
+    let valid = (2..=32).contains(&text.len())
+        && text.starts_with(|c: char| c.is_ascii_lowercase())
+        && text.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+    if !valid {
+        return Err("CUID2 is 2-32 characters of a-z0-9 starting with a letter".to_string());
+    }
+    Ok(IdReport {
+        kind: "CUID2",
+        timestamp: None,
+        fields: vec![("Length", text.len().to_string()), ("Note", "opaque hash, no embedded time".to_string())],
+    })
+}
+
+/// `twitter`, `discord` or a custom epoch in Unix milliseconds
+pub fn snowflake_epoch(name: &str) -> Result<i64, String> {
+    match name.to_ascii_lowercase().as_str() {
+        "twitter" | "x" => Ok(super::time::TWITTER_EPOCH_MS as i64),
+        "discord" => Ok(super::time::DISCORD_EPOCH_MS as i64),
+        other => other.parse().map_err(|_| format!("Unknown epoch '{}'. Use twitter, discord or Unix milliseconds", name)),
+    }
+}
+
+/// Snowflake: 41-bit milliseconds since `epoch_ms`, 10-bit worker ID, 12-bit sequence
+pub fn snowflake(src: &mut Source, epoch_ms: i64, worker: u16) -> Result<String, String> {
+    // Last millisecond used and its sequence number
+    static STATE: std::sync::Mutex<(u64, u16)> = std::sync::Mutex::new((0, 0));
+
+    if worker > 1023 {
+        return Err("Worker ID must be 0-1023".to_string());
+    }
+    // The seeded clock ticks on every read, so each ID gets its own millisecond
+    let (millis, sequence) = if src.is_seeded() {
+        (src.now_millis(), 0)
+    } else {
+        let mut state = STATE.lock().map_err(|_| "Snowflake state poisoned".to_string())?;
+        let mut millis = src.now_millis().max(state.0);
+        let mut sequence = if millis == state.0 { state.1 + 1 } else { 0 };
+        if sequence > 0xfff {
+            // 4096 IDs this millisecond already: wait for the next one
+            while millis <= state.0 {
+                std::thread::yield_now();
+                millis = src.now_millis();
+            }
+            sequence = 0;
+        }
+        *state = (millis, sequence);
+        (millis, sequence)
+    };
+    let elapsed = millis as i64 - epoch_ms;
+    if !(0..1 << 41).contains(&elapsed) {
+        return Err("Current time is outside the 41-bit range of this epoch".to_string());
+    }
+    Ok(((elapsed as u64) << 22 | u64::from(worker) << 12 | u64::from(sequence)).to_string())
+}
+
+pub fn snowflake_inspect(text: &str, epoch_ms: i64) -> Result<IdReport, String> {
+    let id: u64 = text.trim().parse().map_err(|_| "Snowflake must be a 64-bit integer".to_string())?;
+    let worker = (id >> 12) & 0x3ff;
+    Ok(IdReport {
+        kind: "Snowflake",
+        timestamp: Some((i128::from(id >> 22) + i128::from(epoch_ms)) * 1_000_000),
+        fields: vec![
+            ("Worker", format!("{} (datacenter {}, worker {})", worker, worker >> 5, worker & 0x1f)),
+            ("Sequence", (id & 0xfff).to_string()),
+            ("Epoch", format!("{} ms", epoch_ms)),
+        ],
+    })
+}
+
+/// MongoDB ObjectId: 32-bit seconds, 5 random bytes, 24-bit counter
+pub fn object_id(src: &mut Source) -> String {
+    if !src.is_seeded() {
+        return bson::oid::ObjectId::new().to_hex();
+    }
+    // bson keeps its random value and counter per process; seeded IDs draw both
+    let mut bytes = [0u8; 12];
+    bytes[..4].copy_from_slice(&((src.now_millis() / 1000) as u32).to_be_bytes());
+    src.fill(&mut bytes[4..]);
+    hex::encode(bytes)
+}
+
+pub fn object_id_inspect(text: &str) -> Result<IdReport, String> {
+    let oid = bson::oid::ObjectId::parse_str(text.trim()).map_err(|_| "ObjectId must be 24 hex characters".to_string())?;
+    let bytes = oid.bytes();
+    let secs = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    Ok(IdReport {
+        kind: "ObjectId",
+        timestamp: Some(i128::from(secs) * 1_000_000_000),
+        fields: vec![
+            ("Random", hex::encode(&bytes[4..9])),
+            ("Counter", u32::from_be_bytes([0, bytes[9], bytes[10], bytes[11]]).to_string()),
+        ],
+    })
+}
+
+/// Guess the ID type by shape: ObjectId, ULID, KSUID or Snowflake (with `epoch_ms`)
+pub fn id_inspect(text: &str, epoch_ms: i64) -> Result<IdReport, String> {
+    let text = text.trim();
+    match text.len() {
+        24 if text.chars().all(|c| c.is_ascii_hexdigit()) => object_id_inspect(text),
+        26 => ulid_inspect(text),
+        27 => ksuid_inspect(text),
+        1..=20 if text.chars().all(|c| c.is_ascii_digit()) => snowflake_inspect(text, epoch_ms),
+        _ => Err("Not a recognized ULID, KSUID, Snowflake or ObjectId".to_string()),
+    }
+}
+
+// ============================================================================
+// BULK OUTPUT
+// ============================================================================
+
+/// How a batch of generated values is written out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListFormat {
+    /// One value per line
+    #[default]
+    Lines,
+    Json,
+    Csv,
+    /// A SQL `VALUES` list
+    Sql,
+}
+
+pub const LIST_FORMATS: [ListFormat; 4] = [ListFormat::Lines, ListFormat::Json, ListFormat::Csv, ListFormat::Sql];
+
+impl ListFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ListFormat::Lines => "lines",
+            ListFormat::Json => "json",
+            ListFormat::Csv => "csv",
+            ListFormat::Sql => "sql",
+        }
+    }
+
+    /// File extension for saving
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ListFormat::Lines => "txt",
+            other => other.name(),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "lines" | "text" | "txt" => Ok(ListFormat::Lines),
+            "json" => Ok(ListFormat::Json),
+            "csv" => Ok(ListFormat::Csv),
+            "sql" => Ok(ListFormat::Sql),
+            _ => Err(format!("Unknown output format '{}'. Use lines, json, csv or sql", name)),
+        }
+    }
+}
+
+/// Most values one bulk request may ask for
+pub const MAX_COUNT: usize = 10_000_000;
+
+/// Call `next` until `count` values exist; with `unique`, repeats are drawn again
+pub fn generate_many(count: usize, unique: bool, mut next: impl FnMut() -> Result<String, String>) -> Result<Vec<String>, String> {
+    if count > MAX_COUNT {
+        return Err(format!("Count must be at most {}", MAX_COUNT));
+    }
+    // Grow past the first batch as values arrive rather than reserving everything
+    let mut values = Vec::with_capacity(count.min(4096));
+    if !unique {
+        for _ in 0..count {
+            values.push(next()?);
+        }
+        return Ok(values);
+    }
+    let mut seen = std::collections::HashSet::with_capacity(count.min(4096));
+    let max_attempts = count.saturating_mul(10).saturating_add(1000);
+    let mut attempts = 0;
+    while values.len() < count {
+        if attempts == max_attempts {
+            return Err(format!(
+                "Only {} unique values after {} attempts; use a longer length or larger alphabet",
+                values.len(),
+                attempts
+            ));
+        }
+        attempts += 1;
+        let value = next()?;
+        if seen.insert(value.clone()) {
+            values.push(value);
+        }
+    }
+    Ok(values)
+}
+
+/// Render generated values as lines, a JSON array, a CSV column or a SQL `VALUES` list
+pub fn format_list(values: &[String], format: ListFormat) -> String {
+    match format {
+        ListFormat::Lines => values.join("\n"),
+        ListFormat::Json => serde_json::to_string_pretty(values).unwrap_or_default(),
+        ListFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            for value in values {
+                let _ = writer.write_record([value]);
+            }
+            let bytes = writer.into_inner().unwrap_or_default();
+            String::from_utf8_lossy(&bytes).trim_end().to_string()
+        }
+        ListFormat::Sql => {
+            let rows: Vec<String> = values.iter().map(|v| format!("  ('{}')", v.replace('\'', "''"))).collect();
+            format!("VALUES\n{};", rows.join(",\n"))
+        }
+    }
+}
+
+/// Theoretical entropy of a random string: `length × log2(charset)` bits
+pub fn password_entropy(length: usize, charset: usize) -> f64 {
+    if charset < 2 {
+        return 0.0;
+    }
+    length as f64 * (charset as f64).log2()
 }
 
 /// Generate a password with specified length and character set
-pub fn password(length: usize, charset: &str) -> String {
-    let mut rng = rand::thread_rng();
+pub fn password(src: &mut Source, length: usize, charset: &str) -> String {
     let chars: Vec<char> = charset.chars().collect();
     (0..length)
-        .map(|_| chars[rng.gen_range(0..chars.len())])
+        .map(|_| src.pick(&chars))
+        .collect()
+}
+
+// ============================================================================
+// PASSWORD POLICY
+// ============================================================================
+
+/// Symbols a [`PasswordPolicy`] draws from (the same set as [`CHARS_ALL`])
+pub const PASSWORD_SYMBOLS: &str = "!@#$%^&*()-_=+";
+/// Characters easily mistaken for one another
+pub const LOOKALIKES: &str = "0O1lI";
+
+/// Password rules: character classes with minimum counts, custom sets and repetition limits.
+/// Build with `PasswordPolicy::new(20).min_digits(3).symbols(false)` and call [`generate`](Self::generate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    pub length: usize,
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub digits: bool,
+    pub symbols: bool,
+    /// Minimum count per class, applied only when the class is enabled
+    pub min_lowercase: usize,
+    pub min_uppercase: usize,
+    pub min_digits: usize,
+    pub min_symbols: usize,
+    /// Extra characters added to the pool
+    pub include: String,
+    /// Characters never used, even when listed in `include`
+    pub exclude: String,
+    /// Drop [`LOOKALIKES`] from the classes
+    pub no_lookalikes: bool,
+    /// Use each character at most once
+    pub no_repeats: bool,
+    /// Longest allowed run of one character (0 = unlimited)
+    pub max_run: usize,
+}
+
+impl Default for PasswordPolicy {
+    fn default() -> Self {
+        PasswordPolicy {
+            length: 16,
+            lowercase: true,
+            uppercase: true,
+            digits: true,
+            symbols: true,
+            min_lowercase: 1,
+            min_uppercase: 1,
+            min_digits: 1,
+            min_symbols: 1,
+            include: String::new(),
+            exclude: String::new(),
+            no_lookalikes: false,
+            no_repeats: false,
+            max_run: 0,
+        }
+    }
+}
+
+/// Length of the longest run of one repeated character
+fn longest_run(chars: &[char]) -> usize {
+    let (mut longest, mut run) = (0, 0);
+    for (i, c) in chars.iter().enumerate() {
+        run = if i > 0 && chars[i - 1] == *c { run + 1 } else { 1 };
+        longest = longest.max(run);
+    }
+    longest
+}
+
+impl PasswordPolicy {
+    /// All four classes, at least one of each when the length allows
+    pub fn new(length: usize) -> Self {
+        PasswordPolicy { length, ..Default::default() }.one_of_each()
+    }
+
+    /// Require one character of each enabled class, or none when they don't all fit
+    pub fn one_of_each(mut self) -> Self {
+        let enabled = [self.lowercase, self.uppercase, self.digits, self.symbols].iter().filter(|&&on| on).count();
+        let min = usize::from(self.length >= enabled);
+        self.min_lowercase = min;
+        self.min_uppercase = min;
+        self.min_digits = min;
+        self.min_symbols = min;
+        self
+    }
+
+    pub fn lowercase(mut self, on: bool) -> Self {
+        self.lowercase = on;
+        self
+    }
+
+    pub fn uppercase(mut self, on: bool) -> Self {
+        self.uppercase = on;
+        self
+    }
+
+    pub fn digits(mut self, on: bool) -> Self {
+        self.digits = on;
+        self
+    }
+
+    pub fn symbols(mut self, on: bool) -> Self {
+        self.symbols = on;
+        self
+    }
+
+    /// Require at least `n` lowercase letters (enables the class)
+    pub fn min_lowercase(mut self, n: usize) -> Self {
+        self.lowercase = true;
+        self.min_lowercase = n;
+        self
+    }
+
+    pub fn min_uppercase(mut self, n: usize) -> Self {
+        self.uppercase = true;
+        self.min_uppercase = n;
+        self
+    }
+
+    pub fn min_digits(mut self, n: usize) -> Self {
+        self.digits = true;
+        self.min_digits = n;
+        self
+    }
+
+    pub fn min_symbols(mut self, n: usize) -> Self {
+        self.symbols = true;
+        self.min_symbols = n;
+        self
+    }
+
+    pub fn include(mut self, chars: &str) -> Self {
+        self.include = chars.to_string();
+        self
+    }
+
+    pub fn exclude(mut self, chars: &str) -> Self {
+        self.exclude = chars.to_string();
+        self
+    }
+
+    pub fn no_lookalikes(mut self, on: bool) -> Self {
+        self.no_lookalikes = on;
+        self
+    }
+
+    pub fn no_repeats(mut self, on: bool) -> Self {
+        self.no_repeats = on;
+        self
+    }
+
+    pub fn max_run(mut self, n: usize) -> Self {
+        self.max_run = n;
+        self
+    }
+
+    /// Enabled classes after exclusions, with their minimum counts
+    fn classes(&self) -> Vec<(&'static str, Vec<char>, usize)> {
+        let keep = |c: &char| !(self.exclude.contains(*c) || (self.no_lookalikes && LOOKALIKES.contains(*c)));
+        [
+            ("lowercase", self.lowercase, "abcdefghijklmnopqrstuvwxyz", self.min_lowercase),
+            ("uppercase", self.uppercase, "ABCDEFGHIJKLMNOPQRSTUVWXYZ", self.min_uppercase),
+            ("digit", self.digits, CHARS_NUMERIC, self.min_digits),
+            ("symbol", self.symbols, PASSWORD_SYMBOLS, self.min_symbols),
+        ]
+        .into_iter()
+        .filter(|(_, on, _, _)| *on)
+        .map(|(name, _, set, min)| (name, set.chars().filter(keep).collect(), min))
         .collect()
+    }
+
+    /// Every character the password may contain
+    fn pool(&self, classes: &[(&'static str, Vec<char>, usize)]) -> Vec<char> {
+        let mut pool: Vec<char> = classes.iter().flat_map(|(_, set, _)| set.iter().copied()).collect();
+        for c in self.include.chars().filter(|c| !self.exclude.contains(*c)) {
+            if !pool.contains(&c) {
+                pool.push(c);
+            }
+        }
+        pool
+    }
+
+    /// Theoretical entropy in bits: `length × log2(pool size)`
+    pub fn entropy(&self) -> f64 {
+        password_entropy(self.length, self.pool(&self.classes()).len())
+    }
+
+    /// A random password meeting every rule, or why none can exist
+    pub fn generate(&self, src: &mut Source) -> Result<String, String> {
+        use rand::seq::SliceRandom;
+
+        let classes = self.classes();
+        let pool = self.pool(&classes);
+        if pool.is_empty() {
+            return Err("No characters left to choose from".to_string());
+        }
+        let required: usize = classes.iter().map(|(_, _, min)| min).sum();
+        if required > self.length {
+            return Err(format!("Length {} is shorter than the {} required characters", self.length, required));
+        }
+        for (name, set, min) in &classes {
+            if *min > 0 && set.is_empty() {
+                return Err(format!("No {} characters left after exclusions", name));
+            }
+            if self.no_repeats && *min > set.len() {
+                return Err(format!("Only {} distinct {} characters, {} required", set.len(), name, min));
+            }
+        }
+        if self.no_repeats && self.length > pool.len() {
+            return Err(format!("Only {} distinct characters for a {}-character password without repeats", pool.len(), self.length));
+        }
+        if self.max_run > 0 && pool.len() == 1 && self.length > self.max_run {
+            return Err(format!("One character can't avoid runs longer than {}", self.max_run));
+        }
+
+        let draw = |src: &mut Source, set: &[char], used: &[char]| {
+            let candidates: Vec<char> = set.iter().copied().filter(|c| !self.no_repeats || !used.contains(c)).collect();
+            src.pick(&candidates)
+        };
+        for _ in 0..1000 {
+            let mut chars = Vec::with_capacity(self.length);
+            for (_, set, min) in &classes {
+                for _ in 0..*min {
+                    let c = draw(src, set, &chars);
+                    chars.push(c);
+                }
+            }
+            while chars.len() < self.length {
+                let c = draw(src, &pool, &chars);
+                chars.push(c);
+            }
+            chars.shuffle(src);
+            if self.max_run == 0 || longest_run(&chars) <= self.max_run {
+                return Ok(chars.into_iter().collect());
+            }
+        }
+        Err(format!("Could not keep runs to {} characters; allow longer runs or more characters", self.max_run))
+    }
+}
+
+// ============================================================================
+// REGEX
+// ============================================================================
+
+/// Longest string a regex pattern may generate
+pub const MAX_REGEX_LENGTH: u64 = 1_000_000;
+
+/// A parsed pattern, reduced to what generation needs
+#[derive(Debug, Clone)]
+enum RegexNode {
+    Literal(String),
+    /// Inclusive code point ranges
+    Class(Vec<(u32, u32)>),
+    Repeat(Box<RegexNode>, u32, Option<u32>),
+    Concat(Vec<RegexNode>),
+    Alternation(Vec<RegexNode>),
+}
+
+/// Random strings matching a regular expression
+///
+/// Supports literals, classes (`[A-Z]`, `\d`, `\w`, `.`), quantifiers,
+/// alternation and groups. Anchors are allowed only at the ends of the
+/// pattern, and word boundaries are rejected. Classes are narrowed to printable ASCII unless that leaves nothing or
+/// `unicode` is set. Patterns that could generate more than [`MAX_REGEX_LENGTH`] characters are refused.
+#[derive(Debug, Clone)]
+pub struct RegexPattern {
+    root: RegexNode,
+    /// Most extra repetitions for `*`, `+` and `{n,}`
+    max_repeat: u32,
+}
+
+impl RegexPattern {
+    /// Parse `pattern`; with `unicode`, classes draw from every matching code point
+    pub fn new(pattern: &str, unicode: bool) -> Result<Self, String> {
+        let hir = regex_syntax::ParserBuilder::new().build().parse(pattern).map_err(|e| format!("Invalid regex: {}", e))?;
+        Ok(RegexPattern { root: regex_node(&hir, unicode, true, true)?, max_repeat: 8 })
+    }
+
+    pub fn max_repeat(mut self, n: u32) -> Self {
+        self.max_repeat = n;
+        self
+    }
+
+    /// One random string that the pattern matches
+    pub fn generate(&self, src: &mut Source) -> Result<String, String> {
+        if self.longest(&self.root) > MAX_REGEX_LENGTH {
+            return Err(format!("Pattern can generate more than {} characters; use smaller repeat counts", MAX_REGEX_LENGTH));
+        }
+        let mut out = String::new();
+        self.walk(&self.root, src, &mut out);
+        Ok(out)
+    }
+
+    /// Most characters `node` can produce
+    fn longest(&self, node: &RegexNode) -> u64 {
+        match node {
+            RegexNode::Literal(text) => text.chars().count() as u64,
+            RegexNode::Class(_) => 1,
+            RegexNode::Repeat(sub, min, max) => {
+                let max = max.unwrap_or_else(|| min.saturating_add(self.max_repeat));
+                u64::from(max).saturating_mul(self.longest(sub))
+            }
+            RegexNode::Concat(nodes) => nodes.iter().fold(0, |total, sub| total.saturating_add(self.longest(sub))),
+            RegexNode::Alternation(nodes) => nodes.iter().map(|sub| self.longest(sub)).max().unwrap_or(0),
+        }
+    }
+
+    fn walk(&self, node: &RegexNode, src: &mut Source, out: &mut String) {
+        match node {
+            RegexNode::Literal(text) => out.push_str(text),
+            RegexNode::Class(ranges) => {
+                let total: u64 = ranges.iter().map(|(lo, hi)| u64::from(hi - lo) + 1).sum();
+                let mut n = src.gen_range(0..total);
+                for (lo, hi) in ranges {
+                    let size = u64::from(hi - lo) + 1;
+                    if n < size {
+                        out.extend(char::from_u32(lo + n as u32));
+                        break;
+                    }
+                    n -= size;
+                }
+            }
+            RegexNode::Repeat(sub, min, max) => {
+                let max = max.unwrap_or_else(|| min.saturating_add(self.max_repeat));
+                let count = src.gen_range(*min..=max);
+                for _ in 0..count {
+                    self.walk(sub, src, out);
+                }
+            }
+            RegexNode::Concat(nodes) => nodes.iter().for_each(|sub| self.walk(sub, src, out)),
+            RegexNode::Alternation(nodes) => {
+                let branch = src.index(nodes.len());
+                self.walk(&nodes[branch], src, out);
+            }
+        }
+    }
+}
+
+/// `start` and `end` say whether `hir` sits at that edge of the pattern, where anchors are no-ops
+fn regex_node(hir: &regex_syntax::hir::Hir, unicode: bool, start: bool, end: bool) -> Result<RegexNode, String> {
+    use regex_syntax::hir::{Class, HirKind, Look};
+    let is_look = |sub: &regex_syntax::hir::Hir| matches!(sub.kind(), HirKind::Look(_));
+    Ok(match hir.kind() {
+        HirKind::Empty => RegexNode::Concat(Vec::new()),
+        HirKind::Look(Look::Start | Look::StartLF | Look::StartCRLF) if start => RegexNode::Concat(Vec::new()),
+        HirKind::Look(Look::End | Look::EndLF | Look::EndCRLF) if end => RegexNode::Concat(Vec::new()),
+        HirKind::Look(Look::Start | Look::StartLF | Look::StartCRLF | Look::End | Look::EndLF | Look::EndCRLF) => {
+            return Err("Anchors are only supported at the start or end of the pattern".to_string())
+        }
+        HirKind::Look(_) => return Err(r"Word boundaries (\b, \B) are not supported".to_string()),
+        HirKind::Class(class) if class.is_empty() => return Err("Pattern has a class that matches nothing".to_string()),
+        HirKind::Literal(literal) => RegexNode::Literal(String::from_utf8_lossy(&literal.0).into_owned()),
+        HirKind::Class(Class::Unicode(class)) => {
+            // Char ranges may span the surrogate gap, which has no chars to draw
+            let ranges: Vec<(u32, u32)> = class
+                .ranges()
+                .iter()
+                .flat_map(|r| {
+                    let (lo, hi) = (u32::from(r.start()), u32::from(r.end()));
+                    [(lo, hi.min(0xd7ff)), (lo.max(0xe000), hi)]
+                })
+                .filter(|(lo, hi)| lo <= hi)
+                .collect();
+            let printable: Vec<(u32, u32)> =
+                ranges.iter().filter(|(lo, hi)| *lo <= 0x7e && *hi >= 0x20).map(|(lo, hi)| (*lo.max(&0x20), *hi.min(&0x7e))).collect();
+            if unicode || printable.is_empty() {
+                RegexNode::Class(ranges)
+            } else {
+                RegexNode::Class(printable)
+            }
+        }
+        HirKind::Class(Class::Bytes(_)) => return Err("Byte classes (?-u) are not supported".to_string()),
+        HirKind::Repetition(rep) => RegexNode::Repeat(Box::new(regex_node(&rep.sub, unicode, false, false)?), rep.min, rep.max),
+        HirKind::Capture(capture) => regex_node(&capture.sub, unicode, start, end)?,
+        HirKind::Concat(subs) => RegexNode::Concat(
+            (0..subs.len())
+                .map(|i| {
+                    let at_start = start && subs[..i].iter().all(is_look);
+                    let at_end = end && subs[i + 1..].iter().all(is_look);
+                    regex_node(&subs[i], unicode, at_start, at_end)
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        HirKind::Alternation(subs) => RegexNode::Alternation(
+            subs.iter().map(|sub| regex_node(sub, unicode, start, end)).collect::<Result<_, _>>()?,
+        ),
+    })
+}
+
+// ============================================================================
+// PASSPHRASE
+// ============================================================================
+
+/// The BIP-39 English list: 2048 common words, unique in their first four letters
+const BIP39_ENGLISH: &str = include_str!("../wordlists/bip39_english.txt");
+
+/// Letter case applied to each passphrase word
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WordCase {
+    #[default]
+    Lower,
+    /// First letter uppercase
+    Title,
+    Upper,
+}
+
+pub const WORD_CASES: [WordCase; 3] = [WordCase::Lower, WordCase::Title, WordCase::Upper];
+
+impl WordCase {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WordCase::Lower => "lower",
+            WordCase::Title => "title",
+            WordCase::Upper => "upper",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "lower" => Ok(WordCase::Lower),
+            "title" | "capitalize" => Ok(WordCase::Title),
+            "upper" => Ok(WordCase::Upper),
+            _ => Err(format!("Unknown case '{}'. Use lower, title or upper", name)),
+        }
+    }
+
+    fn apply(&self, word: &str) -> String {
+        match self {
+            WordCase::Lower => word.to_lowercase(),
+            WordCase::Title => {
+                let mut chars = word.chars();
+                chars.next().map(|c| c.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect()).unwrap_or_default()
+            }
+            WordCase::Upper => word.to_uppercase(),
+        }
+    }
+}
+
+/// Diceware passphrase options
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassphraseOptions {
+    pub words: usize,
+    pub separator: String,
+    pub case: WordCase,
+    /// Append a random digit to one random word
+    pub digit: bool,
+    /// Append a random symbol to one random word
+    pub symbol: bool,
+}
+
+impl Default for PassphraseOptions {
+    fn default() -> Self {
+        PassphraseOptions { words: 6, separator: "-".to_string(), case: WordCase::Lower, digit: false, symbol: false }
+    }
+}
+
+/// Words from a list file: one per line, with optional dice numbers (`11111<TAB>abacus`) as in the EFF lists;
+/// words differing only in case count once
+pub fn parse_wordlist(text: &str) -> Vec<&str> {
+    let mut words: Vec<&str> = text.lines().filter_map(|line| line.split_whitespace().last()).collect();
+    words.sort_unstable_by_key(|w| w.to_lowercase());
+    words.dedup_by(|a, b| a.to_lowercase() == b.to_lowercase());
+    words
+}
+
+/// The bundled BIP-39 English wordlist
+pub fn default_wordlist() -> Vec<&'static str> {
+    parse_wordlist(BIP39_ENGLISH)
+}
+
+/// A passphrase of words drawn uniformly from `list`, and its entropy in bits
+pub fn passphrase(src: &mut Source, list: &[&str], options: &PassphraseOptions) -> Result<(String, f64), String> {
+    // Words differing only in case look alike once `case` is applied
+    let mut list: Vec<String> = list.iter().map(|w| w.to_lowercase()).collect();
+    list.sort_unstable();
+    list.dedup();
+    if list.len() < 2 {
+        return Err("Wordlist needs at least 2 distinct words".to_string());
+    }
+    if options.words == 0 {
+        return Err("Need at least 1 word".to_string());
+    }
+    let mut words: Vec<String> = (0..options.words).map(|_| options.case.apply(&list[src.index(list.len())])).collect();
+    let mut entropy = options.words as f64 * (list.len() as f64).log2();
+    let symbols: Vec<char> = PASSWORD_SYMBOLS.chars().collect();
+    for (on, set) in [(options.digit, CHARS_NUMERIC.chars().collect::<Vec<_>>()), (options.symbol, symbols)] {
+        if on {
+            let c = src.pick(&set);
+            words[src.index(options.words)].push(c);
+            entropy += (set.len() as f64).log2() + (options.words as f64).log2();
+        }
+    }
+    Ok((words.join(&options.separator), entropy))
 }
 
 /// Generate a strong password (alphanumeric + symbols)
-pub fn strong_password(length: usize) -> String {
-    password(length, CHARS_ALL)
+pub fn strong_password(src: &mut Source, length: usize) -> String {
+    password(src, length, CHARS_ALL)
 }
 
 /// Generate an alphanumeric password
-pub fn alphanum_password(length: usize) -> String {
-    password(length, CHARS_ALPHANUMERIC)
+pub fn alphanum_password(src: &mut Source, length: usize) -> String {
+    password(src, length, CHARS_ALPHANUMERIC)
 }
 
 /// Generate random hex bytes
-pub fn random_hex(byte_count: usize) -> String {
-    let mut rng = rand::thread_rng();
-    let bytes: Vec<u8> = (0..byte_count).map(|_| rng.gen()).collect();
+pub fn random_hex(src: &mut Source, byte_count: usize) -> String {
+    let bytes: Vec<u8> = (0..byte_count).map(|_| src.gen()).collect();
     hex::encode(bytes)
 }
 
 /// Generate random bytes as base64
-pub fn random_base64(byte_count: usize) -> String {
+pub fn random_base64(src: &mut Source, byte_count: usize) -> String {
     use base64::{Engine as _, engine::general_purpose::STANDARD};
-    let mut rng = rand::thread_rng();
-    let bytes: Vec<u8> = (0..byte_count).map(|_| rng.gen()).collect();
+    let bytes: Vec<u8> = (0..byte_count).map(|_| src.gen()).collect();
     STANDARD.encode(bytes)
 }
 
@@ -55,20 +1274,221 @@ mod tests {
 
     #[test]
     fn test_uuid() {
-        let id = uuid_v4();
+        let mut src = Source::system();
+        let id = uuid_v4(&mut src);
         assert_eq!(id.len(), 36);
         assert!(id.contains('-'));
     }
 
+    #[test]
+    fn test_uuid_versions() {
+        let mut src = Source::system();
+        for v in UUID_VERSIONS {
+            let id = uuid(&mut src, v, Some("url"), Some("https://example.com")).unwrap();
+            assert_eq!(Uuid::parse_str(&id).unwrap().get_version_num(), v as usize);
+        }
+        // RFC 9562 Appendix A.2 / A.4 and B.2 (www.example.com in the DNS namespace)
+        assert_eq!(uuid(&mut src, 3, Some("dns"), Some("www.example.com")).unwrap(), "5df41881-3aed-3515-88a7-2f4a814cf09e");
+        assert_eq!(uuid(&mut src, 5, None, Some("www.example.com")).unwrap(), "2ed6657d-e927-568b-95e1-2665a8aea6a2");
+        assert_eq!(uuid(&mut src, 8, Some("dns"), Some("www.example.com")).unwrap(), "5c146b14-3c52-8afd-938a-375d0df1fbf6");
+        assert!(uuid(&mut src, 5, None, None).is_err());
+        assert!(uuid(&mut src, 2, None, None).is_err());
+    }
+
+    #[test]
+    fn test_uuid_inspect() {
+        // RFC 9562 Appendix A.1: v1 at 2022-02-22T19:22:22Z
+        let report = uuid_inspect("C232AB00-9414-11EC-B3C8-9F6BDECED846", None).unwrap();
+        assert_eq!((report.version, report.variant), (1, "RFC 9562"));
+        assert_eq!(report.timestamp, Some(1_645_557_742_000_000_000));
+        assert_eq!(report.clock_seq, Some(0x33c8));
+        assert_eq!(report.node.as_deref(), Some("9f:6b:de:ce:d8:46 (random)"));
+        assert!(report.summary().contains("2022-02-22T19:22:22Z"));
+        assert_eq!(uuid_inspect("017f22e2-79b0-7cc3-98c4-dc0c0c07398f", None).unwrap().timestamp, Some(1_645_557_742_000_000_000));
+        for text in ["not a uuid", "abc", "Test", "12345"] {
+            assert_eq!(uuid_inspect(text, None).unwrap_err(), format!("Not a UUID: {}", text));
+        }
+        assert_eq!(uuid_parse("1", Some("integer")).unwrap().as_u128(), 1);
+
+        // Every printed form reads back; unnamed forms never decode to a different UUID
+        let mut src = Source::system();
+        for _ in 0..300 {
+            let report = uuid_inspect(&uuid(&mut src, 4, None, None).unwrap(), None).unwrap();
+            for (label, form) in &report.forms {
+                assert_eq!(uuid_parse(form, Some(label)).unwrap(), report.uuid, "{}", form);
+                if *label != "Integer" {
+                    assert!(uuid_parse(form, None).map_or(true, |id| id == report.uuid), "{}", form);
+                }
+            }
+        }
+        let base58 = "GcHaGgg1UMUkGW5MJRoSfw";
+        assert!(uuid_parse(base58, None).unwrap_err().contains("--from base58"));
+        assert_eq!(uuid_parse(base58, Some("base58")).unwrap().to_string(), "7e665361-c9e0-4497-8f18-6003ce7f7ba6");
+    }
+
+    #[test]
+    fn test_other_ids() {
+        let mut src = Source::system();
+        // Reference values from the segmentio/ksuid README and MongoDB docs
+        let report = ksuid_inspect("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+        assert_eq!(report.timestamp, Some(1_507_608_047_000_000_000));
+        assert!(report.summary().contains("b5a1cd34b5f99d1154fb6853345c9735"));
+        assert_eq!(object_id_inspect("507f1f77bcf86cd799439011").unwrap().timestamp, Some(1_350_508_407_000_000_000));
+        let report = snowflake_inspect("175928847299117063", snowflake_epoch("discord").unwrap()).unwrap();
+        assert_eq!(report.timestamp, Some(1_462_015_105_796_000_000));
+        assert_eq!(report.fields[1].1, "7");
+        assert_eq!(ulid_inspect("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap().timestamp, Some(1_469_922_850_259_000_000));
+
+        for (id, kind) in [(ulid(&mut src), "ULID"), (ksuid(&mut src), "KSUID"), (object_id(&mut src), "ObjectId"), (snowflake(&mut src, 0, 5).unwrap(), "Snowflake")] {
+            assert_eq!(id_inspect(&id, 0).unwrap().kind, kind, "{}", id);
+        }
+        assert_eq!(nanoid(&mut src, 10, Some("ab")).unwrap().len(), 10);
+        assert!(nanoid(&mut src, 4, Some("aa")).is_err());
+        assert!(nanoid_inspect("abc", Some("ab")).is_err());
+        let id = cuid2(&mut src, 24).unwrap();
+        assert_eq!(id.len(), 24);
+        assert!(cuid2_inspect(&id).is_ok());
+    }
+
+    #[test]
+    fn test_bulk_output() {
+        let mut src = Source::system();
+        let ids = generate_many(5000, false, || snowflake(&mut src, 0, 1)).unwrap();
+        assert_eq!(ids.iter().collect::<std::collections::HashSet<_>>().len(), 5000);
+        assert!(generate_many(5, true, || nanoid(&mut src, 2, Some("ab"))).is_err());
+        assert_eq!(generate_many(4, true, || nanoid(&mut src, 2, Some("ab"))).unwrap().len(), 4);
+        assert_eq!(generate_many(usize::MAX, false, || Ok(String::new())).unwrap_err(), "Count must be at most 10000000");
+
+        let values = vec!["a".to_string(), "it's".to_string(), "x,y".to_string()];
+        assert_eq!(format_list(&values, ListFormat::Lines), "a\nit's\nx,y");
+        assert_eq!(format_list(&values, ListFormat::Csv), "a\nit's\n\"x,y\"");
+        assert_eq!(format_list(&values, ListFormat::Sql), "VALUES\n  ('a'),\n  ('it''s'),\n  ('x,y');");
+        assert_eq!(serde_json::from_str::<Vec<String>>(&format_list(&values, ListFormat::Json)).unwrap(), values);
+    }
+
     #[test]
     fn test_password_length() {
-        let pass = strong_password(16);
+        let mut src = Source::system();
+        let pass = strong_password(&mut src, 16);
         assert_eq!(pass.len(), 16);
     }
 
+    #[test]
+    fn test_password_policy() {
+        let mut src = Source::system();
+        for _ in 0..50 {
+            let pass = PasswordPolicy::new(12).min_digits(4).min_symbols(2).no_lookalikes(true).max_run(1).generate(&mut src).unwrap();
+            let chars: Vec<char> = pass.chars().collect();
+            assert_eq!(chars.len(), 12);
+            assert!(chars.iter().filter(|c| c.is_ascii_digit()).count() >= 4);
+            assert!(chars.iter().filter(|c| PASSWORD_SYMBOLS.contains(**c)).count() >= 2);
+            assert!(chars.iter().any(|c| c.is_ascii_lowercase()) && chars.iter().any(|c| c.is_ascii_uppercase()));
+            assert!(!chars.iter().any(|c| LOOKALIKES.contains(*c)));
+            assert_eq!(longest_run(&chars), 1);
+        }
+
+        let pass = PasswordPolicy::new(10).lowercase(false).uppercase(false).symbols(false).no_repeats(true).generate(&mut src).unwrap();
+        assert_eq!(pass.chars().collect::<std::collections::HashSet<_>>().len(), 10);
+        let pass = PasswordPolicy::new(8).digits(false).lowercase(false).uppercase(false).symbols(false).include("xy").generate(&mut src).unwrap();
+        assert!(pass.chars().all(|c| c == 'x' || c == 'y'));
+
+        // Too short for one of each: the default minimums step aside
+        assert_eq!(PasswordPolicy::new(3).generate(&mut src).unwrap().len(), 3);
+        assert_eq!(PasswordPolicy::new(2).symbols(false).one_of_each().generate(&mut src).unwrap().len(), 2);
+        assert!(PasswordPolicy::new(3).min_digits(4).generate(&mut src).is_err());
+        assert!(PasswordPolicy::new(11).uppercase(false).lowercase(false).symbols(false).no_repeats(true).generate(&mut src).is_err());
+        assert!(PasswordPolicy::new(8).symbols(false).exclude("0123456789").generate(&mut src).is_err());
+    }
+
+    #[test]
+    fn test_passphrase() {
+        let mut src = Source::system();
+        let list = default_wordlist();
+        assert_eq!(list.len(), 2048);
+        let (phrase, bits) = passphrase(&mut src, &list, &PassphraseOptions::default()).unwrap();
+        assert_eq!(phrase.split('-').count(), 6);
+        assert!(phrase.split('-').all(|w| list.contains(&w)));
+        assert_eq!(bits, 66.0);
+
+        let eff = parse_wordlist("11111\tabacus\n11112\tabdomen\n");
+        assert_eq!(eff, ["abacus", "abdomen"]);
+        assert_eq!(parse_wordlist("Apple\napple\npear\n").len(), 2);
+        let options = PassphraseOptions { words: 4, separator: " ".to_string(), case: WordCase::Title, digit: true, symbol: false };
+        let (phrase, bits) = passphrase(&mut src, &eff, &options).unwrap();
+        assert!(phrase.starts_with('A') && phrase.chars().any(|c| c.is_ascii_digit()));
+        assert!((bits - (4.0 + 10f64.log2() + 2.0)).abs() < 1e-9);
+        assert!(passphrase(&mut src, &["one"], &options).is_err());
+        let options = PassphraseOptions { words: 3, ..PassphraseOptions::default() };
+        assert_eq!(passphrase(&mut src, &["Apple", "apple", "pear"], &options).unwrap().1, 3.0);
+        assert!(passphrase(&mut src, &["Apple", "APPLE"], &options).is_err());
+    }
+
+    #[test]
+    fn test_seeded() {
+        // Pinned so a dependency bump can't silently change seeded fixtures
+        assert_eq!(random_hex(&mut Source::seeded(42), 16), "781199f9d55d18e855a7aa84c5e4b40d");
+        assert_eq!(uuid(&mut Source::seeded(42), 4, None, None).unwrap(), "781199f9-d55d-48e8-95a7-aa84c5e4b40d");
+        assert_eq!(uuid(&mut Source::seeded(42), 7, None, None).unwrap(), "018cc251-f400-7811-99f9-d55d18e855a7");
+        let list = default_wordlist();
+        let options = PassphraseOptions::default();
+        assert_eq!(passphrase(&mut Source::seeded(42), &list, &options).unwrap().0, "harbor-boil-clap-flash-reveal-return");
+
+        let all = |seed| {
+            let mut src = Source::seeded(seed);
+            let mut ids = vec![ulid(&mut src), ksuid(&mut src), object_id(&mut src), cuid2(&mut src, 24).unwrap()];
+            ids.push(snowflake(&mut src, 0, 1).unwrap());
+            ids.push(nanoid(&mut src, 21, None).unwrap());
+            ids.push(PasswordPolicy::default().generate(&mut src).unwrap());
+            for v in UUID_VERSIONS {
+                ids.push(uuid(&mut src, v, None, Some("x")).unwrap());
+            }
+            ids
+        };
+        assert_eq!(all(7), all(7));
+        assert_ne!(all(7), all(8));
+        assert_eq!(ulid_inspect(&ulid(&mut Source::seeded(1))).unwrap().timestamp, Some(i128::from(SEEDED_EPOCH_MS) * 1_000_000));
+    }
+
+    #[test]
+    fn test_regex_pattern() {
+        let mut src = Source::system();
+        let key = RegexPattern::new("[A-Z]{4}-[0-9]{4}", false).unwrap();
+        for _ in 0..50 {
+            let value = key.generate(&mut src).unwrap();
+            let (letters, digits) = value.split_once('-').unwrap();
+            assert!(letters.len() == 4 && letters.chars().all(|c| c.is_ascii_uppercase()), "{}", value);
+            assert!(digits.len() == 4 && digits.chars().all(|c| c.is_ascii_digit()), "{}", value);
+        }
+
+        let user = RegexPattern::new(r"^(admin|user)_\w+$", false).unwrap().max_repeat(3);
+        for _ in 0..50 {
+            let value = user.generate(&mut src).unwrap();
+            let rest = value.strip_prefix("admin_").or_else(|| value.strip_prefix("user_")).unwrap();
+            assert!((1..=4).contains(&rest.len()) && rest.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'), "{}", value);
+        }
+        assert!(RegexPattern::new("(?i)ab", false).unwrap().generate(&mut src).unwrap().eq_ignore_ascii_case("ab"));
+        assert!(RegexPattern::new("[^a]", true).unwrap().generate(&mut src).unwrap().chars().all(|c| c != 'a'));
+        assert!(RegexPattern::new("[a-", false).is_err());
+        assert_eq!(RegexPattern::new(r"[^\x00-\x{10FFFF}]", false).unwrap_err(), "Pattern has a class that matches nothing");
+        assert_eq!(RegexPattern::new(r"a\bb", false).unwrap_err(), r"Word boundaries (\b, \B) are not supported");
+        assert_eq!(RegexPattern::new("a^b", false).unwrap_err(), "Anchors are only supported at the start or end of the pattern");
+        assert_eq!(RegexPattern::new("(^a|b$)", false).unwrap().max_repeat(0).generate(&mut src).unwrap().len(), 1);
+
+        let too_long = |pattern: &str, max_repeat: u32| {
+            let err = RegexPattern::new(pattern, false).unwrap().max_repeat(max_repeat).generate(&mut Source::system()).unwrap_err();
+            assert!(err.starts_with("Pattern can generate more than"), "{}: {}", pattern, err);
+        };
+        too_long("a{1000000000}", 8);
+        too_long(".{4294967295}", 8);
+        too_long("((ab){1000}){1000}", 8);
+        too_long("x+", u32::MAX);
+        assert_eq!(RegexPattern::new("a{1000}", false).unwrap().generate(&mut src).unwrap().len(), 1000);
+    }
+
     #[test]
     fn test_random_hex() {
-        let hex = random_hex(16);
+        let mut src = Source::system();
+        let hex = random_hex(&mut src, 16);
         assert_eq!(hex.len(), 32); // 16 bytes = 32 hex chars
     }
 }
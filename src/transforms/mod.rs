@@ -3,3 +3,17 @@
 pub mod encode;
 pub mod hash;
 pub mod generate;
+pub mod strength;
+pub mod otp;
+pub mod number;
+pub mod cipher;
+pub mod detect;
+pub mod compress;
+pub mod jwt;
+pub mod cert;
+pub mod protobuf;
+pub mod serial;
+pub mod structured;
+pub mod query;
+pub mod time;
+pub mod unicode;
@@ -0,0 +1,146 @@
+//! Integer base conversion with arbitrary precision
+
+use num_bigint::BigInt;
+use num_traits::{Num, One, Signed};
+
+/// Integer shown in every supported base
+#[derive(Debug, Clone)]
+pub struct NumberResult {
+    pub decimal: String,
+    pub hex: String,
+    pub octal: String,
+    pub binary: String,
+    pub base36: String,
+    /// Signed interpretation (only when a bit width is given)
+    pub signed: Option<String>,
+    /// Unsigned two's complement interpretation (only when a bit width is given)
+    pub unsigned: Option<String>,
+}
+
+/// Parse an integer in the given base, or detect `0x`/`0o`/`0b` prefixes
+///
+/// A leading `-` and `_` digit separators are accepted.
+pub fn parse_int(input: &str, base: Option<u32>) -> Result<BigInt, String> {
+    let cleaned: String = input.trim().chars().filter(|&c| c != '_').collect();
+    let (negative, body) = match cleaned.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, cleaned.strip_prefix('+').unwrap_or(&cleaned)),
+    };
+
+    let lower = body.to_ascii_lowercase();
+    let (radix, digits) = match base {
+        Some(b) if !(2..=36).contains(&b) => return Err(format!("Unsupported base: {}", b)),
+        Some(16) => (16, lower.strip_prefix("0x").unwrap_or(&lower)),
+        Some(8) => (8, lower.strip_prefix("0o").unwrap_or(&lower)),
+        Some(2) => (2, lower.strip_prefix("0b").unwrap_or(&lower)),
+        Some(b) => (b, lower.as_str()),
+        None => {
+            if let Some(d) = lower.strip_prefix("0x") {
+                (16, d)
+            } else if let Some(d) = lower.strip_prefix("0o") {
+                (8, d)
+            } else if let Some(d) = lower.strip_prefix("0b") {
+                (2, d)
+            } else {
+                (10, lower.as_str())
+            }
+        }
+    };
+
+    if digits.is_empty() {
+        return Err("No digits to parse".to_string());
+    }
+    let value = BigInt::from_str_radix(digits, radix)
+        .map_err(|_| format!("Invalid base-{} number: {}", radix, input.trim()))?;
+    Ok(if negative { -value } else { value })
+}
+
+/// Format an integer in any base from 2 to 36
+pub fn format_int(value: &BigInt, base: u32) -> Result<String, String> {
+    if !(2..=36).contains(&base) {
+        return Err(format!("Unsupported base: {}", base));
+    }
+    Ok(value.to_str_radix(base))
+}
+
+/// Largest bit width [`convert`] accepts
+pub const MAX_WIDTH: u32 = 4096;
+
+/// Convert an integer to every base
+///
+/// With a bit `width`, negative values are shown as their two's complement
+/// bit pattern and values with the top bit set also get a signed reading.
+pub fn convert(value: &BigInt, width: Option<u32>) -> Result<NumberResult, String> {
+    let Some(bits) = width else {
+        let sign = if value.is_negative() { "-" } else { "" };
+        let magnitude = value.abs();
+        return Ok(NumberResult {
+            decimal: value.to_string(),
+            hex: format!("{}0x{}", sign, magnitude.to_str_radix(16)),
+            octal: format!("{}0o{}", sign, magnitude.to_str_radix(8)),
+            binary: format!("{}0b{}", sign, magnitude.to_str_radix(2)),
+            base36: value.to_str_radix(36),
+            signed: None,
+            unsigned: None,
+        });
+    };
+
+    if !(1..=MAX_WIDTH).contains(&bits) {
+        return Err(format!("Bit width must be 1-{}", MAX_WIDTH));
+    }
+    let modulus = BigInt::one() << bits;
+    let half = BigInt::one() << (bits - 1);
+    if *value >= modulus || *value < -&half {
+        return Err(format!("{} does not fit in {} bits", value, bits));
+    }
+
+    let unsigned = if value.is_negative() { value + &modulus } else { value.clone() };
+    let signed = if unsigned >= half { &unsigned - &modulus } else { unsigned.clone() };
+
+    let hex_digits = (bits as usize + 3) / 4;
+    let oct_digits = (bits as usize + 2) / 3;
+    Ok(NumberResult {
+        decimal: value.to_string(),
+        hex: format!("0x{:0>w$}", unsigned.to_str_radix(16), w = hex_digits),
+        octal: format!("0o{:0>w$}", unsigned.to_str_radix(8), w = oct_digits),
+        binary: format!("0b{:0>w$}", unsigned.to_str_radix(2), w = bits as usize),
+        base36: unsigned.to_str_radix(36),
+        signed: Some(signed.to_string()),
+        unsigned: Some(unsigned.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_prefixes() {
+        assert_eq!(parse_int("0xFF", None).unwrap(), BigInt::from(255));
+        assert_eq!(parse_int("0b1010", None).unwrap(), BigInt::from(10));
+        assert_eq!(parse_int("-0o17", None).unwrap(), BigInt::from(-15));
+        assert_eq!(parse_int("zz", Some(36)).unwrap(), BigInt::from(1295));
+        assert!(parse_int("12g", None).is_err());
+    }
+
+    #[test]
+    fn test_twos_complement() {
+        let r = convert(&BigInt::from(-1), Some(8)).unwrap();
+        assert_eq!(r.hex, "0xff");
+        assert_eq!(r.unsigned.as_deref(), Some("255"));
+
+        let r = convert(&BigInt::from(0x80), Some(8)).unwrap();
+        assert_eq!(r.signed.as_deref(), Some("-128"));
+        assert_eq!(r.binary, "0b10000000");
+
+        assert!(convert(&BigInt::from(256), Some(8)).is_err());
+        assert!(convert(&BigInt::from(1), Some(0)).is_err());
+        assert!(convert(&BigInt::from(1), Some(u32::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_arbitrary_precision() {
+        let big = parse_int("0xffffffffffffffffffffffffffffffff", None).unwrap();
+        assert_eq!(convert(&big, None).unwrap().decimal, "340282366920938463463374607431768211455");
+    }
+}
@@ -0,0 +1,324 @@
+//! HOTP/TOTP one-time passwords (RFC 4226, RFC 6238) and otpauth:// URIs
+
+use super::generate::Source;
+use hmac::{Hmac, Mac};
+use rand::Rng;
+
+const BASE32: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// HMAC hash for OTP codes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Algorithm {
+    #[default]
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+pub const ALGORITHMS: [Algorithm; 3] = [Algorithm::Sha1, Algorithm::Sha256, Algorithm::Sha512];
+
+impl Algorithm {
+    /// Name as used in otpauth URIs
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::Sha1 => "SHA1",
+            Algorithm::Sha256 => "SHA256",
+            Algorithm::Sha512 => "SHA512",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_ascii_uppercase().replace('-', "").as_str() {
+            "SHA1" => Ok(Algorithm::Sha1),
+            "SHA256" => Ok(Algorithm::Sha256),
+            "SHA512" => Ok(Algorithm::Sha512),
+            _ => Err(format!("Unknown algorithm '{}'. Use SHA1, SHA256 or SHA512", name)),
+        }
+    }
+
+    fn hmac(&self, key: &[u8], message: &[u8]) -> Vec<u8> {
+        fn mac<M: Mac + hmac::digest::KeyInit>(key: &[u8], message: &[u8]) -> Vec<u8> {
+            let mut mac = <M as Mac>::new_from_slice(key).expect("HMAC accepts any key length");
+            mac.update(message);
+            mac.finalize().into_bytes().to_vec()
+        }
+        match self {
+            Algorithm::Sha1 => mac::<Hmac<sha1::Sha1>>(key, message),
+            Algorithm::Sha256 => mac::<Hmac<sha2::Sha256>>(key, message),
+            Algorithm::Sha512 => mac::<Hmac<sha2::Sha512>>(key, message),
+        }
+    }
+}
+
+// ============================================================================
+// SECRETS
+// ============================================================================
+
+/// Decode an RFC 4648 base32 secret; case, spaces, dashes and padding are ignored
+pub fn base32_decode(text: &str) -> Result<Vec<u8>, String> {
+    let mut out = Vec::new();
+    let (mut buffer, mut bits, mut chars) = (0u64, 0, 0);
+    for c in text.chars().filter(|c| !c.is_whitespace() && *c != '-' && *c != '=') {
+        chars += 1;
+        let value = BASE32
+            .iter()
+            .position(|&b| b as char == c.to_ascii_uppercase())
+            .ok_or_else(|| format!("Invalid base32 character '{}'", c))?;
+        buffer = (buffer << 5) | value as u64;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    match chars {
+        0 => Err("Secret is empty".to_string()),
+        1 => Err("Secret is too short: 1 base32 character is less than a byte".to_string()),
+        _ => Ok(out),
+    }
+}
+
+/// Unpadded RFC 4648 base32
+pub fn base32_encode(data: &[u8]) -> String {
+    let mut out = String::new();
+    let (mut buffer, mut bits) = (0u64, 0);
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u64;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+/// Random base32 secret of `bytes` bytes (20 is the RFC 4226 recommendation)
+pub fn generate_secret(src: &mut Source, bytes: usize) -> String {
+    let mut secret = vec![0u8; bytes];
+    src.fill(&mut secret[..]);
+    base32_encode(&secret)
+}
+
+// ============================================================================
+// CODES
+// ============================================================================
+
+/// HOTP code for `counter` (RFC 4226 dynamic truncation)
+pub fn hotp(secret: &[u8], counter: u64, digits: u32, algorithm: Algorithm) -> Result<String, String> {
+    if !(4..=10).contains(&digits) {
+        return Err("Digits must be 4-10".to_string());
+    }
+    let mac = algorithm.hmac(secret, &counter.to_be_bytes());
+    let offset = (mac[mac.len() - 1] & 0x0f) as usize;
+    let code = u32::from_be_bytes([mac[offset] & 0x7f, mac[offset + 1], mac[offset + 2], mac[offset + 3]]);
+    Ok(format!("{:0width$}", u64::from(code) % 10u64.pow(digits), width = digits as usize))
+}
+
+/// TOTP code at Unix time `now`, and the seconds it stays valid
+pub fn totp(secret: &[u8], now: u64, period: u64, digits: u32, algorithm: Algorithm) -> Result<(String, u64), String> {
+    if period == 0 {
+        return Err("Period must be at least 1 second".to_string());
+    }
+    let code = hotp(secret, now / period, digits, algorithm)?;
+    Ok((code, period - now % period))
+}
+
+pub fn unix_now() -> u64 {
+    std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+// ============================================================================
+// OTPAUTH URIS
+// ============================================================================
+
+/// The contents of an `otpauth://` URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtpAuth {
+    /// Base32 secret
+    pub secret: String,
+    pub issuer: Option<String>,
+    pub account: String,
+    pub algorithm: Algorithm,
+    pub digits: u32,
+    /// TOTP step in seconds
+    pub period: u64,
+    /// HOTP counter; `None` for TOTP
+    pub counter: Option<u64>,
+}
+
+impl Default for OtpAuth {
+    fn default() -> Self {
+        OtpAuth {
+            secret: String::new(),
+            issuer: None,
+            account: String::new(),
+            algorithm: Algorithm::Sha1,
+            digits: 6,
+            period: 30,
+            counter: None,
+        }
+    }
+}
+
+impl OtpAuth {
+    /// `otpauth://totp/Issuer:account?secret=...` (or `hotp` with a counter)
+    pub fn to_uri(&self) -> String {
+        let enc = |s: &str| urlencoding::encode(s).into_owned();
+        let label = match &self.issuer {
+            Some(issuer) => format!("{}:{}", enc(issuer), enc(&self.account)),
+            None => enc(&self.account),
+        };
+        let mut uri = format!(
+            "otpauth://{}/{}?secret={}",
+            if self.counter.is_some() { "hotp" } else { "totp" },
+            label,
+            self.secret.replace(['=', ' '], "").to_ascii_uppercase()
+        );
+        if let Some(issuer) = &self.issuer {
+            uri.push_str(&format!("&issuer={}", enc(issuer)));
+        }
+        uri.push_str(&format!("&algorithm={}&digits={}", self.algorithm.name(), self.digits));
+        match self.counter {
+            Some(counter) => uri.push_str(&format!("&counter={}", counter)),
+            None => uri.push_str(&format!("&period={}", self.period)),
+        }
+        uri
+    }
+
+    /// Current code and, for TOTP, seconds remaining
+    pub fn code(&self, now: u64) -> Result<(String, Option<u64>), String> {
+        let secret = base32_decode(&self.secret)?;
+        match self.counter {
+            Some(counter) => hotp(&secret, counter, self.digits, self.algorithm).map(|code| (code, None)),
+            None => totp(&secret, now, self.period, self.digits, self.algorithm).map(|(code, left)| (code, Some(left))),
+        }
+    }
+
+    /// Aligned `Label: value` lines including the current code
+    pub fn summary(&self, now: u64) -> String {
+        let mut fields = vec![
+            ("Type", if self.counter.is_some() { "HOTP" } else { "TOTP" }.to_string()),
+            ("Issuer", self.issuer.clone().unwrap_or_else(|| "(none)".to_string())),
+            ("Account", self.account.clone()),
+            ("Secret", self.secret.clone()),
+            ("Algorithm", self.algorithm.name().to_string()),
+            ("Digits", self.digits.to_string()),
+        ];
+        match self.counter {
+            Some(counter) => fields.push(("Counter", counter.to_string())),
+            None => fields.push(("Period", format!("{}s", self.period))),
+        }
+        match self.code(now) {
+            Ok((code, Some(left))) => fields.push(("Code", format!("{} ({}s left)", code, left))),
+            Ok((code, None)) => fields.push(("Code", code)),
+            Err(e) => fields.push(("Code", format!("error: {}", e))),
+        }
+        fields.iter().map(|(label, value)| format!("{:<10} {}", format!("{}:", label), value)).collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// Parse an `otpauth://totp/...` or `otpauth://hotp/...` URI
+pub fn parse_uri(uri: &str) -> Result<OtpAuth, String> {
+    let rest = uri.trim().strip_prefix("otpauth://").ok_or("URI must start with otpauth://")?;
+    let (kind, rest) = rest.split_once('/').ok_or("Missing otpauth type")?;
+    let (label, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let dec = |s: &str| urlencoding::decode(&s.replace('+', " ")).map(|s| s.into_owned()).map_err(|e| format!("Bad URI encoding: {}", e));
+    let label = dec(label)?;
+    let (label_issuer, account) = match label.split_once(':') {
+        Some((issuer, account)) => (Some(issuer.trim().to_string()), account.trim().to_string()),
+        None => (None, label.trim().to_string()),
+    };
+    let mut auth = OtpAuth { issuer: label_issuer, account, ..Default::default() };
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let value = dec(value)?;
+        let number = |v: &str| v.parse::<u64>().map_err(|_| format!("Invalid {}: {}", key, v));
+        match key.to_ascii_lowercase().as_str() {
+            "secret" => auth.secret = value,
+            "issuer" => auth.issuer = Some(value),
+            "algorithm" => auth.algorithm = Algorithm::from_name(&value)?,
+            "digits" => auth.digits = u32::try_from(number(&value)?).map_err(|_| format!("Invalid digits: {}", value))?,
+            "period" => auth.period = number(&value)?,
+            "counter" => auth.counter = Some(number(&value)?),
+            _ => {}
+        }
+    }
+    match kind.to_ascii_lowercase().as_str() {
+        "totp" => auth.counter = None,
+        "hotp" => auth.counter = Some(auth.counter.unwrap_or(0)),
+        other => return Err(format!("Unknown otpauth type '{}'", other)),
+    }
+    if auth.secret.is_empty() {
+        return Err("URI has no secret".to_string());
+    }
+    if !(4..=10).contains(&auth.digits) {
+        return Err(format!("Invalid digits: {} (must be 4-10)", auth.digits));
+    }
+    if auth.period == 0 {
+        return Err("Invalid period: must be at least 1 second".to_string());
+    }
+    base32_decode(&auth.secret)?;
+    Ok(auth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc_vectors() {
+        // RFC 4226 Appendix D
+        let secret = b"12345678901234567890";
+        let codes: Vec<String> = (0..4).map(|c| hotp(secret, c, 6, Algorithm::Sha1).unwrap()).collect();
+        assert_eq!(codes, ["755224", "287082", "359152", "969429"]);
+
+        // RFC 6238 Appendix B
+        assert_eq!(totp(secret, 59, 30, 8, Algorithm::Sha1).unwrap(), ("94287082".to_string(), 1));
+        assert_eq!(totp(secret, 1111111109, 30, 8, Algorithm::Sha1).unwrap().0, "07081804");
+        assert_eq!(totp(b"12345678901234567890123456789012", 59, 30, 8, Algorithm::Sha256).unwrap().0, "46119246");
+        let key512 = b"1234567890123456789012345678901234567890123456789012345678901234";
+        assert_eq!(totp(key512, 20000000000, 30, 8, Algorithm::Sha512).unwrap().0, "47863826");
+    }
+
+    #[test]
+    fn test_base32_and_uri() {
+        assert_eq!(base32_encode(b"12345678901234567890"), "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+        assert_eq!(base32_decode("gezd gnbv gy3t qojq gezd gnbv gy3t qojq").unwrap(), b"12345678901234567890");
+        assert_eq!(base32_decode(&generate_secret(&mut Source::system(), 20)).unwrap().len(), 20);
+
+        let auth = parse_uri("otpauth://totp/ACME%20Co:john@example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=ACME%20Co&digits=8").unwrap();
+        assert_eq!(auth.issuer.as_deref(), Some("ACME Co"));
+        assert_eq!((auth.account.as_str(), auth.digits, auth.period), ("john@example.com", 8, 30));
+        assert_eq!(auth.code(59).unwrap(), ("94287082".to_string(), Some(1)));
+        assert_eq!(parse_uri(&auth.to_uri()).unwrap(), auth);
+
+        let hotp = OtpAuth { counter: Some(1), ..auth };
+        assert!(hotp.to_uri().starts_with("otpauth://hotp/"));
+        assert_eq!(parse_uri(&hotp.to_uri()).unwrap().code(0).unwrap().0, "94287082");
+        assert!(parse_uri("otpauth://totp/x").is_err());
+    }
+
+    #[test]
+    fn test_uri_fields() {
+        let hotp = parse_uri("otpauth://hotp/alice?secret=GEZDGNBV").unwrap();
+        assert_eq!((hotp.counter, hotp.issuer), (Some(0), None));
+        assert_eq!(hotp.account, "alice");
+
+        let label_only = parse_uri("otpauth://totp/Label%20Co:bob?secret=GEZDGNBV").unwrap();
+        assert_eq!(label_only.issuer.as_deref(), Some("Label Co"));
+        let both = parse_uri("otpauth://totp/Label:bob?secret=GEZDGNBV&issuer=Query").unwrap();
+        assert_eq!((both.issuer.as_deref(), both.account.as_str()), (Some("Query"), "bob"));
+
+        for bad in ["digits=4294967302", "digits=3", "digits=11", "digits=six", "period=0", "period=-30"] {
+            let uri = format!("otpauth://totp/x?secret=GEZDGNBV&{}", bad);
+            assert!(parse_uri(&uri).unwrap_err().starts_with("Invalid"), "{}", bad);
+        }
+        assert_eq!(base32_decode("").unwrap_err(), "Secret is empty");
+        assert!(base32_decode("G").unwrap_err().contains("too short"));
+        assert!(base32_decode("G1").unwrap_err().contains("'1'"));
+    }
+}
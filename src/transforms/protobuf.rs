@@ -0,0 +1,519 @@
+//! Schema-less protobuf wire-format decoding, with optional `.proto` names
+
+use std::collections::{HashMap, HashSet};
+
+/// Nesting limit for messages and groups
+const MAX_DEPTH: usize = 64;
+
+/// Decoded value of one field
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Wire type 0
+    Varint(u64),
+    /// Wire type 1
+    Fixed64(u64),
+    /// Wire type 5
+    Fixed32(u32),
+    /// Length-delimited field that looks like UTF-8 text
+    Text(String),
+    /// Length-delimited field that parses as a nested message
+    Message(Vec<Field>),
+    /// Length-delimited field that is neither
+    Bytes(Vec<u8>),
+    /// Packed repeated scalars (only with a schema)
+    Packed(Vec<u64>),
+    /// Wire types 3/4 (deprecated groups)
+    Group(Vec<Field>),
+}
+
+/// One decoded field
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub number: u64,
+    /// Field name from the `.proto` schema, if one was given
+    pub name: Option<String>,
+    /// Declared type from the schema (`int32`, `string`, `Person`, ...)
+    pub kind: Option<String>,
+    pub value: Value,
+}
+
+/// Field declaration from a `.proto` file
+#[derive(Debug, Clone)]
+pub struct FieldDef {
+    pub name: String,
+    pub kind: String,
+}
+
+/// Messages declared in a `.proto` file, by simple name
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    pub messages: HashMap<String, HashMap<u64, FieldDef>>,
+    /// Message names in declaration order (the first is the default root)
+    pub order: Vec<String>,
+    /// Enum names, which travel as varints
+    pub enums: HashSet<String>,
+}
+
+impl Schema {
+    fn message(&self, name: &str) -> Option<&HashMap<u64, FieldDef>> {
+        let simple = name.rsplit('.').next().unwrap_or(name);
+        self.messages.get(simple)
+    }
+
+    fn is_enum(&self, name: &str) -> bool {
+        self.enums.contains(name.rsplit('.').next().unwrap_or(name))
+    }
+}
+
+// ============================================================================
+// WIRE FORMAT
+// ============================================================================
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Result<u64, String> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let b = *data.get(*pos).ok_or_else(|| format!("Truncated varint at offset {}", pos))?;
+        *pos += 1;
+        value |= u64::from(b & 0x7f) << shift;
+        if b & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(format!("Varint too long at offset {}", pos))
+}
+
+fn read_fixed<const N: usize>(data: &[u8], pos: &mut usize) -> Result<[u8; N], String> {
+    let bytes = data
+        .get(*pos..*pos + N)
+        .ok_or_else(|| format!("Truncated fixed{} at offset {}", N * 8, pos))?;
+    *pos += N;
+    let mut out = [0u8; N];
+    out.copy_from_slice(bytes);
+    Ok(out)
+}
+
+/// Zigzag decoding used by `sint32` / `sint64`
+pub fn zigzag(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+fn is_text(data: &[u8]) -> bool {
+    std::str::from_utf8(data)
+        .map(|s| s.chars().all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t')))
+        .unwrap_or(false)
+}
+
+/// Guess what a length-delimited payload is when no schema says otherwise
+///
+/// Readable text that does not start with a control byte wins, since
+/// messages with low field numbers always do; then nested messages.
+fn guess_len(data: &[u8], depth: usize) -> Value {
+    let starts_readable = data.first().map_or(true, |&b| b >= 0x20);
+    if is_text(data) && starts_readable {
+        return Value::Text(String::from_utf8_lossy(data).into_owned());
+    }
+    match parse_fields(data, &mut 0, None, None, depth + 1, None) {
+        Ok(fields) if !fields.is_empty() => Value::Message(fields),
+        _ if is_text(data) => Value::Text(String::from_utf8_lossy(data).into_owned()),
+        _ => Value::Bytes(data.to_vec()),
+    }
+}
+
+fn schema_len(data: &[u8], kind: &str, schema: &Schema, depth: usize) -> Result<Value, String> {
+    Ok(match kind {
+        "string" => Value::Text(String::from_utf8_lossy(data).into_owned()),
+        "bytes" => Value::Bytes(data.to_vec()),
+        "double" | "fixed64" | "sfixed64" => Value::Packed(
+            data.chunks(8).map(|c| u64::from_le_bytes(c.try_into().unwrap_or([0; 8]))).collect(),
+        ),
+        "float" | "fixed32" | "sfixed32" => Value::Packed(
+            data.chunks(4).map(|c| u64::from(u32::from_le_bytes(c.try_into().unwrap_or([0; 4])))).collect(),
+        ),
+        "map" => guess_len(data, depth),
+        k if schema.message(k).is_some() => {
+            Value::Message(parse_fields(data, &mut 0, Some(schema), Some(k), depth + 1, None)?)
+        }
+        "int32" | "int64" | "uint32" | "uint64" | "sint32" | "sint64" | "bool" => packed_varints(data)?,
+        k if schema.is_enum(k) => packed_varints(data)?,
+        // Types the schema does not declare (imports, typos)
+        _ => guess_len(data, depth),
+    })
+}
+
+fn packed_varints(data: &[u8]) -> Result<Value, String> {
+    let mut pos = 0;
+    let mut values = Vec::new();
+    while pos < data.len() {
+        values.push(read_varint(data, &mut pos)?);
+    }
+    Ok(Value::Packed(values))
+}
+
+fn parse_fields(
+    data: &[u8],
+    pos: &mut usize,
+    schema: Option<&Schema>,
+    message: Option<&str>,
+    depth: usize,
+    group: Option<u64>,
+) -> Result<Vec<Field>, String> {
+    if depth > MAX_DEPTH {
+        return Err("Nesting too deep".to_string());
+    }
+    let defs = schema.zip(message).and_then(|(s, m)| s.message(m));
+    let mut fields = Vec::new();
+
+    while *pos < data.len() {
+        let start = *pos;
+        let key = read_varint(data, pos)?;
+        let number = key >> 3;
+        if number == 0 || number > 0x1fff_ffff {
+            return Err(format!("Invalid field number {} at offset {}", number, start));
+        }
+        let def = defs.and_then(|d| d.get(&number));
+        let kind = def.map(|d| d.kind.as_str());
+
+        let value = match key & 7 {
+            0 => Value::Varint(read_varint(data, pos)?),
+            1 => Value::Fixed64(u64::from_le_bytes(read_fixed::<8>(data, pos)?)),
+            5 => Value::Fixed32(u32::from_le_bytes(read_fixed::<4>(data, pos)?)),
+            2 => {
+                let len = read_varint(data, pos)?;
+                let end = usize::try_from(len)
+                    .ok()
+                    .and_then(|l| pos.checked_add(l))
+                    .filter(|&e| e <= data.len())
+                    .ok_or_else(|| format!("Length {} exceeds data at offset {}", len, start))?;
+                let payload = &data[*pos..end];
+                *pos = end;
+                match (schema, kind) {
+                    (Some(s), Some(k)) => schema_len(payload, k, s, depth)?,
+                    _ => guess_len(payload, depth),
+                }
+            }
+            3 => Value::Group(parse_fields(data, pos, schema, kind, depth + 1, Some(number))?),
+            4 if group == Some(number) => return Ok(fields),
+            wire => return Err(format!("Invalid wire type {} at offset {}", wire, start)),
+        };
+        fields.push(Field {
+            number,
+            name: def.map(|d| d.name.clone()),
+            kind: kind.map(str::to_string),
+            value,
+        });
+    }
+
+    match group {
+        Some(n) => Err(format!("Group {} is missing its end tag", n)),
+        None => Ok(fields),
+    }
+}
+
+/// Decode a message without a schema
+pub fn decode(data: &[u8]) -> Result<Vec<Field>, String> {
+    parse_fields(data, &mut 0, None, None, 0, None)
+}
+
+/// Decode a message using field names and types from a schema
+///
+/// `message` picks the root type; the first declared message is used when
+/// it is `None`.
+pub fn decode_with(data: &[u8], schema: &Schema, message: Option<&str>) -> Result<Vec<Field>, String> {
+    let root = match message {
+        Some(m) => m,
+        None => schema.order.first().ok_or("Schema declares no messages")?,
+    };
+    if schema.message(root).is_none() {
+        return Err(format!("Message {} not found in schema", root));
+    }
+    parse_fields(data, &mut 0, Some(schema), Some(root), 0, None)
+}
+
+// ============================================================================
+// RENDERING
+// ============================================================================
+
+fn varint_text(v: u64, kind: Option<&str>) -> String {
+    match kind {
+        Some("sint32" | "sint64") => zigzag(v).to_string(),
+        Some("int32" | "int64") => (v as i64).to_string(),
+        Some("bool") => (v != 0).to_string(),
+        Some(_) => v.to_string(),
+        None => {
+            let signed = v as i64;
+            if signed < 0 {
+                format!("{}  (signed {}, zigzag {})", v, signed, zigzag(v))
+            } else {
+                format!("{}  (zigzag {})", v, zigzag(v))
+            }
+        }
+    }
+}
+
+fn fixed64_text(v: u64, kind: Option<&str>) -> String {
+    match kind {
+        Some("double") => f64::from_bits(v).to_string(),
+        Some("sfixed64") => (v as i64).to_string(),
+        Some(_) => v.to_string(),
+        None => format!("0x{:016x}  (u64 {}, i64 {}, double {})", v, v, v as i64, f64::from_bits(v)),
+    }
+}
+
+fn fixed32_text(v: u32, kind: Option<&str>) -> String {
+    match kind {
+        Some("float") => f32::from_bits(v).to_string(),
+        Some("sfixed32") => (v as i32).to_string(),
+        Some(_) => v.to_string(),
+        None => format!("0x{:08x}  (u32 {}, i32 {}, float {})", v, v, v as i32, f32::from_bits(v)),
+    }
+}
+
+/// Render fields as an indented tree in the style of `protoc --decode_raw`
+pub fn render(fields: &[Field]) -> String {
+    fn walk(fields: &[Field], depth: usize, out: &mut Vec<String>) {
+        let pad = "  ".repeat(depth);
+        for f in fields {
+            let label = match &f.name {
+                Some(name) => format!("{} ({})", name, f.number),
+                None => f.number.to_string(),
+            };
+            let kind = f.kind.as_deref();
+            match &f.value {
+                Value::Message(children) | Value::Group(children) => {
+                    out.push(format!("{}{} {{", pad, label));
+                    walk(children, depth + 1, out);
+                    out.push(format!("{}}}", pad));
+                }
+                Value::Varint(v) => out.push(format!("{}{}: {}", pad, label, varint_text(*v, kind))),
+                Value::Fixed64(v) => out.push(format!("{}{}: {}", pad, label, fixed64_text(*v, kind))),
+                Value::Fixed32(v) => out.push(format!("{}{}: {}", pad, label, fixed32_text(*v, kind))),
+                Value::Text(s) => out.push(format!("{}{}: {:?}", pad, label, s)),
+                Value::Bytes(b) => out.push(format!("{}{}: bytes[{}] {}", pad, label, b.len(), hex::encode(b))),
+                Value::Packed(values) => {
+                    let items: Vec<String> = values
+                        .iter()
+                        .map(|&v| match kind {
+                            Some("double" | "fixed64" | "sfixed64") => fixed64_text(v, kind),
+                            Some("float" | "fixed32" | "sfixed32") => fixed32_text(v as u32, kind),
+                            _ => varint_text(v, kind),
+                        })
+                        .collect();
+                    out.push(format!("{}{}: [{}]", pad, label, items.join(", ")));
+                }
+            }
+        }
+    }
+    let mut out = Vec::new();
+    walk(fields, 0, &mut out);
+    out.join("\n")
+}
+
+// ============================================================================
+// .PROTO PARSING
+// ============================================================================
+
+fn tokenize(source: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '"' | '\'' => {
+                let mut s = String::from(c);
+                for q in chars.by_ref() {
+                    s.push(q);
+                    if q == c {
+                        break;
+                    }
+                }
+                tokens.push(s);
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' => {
+                let mut word = String::from(c);
+                while let Some(&n) = chars.peek() {
+                    if n.is_alphanumeric() || n == '_' || n == '.' {
+                        word.push(n);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(word);
+            }
+            c if c.is_whitespace() => {}
+            c => tokens.push(c.to_string()),
+        }
+    }
+    tokens
+}
+
+/// Parse message and field declarations from `.proto` source
+///
+/// Enums, services, options and imports are skipped; `oneof` members
+/// belong to the enclosing message.
+pub fn parse_proto(source: &str) -> Result<Schema, String> {
+    let tokens = tokenize(source);
+    let mut schema = Schema::default();
+    // Enclosing blocks: Some(message name) or None for oneof/other blocks
+    let mut stack: Vec<Option<String>> = Vec::new();
+    let mut i = 0;
+
+    let skip_block = |i: &mut usize| {
+        let mut depth = 0;
+        while *i < tokens.len() {
+            match tokens[*i].as_str() {
+                "{" => depth += 1,
+                "}" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        *i += 1;
+                        return;
+                    }
+                }
+                _ => {}
+            }
+            *i += 1;
+        }
+    };
+    let skip_statement = |i: &mut usize| {
+        while *i < tokens.len() && tokens[*i] != ";" {
+            *i += 1;
+        }
+        *i += 1;
+    };
+
+    while i < tokens.len() {
+        let current = stack.iter().rev().find_map(|m| m.clone());
+        match tokens[i].as_str() {
+            "message" => {
+                let name = tokens.get(i + 1).ok_or("Message name missing")?.clone();
+                schema.messages.entry(name.clone()).or_default();
+                schema.order.push(name.clone());
+                stack.push(Some(name));
+                i += 3;
+            }
+            "oneof" => {
+                stack.push(None);
+                i += 3;
+            }
+            "enum" => {
+                if let Some(name) = tokens.get(i + 1) {
+                    schema.enums.insert(name.clone());
+                }
+                skip_block(&mut i)
+            }
+            "service" | "extend" => skip_block(&mut i),
+            "}" => {
+                stack.pop();
+                i += 1;
+            }
+            "syntax" | "package" | "import" | "option" | "reserved" | "extensions" | ";" => {
+                skip_statement(&mut i)
+            }
+            _ => {
+                let Some(message) = current else {
+                    skip_statement(&mut i);
+                    continue;
+                };
+                if matches!(tokens[i].as_str(), "repeated" | "optional" | "required") {
+                    i += 1;
+                }
+                let kind = if tokens.get(i).map(String::as_str) == Some("map") {
+                    // map<K, V> travels as repeated entry messages
+                    while i < tokens.len() && tokens[i] != ">" {
+                        i += 1;
+                    }
+                    "map".to_string()
+                } else {
+                    tokens.get(i).cloned().unwrap_or_default()
+                };
+                let name = tokens.get(i + 1).cloned().unwrap_or_default();
+                let number = tokens
+                    .get(i + 3)
+                    .filter(|_| tokens.get(i + 2).map(String::as_str) == Some("="))
+                    .and_then(|n| n.parse::<u64>().ok())
+                    .ok_or_else(|| format!("Cannot parse field '{}' in message {}", name, message))?;
+                if let Some(fields) = schema.messages.get_mut(&message) {
+                    fields.insert(number, FieldDef { name, kind });
+                }
+                skip_statement(&mut i);
+            }
+        }
+    }
+    Ok(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_raw() {
+        // 1: 150, 2: "testing", 3 { 1: -1 as int64 }, 4: 0x0102 bytes
+        let mut data = hex::decode("089601120774657374696e67").unwrap();
+        data.extend(hex::decode("1a0b08ffffffffffffffffff01").unwrap());
+        data.extend(hex::decode("22020102").unwrap());
+        let fields = decode(&data).unwrap();
+        assert_eq!(fields[0].value, Value::Varint(150));
+        assert_eq!(fields[1].value, Value::Text("testing".into()));
+        assert!(matches!(&fields[2].value, Value::Message(inner) if inner[0].value == Value::Varint(u64::MAX)));
+        assert_eq!(fields[3].value, Value::Bytes(vec![1, 2]));
+
+        let text = render(&fields);
+        assert!(text.contains("1: 150  (zigzag 75)"));
+        assert!(text.contains("  1: 18446744073709551615  (signed -1, zigzag -9223372036854775808)"));
+        assert!(decode(&[0x0a, 0x05, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_decode_with_schema() {
+        let proto = r#"
+            syntax = "proto3";
+            // A person
+            message Person {
+                string name = 1;
+                sint32 delta = 2;
+                repeated int32 scores = 3 [packed = true];
+                Address home = 4;
+                enum Kind { A = 0; B = 1; }
+                oneof contact { string email = 5; }
+            }
+            message Address { string city = 1; }
+        "#;
+        let schema = parse_proto(proto).unwrap();
+        // name "frog", delta -2 (zigzag 3), scores [1, 2], home { city "x" }, email "e"
+        let data = hex::decode("0a0466726f6710031a02010222030a01782a0165").unwrap();
+        let text = render(&decode_with(&data, &schema, None).unwrap());
+        assert!(text.contains("name (1): \"frog\""));
+        assert!(text.contains("delta (2): -2"));
+        assert!(text.contains("scores (3): [1, 2]"));
+        assert!(text.contains("home (4) {\n  city (1): \"x\"\n}"));
+        assert!(text.contains("email (5): \"e\""));
+    }
+
+    #[test]
+    fn test_undeclared_type_is_guessed() {
+        let schema = parse_proto("message M { other.Thing thing = 1; Missing note = 2; }").unwrap();
+        // thing { 1: "x" }, note "hello"
+        let data = hex::decode("0a030a0178120568656c6c6f").unwrap();
+        let text = render(&decode_with(&data, &schema, None).unwrap());
+        assert!(text.contains("thing (1) {\n  1: \"x\"\n}"), "{}", text);
+        assert!(text.contains("note (2): \"hello\""), "{}", text);
+    }
+}
@@ -0,0 +1,1140 @@
+//! JSONPath and jq-style queries over JSON values
+//!
+//! Expressions starting with `$` are JSONPath (`$.store.book[?(@.price < 10)].title`);
+//! anything else is a jq filter (`.items[] | select(.ok) | .name`).
+
+use std::cmp::Ordering;
+
+use serde_json::{Map, Value};
+
+/// Run a JSONPath or jq expression, returning every result
+pub fn query(input: &Value, expr: &str) -> Result<Vec<Value>, String> {
+    let expr = expr.trim();
+    if expr.starts_with('$') {
+        let segments = parse_path(expr)?;
+        Ok(eval_path(input, &segments).into_iter().cloned().collect())
+    } else {
+        let ast = parse_jq(expr)?;
+        eval(&ast, input)
+    }
+}
+
+// ============================================================================
+// TOKENS
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Dot,
+    DotDot,
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Op(&'static str),
+    Punct(char),
+}
+
+fn tokenize(src: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        match c {
+            c if c.is_whitespace() => i += 1,
+            '.' if next == Some('.') => {
+                tokens.push(Token::DotDot);
+                i += 2;
+            }
+            // `@` is the current node in JSONPath filters
+            '.' | '@' => {
+                tokens.push(Token::Dot);
+                i += 1;
+            }
+            '"' | '\'' => {
+                let mut s = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        None => return Err("Unterminated string in query".to_string()),
+                        Some(&q) if q == c => break,
+                        Some('\\') => {
+                            let escaped = *chars.get(i + 1).ok_or("Unterminated string in query")?;
+                            s.push(match escaped {
+                                'n' => '\n',
+                                't' => '\t',
+                                other => other,
+                            });
+                            i += 2;
+                            continue;
+                        }
+                        Some(&ch) => s.push(ch),
+                    }
+                    i += 1;
+                }
+                tokens.push(Token::Str(s));
+                i += 1;
+            }
+            c if c.is_ascii_digit() => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.' && tokens_allow_fraction(&chars, i)) {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push(Token::Num(text.parse().map_err(|_| format!("Bad number: {}", text))?));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                tokens.push(Token::Ident(chars[start..i].iter().collect()));
+            }
+            _ => {
+                let two: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+                let op = ["==", "!=", "<=", ">=", "&&", "||", "//"].into_iter().find(|op| *op == two);
+                if let Some(op) = op {
+                    tokens.push(Token::Op(op));
+                    i += 2;
+                } else if let Some(op) = ["<", ">", "+", "-", "*", "/", "%", "!"].into_iter().find(|op| op.starts_with(c)) {
+                    tokens.push(Token::Op(op));
+                    i += 1;
+                } else if "[](){}|,:;?$".contains(c) {
+                    tokens.push(Token::Punct(c));
+                    i += 1;
+                } else {
+                    return Err(format!("Unexpected '{}' in query", c));
+                }
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// A `.` inside a number only when a digit follows (`1.5`, not `.[1].a`)
+fn tokens_allow_fraction(chars: &[char], i: usize) -> bool {
+    chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+}
+
+// ============================================================================
+// JQ PARSER
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Identity,
+    Recurse,
+    Literal(Value),
+    Field(Box<Expr>, String),
+    Index(Box<Expr>, Box<Expr>),
+    Slice(Box<Expr>, Option<Box<Expr>>, Option<Box<Expr>>),
+    Iterate(Box<Expr>),
+    /// `expr?`: drop errors
+    Try(Box<Expr>),
+    Array(Option<Box<Expr>>),
+    Object(Vec<(String, Expr)>),
+    Pipe(Box<Expr>, Box<Expr>),
+    Comma(Box<Expr>, Box<Expr>),
+    Binary(&'static str, Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+/// Deepest nesting a jq filter may have; parsing and evaluation both recurse per level
+const MAX_DEPTH: usize = 100;
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        t
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Count one more level of nesting (a group or a link in an operator chain)
+    fn enter(&mut self) -> Result<(), String> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return Err(format!("Query nests too deeply (limit {})", MAX_DEPTH));
+        }
+        Ok(())
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.eat(&Token::Punct(c)) {
+            Ok(())
+        } else {
+            Err(format!("Expected '{}' in query", c))
+        }
+    }
+
+    fn pipe(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut left = self.comma()?;
+        while self.eat(&Token::Punct('|')) {
+            self.enter()?;
+            left = Expr::Pipe(Box::new(left), Box::new(self.comma()?));
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    fn comma(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut left = self.alternative()?;
+        while self.eat(&Token::Punct(',')) {
+            self.enter()?;
+            left = Expr::Comma(Box::new(left), Box::new(self.alternative()?));
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    fn alternative(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut left = self.or()?;
+        while self.eat(&Token::Op("//")) {
+            self.enter()?;
+            left = Expr::Binary("//", Box::new(left), Box::new(self.or()?));
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    fn or(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut left = self.and()?;
+        while self.eat(&Token::Ident("or".into())) || self.eat(&Token::Op("||")) {
+            self.enter()?;
+            left = Expr::Binary("or", Box::new(left), Box::new(self.and()?));
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    fn and(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut left = self.comparison()?;
+        while self.eat(&Token::Ident("and".into())) || self.eat(&Token::Op("&&")) {
+            self.enter()?;
+            left = Expr::Binary("and", Box::new(left), Box::new(self.comparison()?));
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    fn comparison(&mut self) -> Result<Expr, String> {
+        let left = self.additive()?;
+        for op in ["==", "!=", "<=", ">=", "<", ">"] {
+            if self.eat(&Token::Op(op)) {
+                return Ok(Expr::Binary(op, Box::new(left), Box::new(self.additive()?)));
+            }
+        }
+        Ok(left)
+    }
+
+    fn additive(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut left = self.multiplicative()?;
+        loop {
+            let op = if self.eat(&Token::Op("+")) {
+                "+"
+            } else if self.eat(&Token::Op("-")) {
+                "-"
+            } else {
+                self.depth = depth;
+                return Ok(left);
+            };
+            self.enter()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(self.multiplicative()?));
+        }
+    }
+
+    fn multiplicative(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut left = self.unary()?;
+        loop {
+            let op = ["*", "/", "%"].into_iter().find(|op| self.eat(&Token::Op(op)));
+            let Some(op) = op else {
+                self.depth = depth;
+                return Ok(left);
+            };
+            self.enter()?;
+            left = Expr::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        self.enter()?;
+        let expr = if self.eat(&Token::Op("!")) {
+            Expr::Not(Box::new(self.unary()?))
+        } else if self.eat(&Token::Op("-")) {
+            let operand = self.unary()?;
+            Expr::Binary("-", Box::new(Expr::Literal(Value::from(0))), Box::new(operand))
+        } else {
+            self.postfix()?
+        };
+        self.depth = depth;
+        Ok(expr)
+    }
+
+    /// `[expr]`, `[]`, `[a:b]` after a term
+    fn bracket(&mut self, target: Expr) -> Result<Expr, String> {
+        if self.eat(&Token::Punct(']')) {
+            return Ok(Expr::Iterate(Box::new(target)));
+        }
+        let start = if self.peek() == Some(&Token::Punct(':')) { None } else { Some(Box::new(self.pipe()?)) };
+        if self.eat(&Token::Punct(':')) {
+            let end = if self.peek() == Some(&Token::Punct(']')) { None } else { Some(Box::new(self.pipe()?)) };
+            self.expect(']')?;
+            return Ok(Expr::Slice(Box::new(target), start, end));
+        }
+        self.expect(']')?;
+        Ok(Expr::Index(Box::new(target), start.ok_or("Empty index")?))
+    }
+
+    fn postfix(&mut self) -> Result<Expr, String> {
+        let depth = self.depth;
+        let mut expr = self.primary()?;
+        loop {
+            let dotted = self.peek() == Some(&Token::Dot);
+            let next = if dotted { self.tokens.get(self.pos + 1) } else { self.peek() };
+            let wraps = match next {
+                Some(Token::Ident(_) | Token::Str(_) | Token::Punct('[')) if dotted => true,
+                Some(Token::Punct('[' | '?')) if !dotted => true,
+                _ => false,
+            };
+            if !wraps {
+                self.depth = depth;
+                return Ok(expr);
+            }
+            self.enter()?;
+            if dotted {
+                self.pos += 1;
+            }
+            expr = match self.next() {
+                Some(Token::Ident(name) | Token::Str(name)) => Expr::Field(Box::new(expr), name),
+                Some(Token::Punct('?')) => Expr::Try(Box::new(expr)),
+                _ => self.bracket(expr)?,
+            };
+        }
+    }
+
+    fn primary(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Dot) => match self.peek() {
+                Some(Token::Ident(name)) | Some(Token::Str(name)) => {
+                    let name = name.clone();
+                    self.pos += 1;
+                    Ok(Expr::Field(Box::new(Expr::Identity), name))
+                }
+                Some(Token::Punct('[')) => {
+                    self.pos += 1;
+                    self.bracket(Expr::Identity)
+                }
+                _ => Ok(Expr::Identity),
+            },
+            Some(Token::DotDot) => Ok(Expr::Recurse),
+            Some(Token::Num(n)) => Ok(Expr::Literal(number(n))),
+            Some(Token::Str(s)) => Ok(Expr::Literal(Value::String(s))),
+            Some(Token::Punct('(')) => {
+                let inner = self.pipe()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Some(Token::Punct('[')) => {
+                if self.eat(&Token::Punct(']')) {
+                    return Ok(Expr::Array(None));
+                }
+                let inner = self.pipe()?;
+                self.expect(']')?;
+                Ok(Expr::Array(Some(Box::new(inner))))
+            }
+            Some(Token::Punct('{')) => self.object(),
+            Some(Token::Ident(name)) => match name.as_str() {
+                "true" => Ok(Expr::Literal(Value::Bool(true))),
+                "false" => Ok(Expr::Literal(Value::Bool(false))),
+                "null" => Ok(Expr::Literal(Value::Null)),
+                _ => {
+                    let mut args = Vec::new();
+                    if self.eat(&Token::Punct('(')) {
+                        loop {
+                            args.push(self.pipe()?);
+                            if !self.eat(&Token::Punct(';')) {
+                                break;
+                            }
+                        }
+                        self.expect(')')?;
+                    }
+                    Ok(Expr::Call(name, args))
+                }
+            },
+            Some(t) => Err(format!("Unexpected {:?} in query", t)),
+            None => Err("Unexpected end of query".to_string()),
+        }
+    }
+
+    /// `{a: expr, "b": expr, c}` (`c` is short for `c: .c`)
+    fn object(&mut self) -> Result<Expr, String> {
+        let mut entries = Vec::new();
+        if self.eat(&Token::Punct('}')) {
+            return Ok(Expr::Object(entries));
+        }
+        loop {
+            let key = match self.next() {
+                Some(Token::Ident(k)) | Some(Token::Str(k)) => k,
+                _ => return Err("Expected object key in query".to_string()),
+            };
+            let value = if self.eat(&Token::Punct(':')) {
+                self.alternative()?
+            } else {
+                Expr::Field(Box::new(Expr::Identity), key.clone())
+            };
+            entries.push((key, value));
+            if self.eat(&Token::Punct('}')) {
+                return Ok(Expr::Object(entries));
+            }
+            self.expect(',')?;
+        }
+    }
+}
+
+fn parse_jq(src: &str) -> Result<Expr, String> {
+    let mut parser = Parser { tokens: tokenize(src)?, pos: 0, depth: 0 };
+    if parser.tokens.is_empty() {
+        return Ok(Expr::Identity);
+    }
+    let expr = parser.pipe()?;
+    match parser.peek() {
+        None => Ok(expr),
+        Some(t) => Err(format!("Unexpected {:?} in query", t)),
+    }
+}
+
+// ============================================================================
+// JQ EVALUATION
+// ============================================================================
+
+fn number(n: f64) -> Value {
+    if n.fract() == 0.0 && n.abs() < 9.0e15 {
+        Value::from(n as i64)
+    } else {
+        serde_json::Number::from_f64(n).map_or(Value::Null, Value::Number)
+    }
+}
+
+fn truthy(v: &Value) -> bool {
+    !matches!(v, Value::Null | Value::Bool(false))
+}
+
+fn type_name(v: &Value) -> &'static str {
+    match v {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// jq ordering: null < false < true < numbers < strings < arrays < objects
+fn compare(a: &Value, b: &Value) -> Ordering {
+    let rank = |v: &Value| match v {
+        Value::Null => 0,
+        Value::Bool(false) => 1,
+        Value::Bool(true) => 2,
+        Value::Number(_) => 3,
+        Value::String(_) => 4,
+        Value::Array(_) => 5,
+        Value::Object(_) => 6,
+    };
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => {
+            x.as_f64().unwrap_or(0.0).total_cmp(&y.as_f64().unwrap_or(0.0))
+        }
+        (Value::String(x), Value::String(y)) => x.cmp(y),
+        (Value::Array(x), Value::Array(y)) => {
+            x.iter().zip(y).map(|(p, q)| compare(p, q)).find(|o| o.is_ne()).unwrap_or(x.len().cmp(&y.len()))
+        }
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+fn index_of(len: usize, i: i64) -> Option<usize> {
+    let i = if i < 0 { len as i64 + i } else { i };
+    usize::try_from(i).ok().filter(|&i| i < len)
+}
+
+fn index(target: &Value, key: &Value) -> Result<Value, String> {
+    match (target, key) {
+        (Value::Null, _) => Ok(Value::Null),
+        (Value::Object(obj), Value::String(k)) => Ok(obj.get(k).cloned().unwrap_or(Value::Null)),
+        (Value::Array(items), Value::Number(n)) => {
+            let i = n.as_f64().unwrap_or(0.0) as i64;
+            Ok(index_of(items.len(), i).map(|i| items[i].clone()).unwrap_or(Value::Null))
+        }
+        _ => Err(format!("Cannot index {} with {}", type_name(target), type_name(key))),
+    }
+}
+
+fn slice_bounds(len: usize, start: Option<&Value>, end: Option<&Value>) -> (usize, usize) {
+    let clamp = |v: Option<&Value>, default: usize| match v.and_then(Value::as_f64) {
+        Some(i) if i < 0.0 => (len as i64 + i as i64).max(0) as usize,
+        Some(i) => (i as usize).min(len),
+        None => default,
+    };
+    let s = clamp(start, 0);
+    (s, clamp(end, len).max(s))
+}
+
+fn arithmetic(op: &str, a: &Value, b: &Value) -> Result<Value, String> {
+    match (op, a, b) {
+        ("+", Value::Null, x) | ("+", x, Value::Null) => Ok(x.clone()),
+        ("+", Value::String(x), Value::String(y)) => Ok(Value::String(format!("{}{}", x, y))),
+        ("+", Value::Array(x), Value::Array(y)) => Ok(Value::Array(x.iter().chain(y).cloned().collect())),
+        ("+", Value::Object(x), Value::Object(y)) => {
+            let mut merged = x.clone();
+            merged.extend(y.iter().map(|(k, v)| (k.clone(), v.clone())));
+            Ok(Value::Object(merged))
+        }
+        ("-", Value::Array(x), Value::Array(y)) => {
+            Ok(Value::Array(x.iter().filter(|v| !y.contains(v)).cloned().collect()))
+        }
+        (_, Value::Number(x), Value::Number(y)) => {
+            let (x, y) = (x.as_f64().unwrap_or(0.0), y.as_f64().unwrap_or(0.0));
+            Ok(number(match op {
+                "+" => x + y,
+                "-" => x - y,
+                "*" => x * y,
+                "/" if y == 0.0 => return Err("Division by zero".to_string()),
+                "/" => x / y,
+                "%" if y as i64 == 0 => return Err("Division by zero".to_string()),
+                _ => (x as i64).checked_rem(y as i64).ok_or("Remainder overflows")? as f64,
+            }))
+        }
+        ("/", Value::String(x), Value::String(y)) => {
+            Ok(Value::Array(x.split(y.as_str()).map(|s| Value::String(s.to_string())).collect()))
+        }
+        _ => Err(format!("Cannot apply {} to {} and {}", op, type_name(a), type_name(b))),
+    }
+}
+
+fn recurse(v: &Value, out: &mut Vec<Value>) {
+    out.push(v.clone());
+    match v {
+        Value::Array(items) => items.iter().for_each(|i| recurse(i, out)),
+        Value::Object(obj) => obj.values().for_each(|i| recurse(i, out)),
+        _ => {}
+    }
+}
+
+fn single(expr: &Expr, input: &Value, what: &str) -> Result<Value, String> {
+    eval(expr, input)?.into_iter().next().ok_or_else(|| format!("{} produced no value", what))
+}
+
+fn call(name: &str, args: &[Expr], input: &Value) -> Result<Vec<Value>, String> {
+    let arity = |n: usize| {
+        if args.len() == n {
+            Ok(())
+        } else {
+            Err(format!("{}/{} is not defined", name, args.len()))
+        }
+    };
+    let array = || input.as_array().ok_or_else(|| format!("{} needs an array, got {}", name, type_name(input)));
+    let one = |v: Value| Ok(vec![v]);
+    // Everything but these takes no arguments
+    if !matches!(
+        name,
+        "map" | "select" | "has" | "sort_by" | "group_by" | "unique_by" | "min_by" | "max_by" | "join" | "split"
+            | "startswith" | "endswith" | "contains" | "test"
+    ) {
+        arity(0)?;
+    }
+
+    match name {
+        "empty" => Ok(Vec::new()),
+        "not" => one(Value::Bool(!truthy(input))),
+        "length" => one(match input {
+            Value::Null => Value::from(0),
+            Value::Bool(_) => return Err("boolean has no length".to_string()),
+            Value::Number(n) => number(n.as_f64().unwrap_or(0.0).abs()),
+            Value::String(s) => Value::from(s.chars().count()),
+            Value::Array(a) => Value::from(a.len()),
+            Value::Object(o) => Value::from(o.len()),
+        }),
+        "keys" => one(match input {
+            Value::Object(o) => {
+                let mut keys: Vec<&String> = o.keys().collect();
+                keys.sort();
+                Value::Array(keys.into_iter().map(|k| Value::String(k.clone())).collect())
+            }
+            Value::Array(a) => Value::Array((0..a.len()).map(Value::from).collect()),
+            other => return Err(format!("{} has no keys", type_name(other))),
+        }),
+        "values" => Ok(if input.is_null() { Vec::new() } else { vec![input.clone()] }),
+        "type" => one(Value::String(type_name(input).to_string())),
+        "first" => one(array()?.first().cloned().unwrap_or(Value::Null)),
+        "last" => one(array()?.last().cloned().unwrap_or(Value::Null)),
+        "reverse" => one(Value::Array(array()?.iter().rev().cloned().collect())),
+        "sort" => {
+            let mut items = array()?.clone();
+            items.sort_by(compare);
+            one(Value::Array(items))
+        }
+        "unique" => {
+            let mut items = array()?.clone();
+            items.sort_by(compare);
+            items.dedup();
+            one(Value::Array(items))
+        }
+        "min" => one(array()?.iter().min_by(|a, b| compare(a, b)).cloned().unwrap_or(Value::Null)),
+        "max" => one(array()?.iter().max_by(|a, b| compare(a, b)).cloned().unwrap_or(Value::Null)),
+        "add" => {
+            let mut total = Value::Null;
+            for item in array()? {
+                total = arithmetic("+", &total, item)?;
+            }
+            one(total)
+        }
+        "to_entries" => {
+            let obj = input.as_object().ok_or("to_entries needs an object")?;
+            one(Value::Array(
+                obj.iter().map(|(k, v)| serde_json::json!({"key": k, "value": v})).collect(),
+            ))
+        }
+        "tostring" => one(match input {
+            Value::String(_) => input.clone(),
+            other => Value::String(other.to_string()),
+        }),
+        "tonumber" => match input {
+            Value::Number(_) => one(input.clone()),
+            Value::String(s) => s.trim().parse::<f64>().map(|n| vec![number(n)]).map_err(|_| format!("Cannot parse '{}' as a number", s)),
+            other => Err(format!("Cannot convert {} to a number", type_name(other))),
+        },
+        "ascii_downcase" | "ascii_upcase" => {
+            let s = input.as_str().ok_or_else(|| format!("{} needs a string", name))?;
+            one(Value::String(if name == "ascii_downcase" { s.to_ascii_lowercase() } else { s.to_ascii_uppercase() }))
+        }
+        "map" => {
+            arity(1)?;
+            let mut out = Vec::new();
+            for item in array()? {
+                out.extend(eval(&args[0], item)?);
+            }
+            one(Value::Array(out))
+        }
+        "select" => {
+            arity(1)?;
+            Ok(eval(&args[0], input)?.iter().filter(|v| truthy(v)).map(|_| input.clone()).collect())
+        }
+        "has" => {
+            arity(1)?;
+            let key = single(&args[0], input, "has")?;
+            one(Value::Bool(match (input, &key) {
+                (Value::Object(o), Value::String(k)) => o.contains_key(k),
+                (Value::Array(a), Value::Number(n)) => n.as_u64().is_some_and(|i| (i as usize) < a.len()),
+                _ => return Err(format!("Cannot check whether {} has a {} key", type_name(input), type_name(&key))),
+            }))
+        }
+        "sort_by" | "group_by" | "unique_by" | "min_by" | "max_by" => {
+            arity(1)?;
+            let mut keyed: Vec<(Value, Value)> = array()?
+                .iter()
+                .map(|item| Ok((Value::Array(eval(&args[0], item)?), item.clone())))
+                .collect::<Result<_, String>>()?;
+            keyed.sort_by(|a, b| compare(&a.0, &b.0));
+            one(match name {
+                "sort_by" => Value::Array(keyed.into_iter().map(|(_, v)| v).collect()),
+                "min_by" => keyed.first().map(|(_, v)| v.clone()).unwrap_or(Value::Null),
+                "max_by" => keyed.last().map(|(_, v)| v.clone()).unwrap_or(Value::Null),
+                _ => {
+                    let mut groups: Vec<(Value, Vec<Value>)> = Vec::new();
+                    for (k, v) in keyed {
+                        match groups.last_mut() {
+                            Some((last, items)) if *last == k => items.push(v),
+                            _ => groups.push((k, vec![v])),
+                        }
+                    }
+                    if name == "group_by" {
+                        Value::Array(groups.into_iter().map(|(_, g)| Value::Array(g)).collect())
+                    } else {
+                        Value::Array(groups.into_iter().filter_map(|(_, g)| g.into_iter().next()).collect())
+                    }
+                }
+            })
+        }
+        "join" => {
+            arity(1)?;
+            let sep = single(&args[0], input, "join")?;
+            let sep = sep.as_str().ok_or("join separator must be a string")?;
+            let parts: Vec<String> = array()?
+                .iter()
+                .map(|v| match v {
+                    Value::String(s) => s.clone(),
+                    Value::Null => String::new(),
+                    other => other.to_string(),
+                })
+                .collect();
+            one(Value::String(parts.join(sep)))
+        }
+        "test" => {
+            if args.len() != 2 {
+                arity(1)?;
+            }
+            let text = input.as_str().ok_or("test needs a string")?;
+            let pattern = single(&args[0], input, "test")?;
+            let pattern = pattern.as_str().ok_or("test needs a string regex")?;
+            let flags = match args.get(1) {
+                Some(flags) => single(flags, input, "test flags")?,
+                None => Value::String(String::new()),
+            };
+            let flags = flags.as_str().ok_or("test flags must be a string")?;
+            let mut builder = regex::RegexBuilder::new(pattern);
+            for flag in flags.chars() {
+                match flag {
+                    'i' => builder.case_insensitive(true),
+                    'x' => builder.ignore_whitespace(true),
+                    's' => builder.dot_matches_new_line(true),
+                    'g' | 'n' => &mut builder,
+                    other => return Err(format!("Unknown regex flag '{}'", other)),
+                };
+            }
+            let re = builder.build().map_err(|e| format!("Invalid regex: {}", e))?;
+            one(Value::Bool(re.is_match(text)))
+        }
+        "split" | "startswith" | "endswith" | "contains" => {
+            arity(1)?;
+            let arg = single(&args[0], input, name)?;
+            if name == "contains" {
+                return one(Value::Bool(contains(input, &arg)));
+            }
+            let (s, a) = input.as_str().zip(arg.as_str()).ok_or_else(|| format!("{} needs strings", name))?;
+            one(match name {
+                "split" => Value::Array(s.split(a).map(|p| Value::String(p.to_string())).collect()),
+                "startswith" => Value::Bool(s.starts_with(a)),
+                _ => Value::Bool(s.ends_with(a)),
+            })
+        }
+        _ => Err(format!("{}/{} is not defined", name, args.len())),
+    }
+}
+
+/// jq `contains`: substring for strings, recursive subset for arrays/objects
+fn contains(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(x), Value::String(y)) => x.contains(y.as_str()),
+        (Value::Array(x), Value::Array(y)) => y.iter().all(|yv| x.iter().any(|xv| contains(xv, yv))),
+        (Value::Object(x), Value::Object(y)) => {
+            y.iter().all(|(k, yv)| x.get(k).is_some_and(|xv| contains(xv, yv)))
+        }
+        _ => a == b,
+    }
+}
+
+fn eval(expr: &Expr, input: &Value) -> Result<Vec<Value>, String> {
+    match expr {
+        Expr::Identity => Ok(vec![input.clone()]),
+        Expr::Recurse => {
+            let mut out = Vec::new();
+            recurse(input, &mut out);
+            Ok(out)
+        }
+        Expr::Literal(v) => Ok(vec![v.clone()]),
+        Expr::Field(target, name) => eval(target, input)?
+            .iter()
+            .map(|t| index(t, &Value::String(name.clone())))
+            .collect(),
+        Expr::Index(target, key) => {
+            let mut out = Vec::new();
+            for t in eval(target, input)? {
+                for k in eval(key, input)? {
+                    out.push(index(&t, &k)?);
+                }
+            }
+            Ok(out)
+        }
+        Expr::Slice(target, start, end) => {
+            let start = start.as_ref().map(|e| single(e, input, "slice start")).transpose()?;
+            let end = end.as_ref().map(|e| single(e, input, "slice end")).transpose()?;
+            eval(target, input)?
+                .iter()
+                .map(|t| match t {
+                    Value::Null => Ok(Value::Null),
+                    Value::Array(items) => {
+                        let (s, e) = slice_bounds(items.len(), start.as_ref(), end.as_ref());
+                        Ok(Value::Array(items[s..e].to_vec()))
+                    }
+                    Value::String(text) => {
+                        let chars: Vec<char> = text.chars().collect();
+                        let (s, e) = slice_bounds(chars.len(), start.as_ref(), end.as_ref());
+                        Ok(Value::String(chars[s..e].iter().collect()))
+                    }
+                    other => Err(format!("Cannot slice {}", type_name(other))),
+                })
+                .collect()
+        }
+        Expr::Iterate(target) => {
+            let mut out = Vec::new();
+            for t in eval(target, input)? {
+                match t {
+                    Value::Array(items) => out.extend(items),
+                    Value::Object(obj) => out.extend(obj.into_iter().map(|(_, v)| v)),
+                    other => return Err(format!("Cannot iterate over {}", type_name(&other))),
+                }
+            }
+            Ok(out)
+        }
+        Expr::Try(inner) => Ok(eval(inner, input).unwrap_or_default()),
+        Expr::Array(inner) => Ok(vec![Value::Array(match inner {
+            Some(e) => eval(e, input)?,
+            None => Vec::new(),
+        })]),
+        Expr::Object(entries) => {
+            let mut objects = vec![Map::new()];
+            for (key, value_expr) in entries {
+                let values = eval(value_expr, input)?;
+                objects = objects
+                    .into_iter()
+                    .flat_map(|obj| {
+                        values.iter().map(move |v| {
+                            let mut o = obj.clone();
+                            o.insert(key.clone(), v.clone());
+                            o
+                        })
+                    })
+                    .collect();
+            }
+            Ok(objects.into_iter().map(Value::Object).collect())
+        }
+        Expr::Pipe(left, right) => {
+            let mut out = Vec::new();
+            for v in eval(left, input)? {
+                out.extend(eval(right, &v)?);
+            }
+            Ok(out)
+        }
+        Expr::Comma(left, right) => {
+            let mut out = eval(left, input)?;
+            out.extend(eval(right, input)?);
+            Ok(out)
+        }
+        Expr::Not(inner) => Ok(eval(inner, input)?.iter().map(|v| Value::Bool(!truthy(v))).collect()),
+        Expr::Binary(op, left, right) => {
+            if *op == "//" {
+                let found: Vec<Value> = eval(left, input).unwrap_or_default().into_iter().filter(truthy).collect();
+                return if found.is_empty() { eval(right, input) } else { Ok(found) };
+            }
+            let mut out = Vec::new();
+            for r in eval(right, input)? {
+                for l in eval(left, input)? {
+                    out.push(match *op {
+                        "and" => Value::Bool(truthy(&l) && truthy(&r)),
+                        "or" => Value::Bool(truthy(&l) || truthy(&r)),
+                        "==" => Value::Bool(l == r),
+                        "!=" => Value::Bool(l != r),
+                        "<" => Value::Bool(compare(&l, &r).is_lt()),
+                        "<=" => Value::Bool(compare(&l, &r).is_le()),
+                        ">" => Value::Bool(compare(&l, &r).is_gt()),
+                        ">=" => Value::Bool(compare(&l, &r).is_ge()),
+                        _ => arithmetic(op, &l, &r)?,
+                    });
+                }
+            }
+            Ok(out)
+        }
+        Expr::Call(name, args) => call(name, args, input),
+    }
+}
+
+// ============================================================================
+// JSONPATH
+// ============================================================================
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Child(String),
+    Index(i64),
+    Wildcard,
+    Slice(Option<i64>, Option<i64>, i64),
+    Union(Vec<Segment>),
+    /// `..` followed by a selector
+    Descend(Box<Segment>),
+    /// `[?(expr)]` with `@` as the current element
+    Filter(Expr),
+}
+
+fn parse_bracket(inner: &str) -> Result<Segment, String> {
+    let inner = inner.trim();
+    if inner == "*" {
+        return Ok(Segment::Wildcard);
+    }
+    if let Some(filter) = inner.strip_prefix('?') {
+        let filter = filter.trim();
+        let filter = filter.strip_prefix('(').and_then(|f| f.strip_suffix(')')).unwrap_or(filter);
+        return parse_jq(filter).map(Segment::Filter);
+    }
+    let parts = split_top_level(inner, ',');
+    if parts.len() > 1 {
+        return parts.iter().map(|p| parse_bracket(p)).collect::<Result<_, _>>().map(Segment::Union);
+    }
+    let quoted = |q: char| inner.strip_prefix(q).and_then(|s| s.strip_suffix(q));
+    if let Some(name) = quoted('\'').or_else(|| quoted('"')) {
+        return Ok(Segment::Child(name.to_string()));
+    }
+    if inner.contains(':') {
+        let nums: Vec<Option<i64>> = inner.split(':').map(|p| p.trim().parse().ok()).collect();
+        return Ok(Segment::Slice(
+            nums.first().copied().flatten(),
+            nums.get(1).copied().flatten(),
+            nums.get(2).copied().flatten().unwrap_or(1),
+        ));
+    }
+    inner
+        .parse::<i64>()
+        .map(Segment::Index)
+        .map_err(|_| format!("Bad JSONPath selector: [{}]", inner))
+}
+
+/// Split on `sep` outside quotes and brackets
+fn split_top_level(s: &str, sep: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let (mut depth, mut quote) = (0i32, None);
+    for c in s.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth -= 1,
+            (None, c) if c == sep && depth == 0 => {
+                parts.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+        if let Some(last) = parts.last_mut() {
+            last.push(c);
+        }
+    }
+    parts
+}
+
+fn parse_path(src: &str) -> Result<Vec<Segment>, String> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut segments = Vec::new();
+    let mut i = 1; // skip `$`
+
+    let read_name = |i: &mut usize| {
+        let start = *i;
+        while *i < chars.len() && !matches!(chars[*i], '.' | '[') {
+            *i += 1;
+        }
+        chars[start..*i].iter().collect::<String>()
+    };
+    let read_bracket = |i: &mut usize| -> Result<String, String> {
+        let start = *i + 1;
+        let (mut depth, mut quote) = (0, None);
+        for (j, &c) in chars.iter().enumerate().skip(*i) {
+            match (quote, c) {
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => {}
+                (None, '\'' | '"') => quote = Some(c),
+                (None, '[') => depth += 1,
+                (None, ']') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        *i = j + 1;
+                        return Ok(chars[start..j].iter().collect());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Err("Unclosed [ in JSONPath".to_string())
+    };
+
+    while i < chars.len() {
+        let descend = chars[i] == '.' && chars.get(i + 1) == Some(&'.');
+        let segment = if descend || chars[i] == '.' {
+            i += if descend { 2 } else { 1 };
+            match chars.get(i) {
+                Some('[') => parse_bracket(&read_bracket(&mut i)?)?,
+                Some('*') => {
+                    i += 1;
+                    Segment::Wildcard
+                }
+                _ => {
+                    let name = read_name(&mut i);
+                    if name.is_empty() {
+                        return Err("Empty name in JSONPath".to_string());
+                    }
+                    Segment::Child(name)
+                }
+            }
+        } else if chars[i] == '[' {
+            parse_bracket(&read_bracket(&mut i)?)?
+        } else {
+            return Err(format!("Unexpected '{}' in JSONPath", chars[i]));
+        };
+        segments.push(if descend { Segment::Descend(Box::new(segment)) } else { segment });
+    }
+    Ok(segments)
+}
+
+fn descendants<'a>(v: &'a Value, out: &mut Vec<&'a Value>) {
+    out.push(v);
+    match v {
+        Value::Array(items) => items.iter().for_each(|i| descendants(i, out)),
+        Value::Object(obj) => obj.values().for_each(|i| descendants(i, out)),
+        _ => {}
+    }
+}
+
+fn select<'a>(node: &'a Value, segment: &Segment, out: &mut Vec<&'a Value>) {
+    match (segment, node) {
+        (Segment::Child(name), Value::Object(obj)) => out.extend(obj.get(name)),
+        (Segment::Index(i), Value::Array(items)) => out.extend(index_of(items.len(), *i).map(|i| &items[i])),
+        (Segment::Wildcard, Value::Array(items)) => out.extend(items),
+        (Segment::Wildcard, Value::Object(obj)) => out.extend(obj.values()),
+        (Segment::Slice(start, end, step), Value::Array(items)) => {
+            let (s, e) = slice_bounds(items.len(), start.map(Value::from).as_ref(), end.map(Value::from).as_ref());
+            if *step > 0 {
+                out.extend(items[s..e].iter().step_by(*step as usize));
+            }
+        }
+        (Segment::Union(parts), _) => parts.iter().for_each(|p| select(node, p, out)),
+        (Segment::Descend(inner), _) => {
+            let mut all = Vec::new();
+            descendants(node, &mut all);
+            all.into_iter().for_each(|n| select(n, inner, out));
+        }
+        (Segment::Filter(expr), Value::Array(_) | Value::Object(_)) => {
+            let mut children = Vec::new();
+            select(node, &Segment::Wildcard, &mut children);
+            out.extend(children.into_iter().filter(|c| eval(expr, c).is_ok_and(|r| r.iter().any(truthy))));
+        }
+        _ => {}
+    }
+}
+
+fn eval_path<'a>(input: &'a Value, segments: &[Segment]) -> Vec<&'a Value> {
+    let mut current = vec![input];
+    for segment in segments {
+        let mut next = Vec::new();
+        for node in current {
+            select(node, segment, &mut next);
+        }
+        current = next;
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn store() -> Value {
+        json!({"store": {"book": [
+            {"title": "Sayings", "price": 8.95, "tags": ["old"]},
+            {"title": "Sword", "price": 12.99, "isbn": "0-553"},
+            {"title": "Moby Dick", "price": 8.99, "isbn": "0-395"}
+        ], "bicycle": {"price": 19.95}}})
+    }
+
+    #[test]
+    fn test_jsonpath() {
+        let data = store();
+        let q = |e: &str| query(&data, e).unwrap();
+        assert_eq!(q("$.store.book[0].title"), vec![json!("Sayings")]);
+        assert_eq!(q("$.store.book[-1].title"), vec![json!("Moby Dick")]);
+        assert_eq!(q("$..price").len(), 4);
+        assert_eq!(q("$.store.book[?(@.price < 10)].title"), vec![json!("Sayings"), json!("Moby Dick")]);
+        assert_eq!(q("$.store.book[?(@.isbn)]").len(), 2);
+        assert_eq!(q("$['store']['book'][0:2].title"), vec![json!("Sayings"), json!("Sword")]);
+        assert_eq!(q("$.store.book[0,2].price"), vec![json!(8.95), json!(8.99)]);
+        assert!(query(&data, "$.store[").is_err());
+    }
+
+    #[test]
+    fn test_jq_filters() {
+        let data = store();
+        let q = |e: &str| query(&data, e).unwrap();
+        assert_eq!(q(".store.book[1].title"), vec![json!("Sword")]);
+        assert_eq!(q(".store.book | map(.price > 9)"), vec![json!([false, true, false])]);
+        assert_eq!(q(".store.book[] | select(.isbn) | .title"), vec![json!("Sword"), json!("Moby Dick")]);
+        assert_eq!(q(".store | keys"), vec![json!(["bicycle", "book"])]);
+        assert_eq!(q(".store.book | length"), vec![json!(3)]);
+        assert_eq!(q("[.store.book[].tags // [] | length] | add"), vec![json!(1)]);
+        assert_eq!(q(".store.book[0] | {title, cheap: (.price < 10)}"), vec![json!({"title": "Sayings", "cheap": true})]);
+        assert_eq!(q(".store.book | sort_by(.price) | .[-1].title"), vec![json!("Sword")]);
+        assert!(query(&data, ".store | nope").unwrap_err().contains("not defined"));
+        assert_eq!(query(&json!({"a": 7}), ".a % -4").unwrap(), vec![json!(3)]);
+        assert_eq!(query(&json!({"a": i64::MIN}), ".a % -1").unwrap_err(), "Remainder overflows");
+    }
+
+    #[test]
+    fn test_jq_builtins() {
+        let data = store();
+        let q = |e: &str| query(&data, e).unwrap();
+        let titles = ".store.book | map(.title)";
+        assert_eq!(q(&format!("{} | first, last", titles)), vec![json!("Sayings"), json!("Moby Dick")]);
+        assert_eq!(q(&format!("{} | join(\", \")", titles)), vec![json!("Sayings, Sword, Moby Dick")]);
+        assert_eq!(q(".store.book | map(.price) | min, max"), vec![json!(8.95), json!(12.99)]);
+        assert_eq!(q(".store.book | group_by(.price < 10) | map(length)"), vec![json!([1, 2])]);
+        assert_eq!(q(".store.book[0] | has(\"tags\"), has(\"isbn\")"), vec![json!(true), json!(false)]);
+        assert_eq!(q(".store.bicycle | to_entries"), vec![json!([{"key": "price", "value": 19.95}])]);
+        assert_eq!(q("\"a-b\" | split(\"-\") | reverse"), vec![json!(["b", "a"])]);
+        assert_eq!(q("\"12\" | tonumber + 1"), vec![json!(13)]);
+        assert_eq!(q("[3, 1, 3] | unique"), vec![json!([1, 3])]);
+        assert_eq!(q(".store.book[0].tags | contains([\"old\"])"), vec![json!(true)]);
+        assert_eq!(q(".missing | length"), vec![json!(0)]);
+        assert_eq!(q("[.store.book[] | .isbn?] | map(values) | length"), vec![json!(2)]);
+        assert_eq!(q("[1, [2]] | map(.[0]?)"), vec![json!([2])]);
+    }
+
+    #[test]
+    fn test_jq_errors_and_regex() {
+        let data = store();
+        let q = |e: &str| query(&data, e).unwrap();
+        assert_eq!(q("[.store.book[].title | test(\"^S\\\\w+$\")]"), vec![json!([true, true, false])]);
+        assert_eq!(q("\"Moby\" | test(\"moby\"), test(\"moby\"; \"i\")"), vec![json!(false), json!(true)]);
+        assert_eq!(q("\"a.c\" | test(\"a.c\"), (\"abc\" | test(\"a\\\\.c\"))"), vec![json!(true), json!(false)]);
+        assert!(query(&data, "\"a\" | test(\"(\")").unwrap_err().starts_with("Invalid regex"));
+        assert!(query(&data, "\"a\" | test(\"a\"; \"q\")").unwrap_err().contains("flag"));
+
+        assert_eq!(query(&data, ".store | keys(.book)").unwrap_err(), "keys/1 is not defined");
+        assert_eq!(query(&data, ".store.book | length(1; 2)").unwrap_err(), "length/2 is not defined");
+        assert_eq!(query(&data, "map").unwrap_err(), "map/0 is not defined");
+        assert!(query(&data, ".store.book[").is_err());
+        assert!(query(&data, "{a: 1").is_err());
+
+        let nested = |n: usize| format!("{}.{}", "(".repeat(n), ")".repeat(n));
+        assert_eq!(q(&nested(MAX_DEPTH - 1)), vec![data.clone()]);
+        assert!(query(&data, &nested(100_000)).unwrap_err().contains("too deeply"));
+        assert!(query(&data, &".a".repeat(100_000)).unwrap_err().contains("too deeply"));
+        assert!(query(&data, &format!("1{}", "+1".repeat(100_000))).unwrap_err().contains("too deeply"));
+        assert!(query(&data, &"!".repeat(100_000)).unwrap_err().contains("too deeply"));
+        assert!(query(&data, &"[".repeat(100_000)).unwrap_err().contains("too deeply"));
+    }
+}
@@ -0,0 +1,362 @@
+//! Data format conversion through JSON: text formats plus MessagePack, CBOR and BSON
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use serde_json::{Map, Number, Value};
+
+use super::structured::{self, FmtOptions};
+
+/// Supported data formats
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Json,
+    Yaml,
+    Toml,
+    Xml,
+    /// Header row plus records, as an array of objects
+    Csv,
+    MsgPack,
+    Cbor,
+    Bson,
+    /// CBOR diagnostic notation (RFC 8949 §8), output only
+    CborDiag,
+}
+
+/// All formats, in the order they are listed to users
+pub const FORMATS: [Format; 9] = [
+    Format::Json,
+    Format::Yaml,
+    Format::Toml,
+    Format::Xml,
+    Format::Csv,
+    Format::MsgPack,
+    Format::Cbor,
+    Format::Bson,
+    Format::CborDiag,
+];
+
+impl Format {
+    /// Short lowercase name (`json`, `msgpack`, ...)
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Json => "json",
+            Format::Yaml => "yaml",
+            Format::Toml => "toml",
+            Format::Xml => "xml",
+            Format::Csv => "csv",
+            Format::MsgPack => "msgpack",
+            Format::Cbor => "cbor",
+            Format::Bson => "bson",
+            Format::CborDiag => "diag",
+        }
+    }
+
+    /// Parse a format name or common alias (`mp`, `messagepack`, `cbor-diag`)
+    pub fn from_name(name: &str) -> Result<Format, String> {
+        match name.to_lowercase().as_str() {
+            "json" => Ok(Format::Json),
+            "yaml" | "yml" => Ok(Format::Yaml),
+            "toml" => Ok(Format::Toml),
+            "xml" => Ok(Format::Xml),
+            "csv" => Ok(Format::Csv),
+            "msgpack" | "messagepack" | "mp" => Ok(Format::MsgPack),
+            "cbor" => Ok(Format::Cbor),
+            "bson" => Ok(Format::Bson),
+            "diag" | "cbor-diag" | "edn" => Ok(Format::CborDiag),
+            _ => Err(format!("Unknown data format: {}", name)),
+        }
+    }
+
+    /// Whether the encoded form is binary rather than text
+    pub fn is_binary(self) -> bool {
+        matches!(self, Format::MsgPack | Format::Cbor | Format::Bson)
+    }
+}
+
+fn float(f: f64) -> Value {
+    // JSON has no NaN or infinity
+    Number::from_f64(f).map_or(Value::Null, Value::Number)
+}
+
+fn key_string(key: Value) -> String {
+    match key {
+        Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+// ============================================================================
+// MESSAGEPACK
+// ============================================================================
+
+fn msgpack_to_json(value: rmpv::Value) -> Value {
+    use rmpv::Value as M;
+    match value {
+        M::Nil => Value::Null,
+        M::Boolean(b) => Value::Bool(b),
+        M::Integer(i) => match (i.as_u64(), i.as_i64()) {
+            (Some(u), _) => Value::from(u),
+            (_, Some(s)) => Value::from(s),
+            _ => Value::Null,
+        },
+        M::F32(f) => float(f64::from(f)),
+        M::F64(f) => float(f),
+        M::String(s) => match s.into_str() {
+            Some(s) => Value::String(s),
+            None => Value::Null,
+        },
+        M::Binary(b) => Value::String(BASE64.encode(b)),
+        M::Array(items) => Value::Array(items.into_iter().map(msgpack_to_json).collect()),
+        M::Map(pairs) => Value::Object(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (key_string(msgpack_to_json(k)), msgpack_to_json(v)))
+                .collect(),
+        ),
+        M::Ext(kind, data) => {
+            let mut obj = Map::new();
+            obj.insert("ext".into(), Value::from(kind));
+            obj.insert("data".into(), Value::String(BASE64.encode(data)));
+            Value::Object(obj)
+        }
+    }
+}
+
+fn json_to_msgpack(value: &Value) -> rmpv::Value {
+    use rmpv::Value as M;
+    match value {
+        Value::Null => M::Nil,
+        Value::Bool(b) => M::Boolean(*b),
+        Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+            (Some(u), _) => M::from(u),
+            (_, Some(s)) => M::from(s),
+            _ => M::F64(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => M::from(s.as_str()),
+        Value::Array(items) => M::Array(items.iter().map(json_to_msgpack).collect()),
+        Value::Object(obj) => M::Map(obj.iter().map(|(k, v)| (M::from(k.as_str()), json_to_msgpack(v))).collect()),
+    }
+}
+
+// ============================================================================
+// CBOR
+// ============================================================================
+
+fn cbor_to_json(value: ciborium::Value) -> Value {
+    use ciborium::Value as C;
+    match value {
+        C::Null => Value::Null,
+        C::Bool(b) => Value::Bool(b),
+        C::Integer(i) => {
+            let i = i128::from(i);
+            match (u64::try_from(i), i64::try_from(i)) {
+                (Ok(u), _) => Value::from(u),
+                (_, Ok(s)) => Value::from(s),
+                _ => Value::String(i.to_string()),
+            }
+        }
+        C::Float(f) => float(f),
+        C::Text(s) => Value::String(s),
+        C::Bytes(b) => Value::String(BASE64.encode(b)),
+        // Tags carry no JSON meaning; keep the tagged value
+        C::Tag(_, inner) => cbor_to_json(*inner),
+        C::Array(items) => Value::Array(items.into_iter().map(cbor_to_json).collect()),
+        C::Map(pairs) => Value::Object(
+            pairs
+                .into_iter()
+                .map(|(k, v)| (key_string(cbor_to_json(k)), cbor_to_json(v)))
+                .collect(),
+        ),
+        _ => Value::Null,
+    }
+}
+
+fn json_to_cbor(value: &Value) -> ciborium::Value {
+    use ciborium::Value as C;
+    match value {
+        Value::Null => C::Null,
+        Value::Bool(b) => C::Bool(*b),
+        Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+            (Some(u), _) => C::Integer(u.into()),
+            (_, Some(s)) => C::Integer(s.into()),
+            _ => C::Float(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => C::Text(s.clone()),
+        Value::Array(items) => C::Array(items.iter().map(json_to_cbor).collect()),
+        Value::Object(obj) => C::Map(obj.iter().map(|(k, v)| (C::Text(k.clone()), json_to_cbor(v))).collect()),
+    }
+}
+
+fn read_cbor(data: &[u8]) -> Result<ciborium::Value, String> {
+    let mut reader = data;
+    let value = ciborium::de::from_reader(&mut reader).map_err(|e| format!("CBOR decode error: {}", e))?;
+    if !reader.is_empty() {
+        return Err(format!("CBOR: {} trailing bytes", reader.len()));
+    }
+    Ok(value)
+}
+
+fn diag_float(f: f64) -> String {
+    if f.is_nan() {
+        "NaN".to_string()
+    } else if f.is_infinite() {
+        if f > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else {
+        format!("{:?}", f)
+    }
+}
+
+fn diag(value: &ciborium::Value) -> String {
+    use ciborium::Value as C;
+    match value {
+        C::Null => "null".to_string(),
+        C::Bool(b) => b.to_string(),
+        C::Integer(i) => i128::from(*i).to_string(),
+        C::Float(f) => diag_float(*f),
+        C::Text(s) => Value::String(s.clone()).to_string(),
+        C::Bytes(b) => format!("h'{}'", hex::encode(b)),
+        C::Tag(tag, inner) => format!("{}({})", tag, diag(inner)),
+        C::Array(items) => format!("[{}]", items.iter().map(diag).collect::<Vec<_>>().join(", ")),
+        C::Map(pairs) => format!(
+            "{{{}}}",
+            pairs.iter().map(|(k, v)| format!("{}: {}", diag(k), diag(v))).collect::<Vec<_>>().join(", ")
+        ),
+        _ => "undefined".to_string(),
+    }
+}
+
+/// Render CBOR in diagnostic notation, e.g. `{"a": [1, h'ff', 1(1700000000)]}`
+pub fn cbor_diagnostic(data: &[u8]) -> Result<String, String> {
+    read_cbor(data).map(|v| diag(&v))
+}
+
+// ============================================================================
+// CONVERSION
+// ============================================================================
+
+/// Decode any supported format into JSON
+///
+/// Byte strings become base64 strings; MessagePack extensions become
+/// `{"ext": type, "data": base64}`.
+pub fn to_json(data: &[u8], from: Format) -> Result<Value, String> {
+    let text = || std::str::from_utf8(data).map_err(|e| format!("{} input is not UTF-8: {}", from.name(), e));
+    match from {
+        Format::Json => structured::parse_json(text()?),
+        Format::Yaml => structured::yaml_to_json(text()?),
+        Format::Toml => structured::toml_to_json(text()?),
+        Format::Xml => structured::xml_to_json(text()?),
+        Format::Csv => structured::csv_to_json(text()?),
+        Format::MsgPack => {
+            let mut reader = data;
+            let value = rmpv::decode::read_value(&mut reader).map_err(|e| format!("MessagePack decode error: {}", e))?;
+            if !reader.is_empty() {
+                return Err(format!("MessagePack: {} trailing bytes", reader.len()));
+            }
+            Ok(msgpack_to_json(value))
+        }
+        Format::Cbor => read_cbor(data).map(cbor_to_json),
+        Format::Bson => {
+            let doc = bson::Document::from_reader(data).map_err(|e| format!("BSON decode error: {}", e))?;
+            Ok(bson::Bson::Document(doc).into_relaxed_extjson())
+        }
+        Format::CborDiag => Err("Diagnostic notation is output only".to_string()),
+    }
+}
+
+/// Encode JSON into any supported format (JSON output is pretty-printed)
+pub fn from_json(value: &Value, to: Format) -> Result<Vec<u8>, String> {
+    match to {
+        Format::Json => Ok(structured::write_json(value, FmtOptions::default()).into_bytes()),
+        Format::Yaml => structured::json_to_yaml(value).map(String::into_bytes),
+        Format::Toml => structured::json_to_toml(value).map(String::into_bytes),
+        Format::Xml => Ok(structured::json_to_xml(value).into_bytes()),
+        Format::Csv => structured::json_to_csv(value).map(String::into_bytes),
+        Format::MsgPack => {
+            let mut out = Vec::new();
+            rmpv::encode::write_value(&mut out, &json_to_msgpack(value))
+                .map_err(|e| format!("MessagePack encode error: {}", e))?;
+            Ok(out)
+        }
+        Format::Cbor => {
+            let mut out = Vec::new();
+            ciborium::ser::into_writer(&json_to_cbor(value), &mut out).map_err(|e| format!("CBOR encode error: {}", e))?;
+            Ok(out)
+        }
+        Format::Bson => {
+            let bson = bson::Bson::try_from(value.clone()).map_err(|e| format!("BSON encode error: {}", e))?;
+            let bson::Bson::Document(doc) = bson else {
+                return Err("BSON needs a JSON object at the top level".to_string());
+            };
+            let mut out = Vec::new();
+            doc.to_writer(&mut out).map_err(|e| format!("BSON encode error: {}", e))?;
+            Ok(out)
+        }
+        Format::CborDiag => from_json(value, Format::Cbor).and_then(|cbor| cbor_diagnostic(&cbor)).map(String::into_bytes),
+    }
+}
+
+/// Convert between any two formats
+pub fn convert(data: &[u8], from: Format, to: Format) -> Result<Vec<u8>, String> {
+    convert_with(data, from, to, FmtOptions::default())
+}
+
+/// Convert with JSON output options; `sort_keys` applies to every format
+///
+/// Converting a format to itself reformats it.
+pub fn convert_with(data: &[u8], from: Format, to: Format, options: FmtOptions) -> Result<Vec<u8>, String> {
+    // CBOR keeps tags and byte strings when viewed directly
+    if from == Format::Cbor && to == Format::CborDiag {
+        return cbor_diagnostic(data).map(String::into_bytes);
+    }
+    write_value(&to_json(data, from)?, to, options)
+}
+
+/// Encode a JSON value as `to`, applying formatting options
+pub fn write_value(value: &Value, to: Format, options: FmtOptions) -> Result<Vec<u8>, String> {
+    match to {
+        Format::Json => Ok(structured::write_json(value, options).into_bytes()),
+        _ if options.sort_keys => {
+            let mut value = value.clone();
+            structured::sort_keys(&mut value);
+            from_json(&value, to)
+        }
+        _ => from_json(value, to),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"{"name":"frog","tags":["a","b"],"n":-3,"big":4294967296,"pi":3.5,"ok":true,"none":null}"#;
+
+    #[test]
+    fn test_roundtrip_all_formats() {
+        let json: Value = serde_json::from_str(SAMPLE).unwrap();
+        for format in [Format::MsgPack, Format::Cbor, Format::Bson] {
+            let encoded = from_json(&json, format).unwrap();
+            assert_eq!(to_json(&encoded, format).unwrap(), json, "{}", format.name());
+        }
+        assert!(from_json(&Value::from(1), Format::Bson).is_err());
+    }
+
+    #[test]
+    fn test_known_encodings() {
+        // {"a": 1} in MessagePack and CBOR
+        let json = serde_json::json!({"a": 1});
+        assert_eq!(hex::encode(from_json(&json, Format::MsgPack).unwrap()), "81a16101");
+        assert_eq!(hex::encode(from_json(&json, Format::Cbor).unwrap()), "a1616101");
+        assert_eq!(to_json(&hex::decode("a1616101").unwrap(), Format::Cbor).unwrap(), json);
+        assert_eq!(to_json(&hex::decode("a161610100").unwrap(), Format::Cbor).unwrap_err(), "CBOR: 1 trailing bytes");
+    }
+
+    #[test]
+    fn test_cbor_diagnostic() {
+        // {"t": 1(1700000000), "b": h'cafe', "f": 1.5, "x": [-1]}
+        let cbor = hex::decode("a46174c11a6553f1006162 42cafe6166f93e006178 8120".replace(' ', "")).unwrap();
+        assert_eq!(
+            cbor_diagnostic(&cbor).unwrap(),
+            r#"{"t": 1(1700000000), "b": h'cafe', "f": 1.5, "x": [-1]}"#
+        );
+    }
+}
@@ -0,0 +1,738 @@
+//! Pattern-based password strength estimate after zxcvbn: dictionary words,
+//! l33t, keyboard patterns, sequences, repeats and dates, scored by the
+//! cheapest way to guess them
+//!
+//! The dictionary is only the top-100 passwords and the 2048 BIP-39 words,
+//! far smaller than an attacker's, so guesses and crack times are upper
+//! bounds and word-like letters it can't place are flagged.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use chrono::Datelike;
+
+/// Very common passwords, most common first
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456", "password", "12345678", "qwerty", "123456789", "12345", "1234", "111111", "1234567", "dragon",
+    "123123", "baseball", "abc123", "football", "monkey", "letmein", "696969", "shadow", "master", "666666",
+    "qwertyuiop", "123321", "mustang", "1234567890", "michael", "654321", "superman", "1qaz2wsx", "7777777",
+    "121212", "000000", "qazwsx", "123qwe", "killer", "trustno1", "jordan", "jennifer", "zxcvbnm", "asdfgh",
+    "hunter", "buster", "soccer", "harley", "batman", "andrew", "tigger", "sunshine", "iloveyou", "charlie",
+    "robert", "thomas", "hockey", "ranger", "daniel", "starwars", "112233", "george", "computer", "michelle",
+    "jessica", "pepper", "1111", "zxcvbn", "555555", "11111111", "131313", "freedom", "777777", "pass",
+    "maggie", "159753", "aaaaaa", "ginger", "princess", "joshua", "cheese", "amanda", "summer", "love",
+    "ashley", "nicole", "chelsea", "biteme", "matthew", "access", "yankees", "987654321", "dallas", "austin",
+    "thunder", "taylor", "matrix", "welcome", "admin", "login", "passw0rd", "password1", "qwerty123",
+    "1q2w3e4r", "secret", "whatever", "hello", "ninja", "azerty", "solo", "flower", "lovely", "monday",
+];
+
+/// Guesses per second for each attack
+const SCENARIOS: [(&str, f64); 4] = [
+    ("Online, throttled", 100.0 / 3600.0),
+    ("Online, unthrottled", 10.0),
+    ("Offline, slow hash", 1e4),
+    ("Offline, fast hash", 1e10),
+];
+
+/// Common l33t substitutions and the letters they stand for
+const LEET: [(char, &str); 18] = [
+    ('4', "a"), ('@', "a"), ('8', "b"), ('(', "c"), ('{', "c"), ('3', "e"), ('6', "g"), ('9', "g"),
+    ('1', "il"), ('!', "i"), ('|', "il"), ('0', "o"), ('$', "s"), ('5', "s"), ('7', "t"), ('+', "t"),
+    ('2', "z"), ('%', "x"),
+];
+
+/// QWERTY rows: unshifted, shifted, and horizontal offset of the first key
+const KEYBOARD: [(&str, &str, f64); 4] = [
+    ("`1234567890-=", "~!@#$%^&*()_+", 0.0),
+    ("qwertyuiop[]\\", "QWERTYUIOP{}|", 1.5),
+    ("asdfghjkl;'", "ASDFGHJKL:\"", 1.75),
+    ("zxcvbnm,./", "ZXCVBNM<>?", 2.25),
+];
+const KEYBOARD_KEYS: f64 = 94.0;
+const KEYBOARD_DEGREE: f64 = 4.6;
+const BRUTEFORCE_CARDINALITY: f64 = 10.0;
+/// Guesses for a word missing from the dictionary: a large wordlist with common mangling
+const UNLISTED_WORD_GUESSES: f64 = 1e6;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Dictionary { rank: usize, common: bool, l33t: bool, reversed: bool, word: String },
+    Spatial { turns: usize },
+    Sequence,
+    Repeat { block: String },
+    Date { year_only: bool },
+    Bruteforce,
+}
+
+/// One piece of the cheapest way to guess a password
+#[derive(Debug, Clone)]
+pub struct Match {
+    pub token: String,
+    pub guesses: f64,
+    i: usize,
+    j: usize,
+    kind: Kind,
+}
+
+impl Match {
+    pub fn pattern(&self) -> &'static str {
+        match self.kind {
+            Kind::Dictionary { .. } => "dictionary",
+            Kind::Spatial { .. } => "keyboard",
+            Kind::Sequence => "sequence",
+            Kind::Repeat { .. } => "repeat",
+            Kind::Date { .. } => "date",
+            Kind::Bruteforce => "bruteforce",
+        }
+    }
+
+    /// Short description of what matched
+    pub fn detail(&self) -> String {
+        match &self.kind {
+            Kind::Dictionary { rank, common, l33t, reversed, word } => {
+                let mut detail = if *common { format!("common password #{}", rank) } else { format!("English word \"{}\"", word) };
+                if *l33t {
+                    detail.push_str(", l33t");
+                }
+                if *reversed {
+                    detail.push_str(", reversed");
+                }
+                detail
+            }
+            Kind::Spatial { turns } => format!("{} turn{}", turns, if *turns == 1 { "" } else { "s" }),
+            Kind::Repeat { block } => format!("\"{}\" repeated", block),
+            Kind::Date { year_only: true } => "year".to_string(),
+            _ => String::new(),
+        }
+    }
+}
+
+/// Estimated strength of a password
+#[derive(Debug, Clone)]
+pub struct Strength {
+    pub guesses: f64,
+    /// 0 (too guessable) to 4 (very unguessable)
+    pub score: u8,
+    /// Theoretical entropy: length × log2(charset size)
+    pub entropy: f64,
+    /// Attack scenario and the time it needs
+    pub crack_times: Vec<(&'static str, String)>,
+    pub matches: Vec<Match>,
+    pub warning: Option<&'static str>,
+    pub suggestions: Vec<&'static str>,
+}
+
+impl Strength {
+    pub fn label(&self) -> &'static str {
+        ["very weak", "weak", "fair", "strong", "very strong"][self.score as usize]
+    }
+
+    /// Crack time in the offline fast-hash scenario (an upper bound)
+    pub fn offline_time(&self) -> &str {
+        self.crack_times.last().map_or("", |(_, time)| time.as_str())
+    }
+
+    /// Aligned `Label: value` lines plus the match breakdown
+    pub fn summary(&self) -> String {
+        let mut lines = vec![
+            format!("{:<22} {}/4 ({})", "Score:", self.score, self.label()),
+            format!("{:<22} 10^{:.1}", "Guesses:", self.guesses.max(1.0).log10()),
+            format!("{:<22} {:.1} bits", "Charset entropy:", self.entropy),
+        ];
+        lines.push("Crack time, at most:".to_string());
+        lines.extend(self.crack_times.iter().map(|(scenario, time)| format!("  {:<20} {}", format!("{}:", scenario), time)));
+        if let Some(warning) = self.warning {
+            lines.push(format!("{:<22} {}", "Warning:", warning));
+        }
+        for suggestion in &self.suggestions {
+            lines.push(format!("{:<22} {}", "Suggestion:", suggestion));
+        }
+        for m in &self.matches {
+            let detail = m.detail();
+            let detail = if detail.is_empty() { String::new() } else { format!(" ({})", detail) };
+            lines.push(format!("  {:<20} {}{}, 10^{:.1} guesses", format!("\"{}\"", m.token), m.pattern(), detail, m.guesses.log10()));
+        }
+        lines.join("\n")
+    }
+}
+
+// ============================================================================
+// MATCHING
+// ============================================================================
+
+/// Lowercase word → (rank, is a common password)
+fn dictionary() -> &'static HashMap<String, (usize, bool)> {
+    static DICTIONARY: OnceLock<HashMap<String, (usize, bool)>> = OnceLock::new();
+    DICTIONARY.get_or_init(|| {
+        let mut map: HashMap<String, (usize, bool)> =
+            COMMON_PASSWORDS.iter().enumerate().map(|(i, w)| (w.to_string(), (i + 1, true))).collect();
+        let words = super::generate::default_wordlist();
+        let rank = words.len();
+        for word in words {
+            map.entry(word.to_string()).or_insert((rank, false));
+        }
+        map
+    })
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+    (0..k.min(n - k)).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+fn factorial(n: usize) -> f64 {
+    (1..=n).fold(1.0, |acc, i| acc * i as f64)
+}
+
+/// How many capitalizations of a word an attacker tries before this one
+fn uppercase_variations(token: &[char]) -> f64 {
+    let upper = token.iter().filter(|c| c.is_uppercase()).count();
+    let lower = token.iter().filter(|c| c.is_lowercase()).count();
+    let first_only = token.first().is_some_and(|c| c.is_uppercase()) && upper == 1;
+    let last_only = token.last().is_some_and(|c| c.is_uppercase()) && upper == 1;
+    match (upper, lower) {
+        (0, _) => 1.0,
+        (_, 0) => 2.0,
+        _ if first_only || last_only => 2.0,
+        _ => (1..=upper.min(lower)).map(|i| binomial(upper + lower, i)).sum(),
+    }
+}
+
+/// Lowercase token with l33t characters replaced; `1` and `|` may read as `i` or `l`
+fn unleet(token: &[char]) -> Vec<(String, bool)> {
+    let mut variants = vec![(String::new(), false), (String::new(), false)];
+    for &c in token {
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        let letters = LEET.iter().find(|(l, _)| *l == c).map(|(_, letters)| *letters);
+        for (v, (text, subbed)) in variants.iter_mut().enumerate() {
+            match letters {
+                Some(letters) => {
+                    let mut chars = letters.chars();
+                    let first = chars.next().unwrap_or(lower);
+                    text.push(if v == 1 { chars.next().unwrap_or(first) } else { first });
+                    *subbed = true;
+                }
+                None => text.push(lower),
+            }
+        }
+    }
+    variants.dedup();
+    variants
+}
+
+fn dictionary_matches(chars: &[char], matches: &mut Vec<Match>) {
+    let dict = dictionary();
+    for i in 0..chars.len() {
+        for j in i + 2..chars.len().min(i + 20) {
+            let token = &chars[i..=j];
+            let lower: String = token.iter().flat_map(|c| c.to_lowercase()).collect();
+            let reversed: String = lower.chars().rev().collect();
+            let mut candidates = vec![(lower, false, false), (reversed, false, true)];
+            candidates.extend(unleet(token).into_iter().filter(|(_, subbed)| *subbed).map(|(word, _)| (word, true, false)));
+            let best = candidates
+                .into_iter()
+                .filter_map(|(word, l33t, reversed)| {
+                    let (rank, common) = *dict.get(&word)?;
+                    let mut guesses = rank as f64 * uppercase_variations(token);
+                    if l33t {
+                        let subs = token.iter().filter(|c| LEET.iter().any(|(l, _)| l == *c)).count();
+                        guesses *= 2f64.powi(subs as i32);
+                    }
+                    if reversed {
+                        guesses *= 2.0;
+                    }
+                    Some((guesses, Kind::Dictionary { rank, common, l33t, reversed, word }))
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+            if let Some((guesses, kind)) = best {
+                matches.push(Match { token: token.iter().collect(), guesses, i, j, kind });
+            }
+        }
+    }
+}
+
+/// Row, horizontal position and shift state of a key
+fn key_position(c: char) -> Option<(i32, f64, bool)> {
+    KEYBOARD.iter().enumerate().find_map(|(row, (plain, shifted, offset))| {
+        let plain_col = plain.chars().position(|k| k == c);
+        let shift_col = shifted.chars().position(|k| k == c);
+        plain_col.or(shift_col).map(|col| (row as i32, col as f64 + offset, shift_col.is_some()))
+    })
+}
+
+fn spatial_matches(chars: &[char], matches: &mut Vec<Match>) {
+    let keys: Vec<Option<(i32, f64, bool)>> = chars.iter().map(|&c| key_position(c)).collect();
+    for i in 0..chars.len() {
+        let mut j = i;
+        let mut turns = 0;
+        let mut last_dir = None;
+        while j + 1 < chars.len() {
+            let (Some((r1, x1, _)), Some((r2, x2, _))) = (keys[j], keys[j + 1]) else { break };
+            let dx = x2 - x1;
+            let adjacent = (r1 == r2 && (dx.abs() - 1.0).abs() < 1e-9) || ((r1 - r2).abs() == 1 && dx.abs() <= 0.75);
+            if !adjacent {
+                break;
+            }
+            let dir = (r2 - r1, dx.signum() as i32);
+            if last_dir != Some(dir) {
+                turns += 1;
+                last_dir = Some(dir);
+            }
+            j += 1;
+        }
+        if j - i + 1 < 3 {
+            continue;
+        }
+        let len = j - i + 1;
+        let mut guesses = 0.0;
+        for l in 2..=len {
+            for t in 1..=turns.min(l - 1) {
+                guesses += binomial(l - 1, t - 1) * KEYBOARD_KEYS * KEYBOARD_DEGREE.powi(t as i32);
+            }
+        }
+        let shifted = keys[i..=j].iter().filter(|k| k.is_some_and(|(_, _, s)| s)).count();
+        if shifted == len {
+            guesses *= 2.0;
+        } else if shifted > 0 {
+            guesses *= (1..=shifted.min(len - shifted)).map(|k| binomial(len, k)).sum::<f64>();
+        }
+        matches.push(Match { token: chars[i..=j].iter().collect(), guesses, i, j, kind: Kind::Spatial { turns } });
+    }
+}
+
+fn sequence_matches(chars: &[char], matches: &mut Vec<Match>) {
+    let class = |c: char| {
+        if c.is_ascii_lowercase() {
+            1
+        } else if c.is_ascii_uppercase() {
+            2
+        } else if c.is_ascii_digit() {
+            3
+        } else {
+            0
+        }
+    };
+    let mut i = 0;
+    while i + 2 < chars.len() {
+        let delta = chars[i + 1] as i32 - chars[i] as i32;
+        let mut j = i + 1;
+        let same_class = |a: char, b: char| class(a) != 0 && class(a) == class(b);
+        if (1..=5).contains(&delta.abs()) && same_class(chars[i], chars[j]) {
+            while j + 1 < chars.len() && chars[j + 1] as i32 - chars[j] as i32 == delta && same_class(chars[j], chars[j + 1]) {
+                j += 1;
+            }
+        }
+        if j - i + 1 >= 3 {
+            let base = match chars[i] {
+                'a' | 'z' | 'A' | 'Z' | '0' | '1' | '9' => 4.0,
+                c if c.is_ascii_digit() => 10.0,
+                _ => 26.0,
+            };
+            let guesses = base * (j - i + 1) as f64 * if delta < 0 { 2.0 } else { 1.0 };
+            matches.push(Match { token: chars[i..=j].iter().collect(), guesses, i, j, kind: Kind::Sequence });
+            i = j;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+fn repeat_matches(chars: &[char], matches: &mut Vec<Match>) {
+    for i in 0..chars.len() {
+        let mut best: Option<(usize, usize)> = None;
+        for block in 1..=(chars.len() - i) / 2 {
+            let mut reps = 1;
+            while i + (reps + 1) * block <= chars.len()
+                && chars[i + reps * block..i + (reps + 1) * block] == chars[i..i + block]
+            {
+                reps += 1;
+            }
+            if reps >= 2 && block * reps >= 3 && best.map_or(true, |(b, r)| block * reps > b * r) {
+                best = Some((block, reps));
+            }
+        }
+        if let Some((block, reps)) = best {
+            let block_chars = &chars[i..i + block];
+            let base = most_guessable(block_chars).0.max(BRUTEFORCE_CARDINALITY + 1.0);
+            let j = i + block * reps - 1;
+            let kind = Kind::Repeat { block: block_chars.iter().collect() };
+            matches.push(Match { token: chars[i..=j].iter().collect(), guesses: base * reps as f64, i, j, kind });
+        }
+    }
+}
+
+/// Guesses for a date in `year`, relative to the current year
+fn year_space(year: i32) -> f64 {
+    ((year - chrono::Utc::now().year()).abs() as f64).max(20.0)
+}
+
+fn full_year(year: u32, digits: usize) -> Option<i32> {
+    let year = match (digits, year) {
+        (2, y) if y > 50 => 1900 + y as i32,
+        (2, y) => 2000 + y as i32,
+        (4, y) => y as i32,
+        _ => return None,
+    };
+    (1900..=2049).contains(&year).then_some(year)
+}
+
+/// Year of a day-month-year reading of three numeric parts, trying y-m-d, d-m-y and m-d-y
+fn date_year(parts: &[&str]) -> Option<i32> {
+    let num = |s: &str| s.parse::<u32>().ok();
+    let valid = |d: u32, m: u32| (1..=31).contains(&d) && (1..=12).contains(&m);
+    let [a, b, c] = parts else { return None };
+    let (na, nb, nc) = (num(a)?, num(b)?, num(c)?);
+    if valid(nc, nb) {
+        if let Some(year) = full_year(na, a.len()) {
+            return Some(year);
+        }
+    }
+    if valid(na, nb) || valid(nb, na) {
+        return full_year(nc, c.len());
+    }
+    None
+}
+
+fn date_matches(chars: &[char], matches: &mut Vec<Match>) {
+    for i in 0..chars.len() {
+        for j in i + 3..chars.len().min(i + 10) {
+            let token: String = chars[i..=j].iter().collect();
+            let len = j - i + 1;
+            let (year, separated, year_only) = if token.chars().all(|c| c.is_ascii_digit()) {
+                match len {
+                    4 => (token.parse().ok().filter(|y| (1900..=2049).contains(y)), false, true),
+                    6 => (date_year(&[&token[..2], &token[2..4], &token[4..]]), false, false),
+                    8 => (
+                        date_year(&[&token[..4], &token[4..6], &token[6..]])
+                            .or_else(|| date_year(&[&token[..2], &token[2..4], &token[4..]])),
+                        false,
+                        false,
+                    ),
+                    _ => continue,
+                }
+            } else {
+                let Some(sep) = token.chars().find(|c| !c.is_ascii_digit()) else { continue };
+                if !" -/._\\".contains(sep) || !token.chars().all(|c| c.is_ascii_digit() || c == sep) {
+                    continue;
+                }
+                let parts: Vec<&str> = token.split(sep).collect();
+                if parts.len() != 3 || parts.iter().any(|p| p.is_empty()) {
+                    continue;
+                }
+                (date_year(&parts), true, false)
+            };
+            let Some(year) = year else { continue };
+            let mut guesses = year_space(year);
+            if !year_only {
+                guesses *= 365.0;
+            }
+            if separated {
+                guesses *= 4.0;
+            }
+            matches.push(Match { token, guesses, i, j, kind: Kind::Date { year_only } });
+        }
+    }
+}
+
+/// Minimum guesses over all ways to split `chars` into matches and brute-force runs, as zxcvbn does
+fn most_guessable(chars: &[char]) -> (f64, Vec<Match>) {
+    let n = chars.len();
+    if n == 0 {
+        return (1.0, Vec::new());
+    }
+    let mut matches = Vec::new();
+    dictionary_matches(chars, &mut matches);
+    spatial_matches(chars, &mut matches);
+    sequence_matches(chars, &mut matches);
+    repeat_matches(chars, &mut matches);
+    date_matches(chars, &mut matches);
+    for m in matches.iter_mut() {
+        if m.j - m.i + 1 < n {
+            m.guesses = m.guesses.max(if m.i == m.j { 10.0 } else { 50.0 });
+        }
+    }
+    let bruteforce = |i: usize, j: usize| {
+        let guesses = BRUTEFORCE_CARDINALITY.powi((j - i + 1) as i32).max(if i == j { 11.0 } else { 51.0 });
+        Match { token: chars[i..=j].iter().collect(), guesses, i, j, kind: Kind::Bruteforce }
+    };
+
+    // Per end position: sequence length → (product of guesses, total guesses, match)
+    let mut optimal: Vec<HashMap<usize, (f64, f64, Match)>> = vec![HashMap::new(); n];
+    let update = |optimal: &mut Vec<HashMap<usize, (f64, f64, Match)>>, m: Match, l: usize, pi: f64| {
+        let k = m.j;
+        let g = factorial(l) * pi + 10000f64.powi(l as i32 - 1);
+        if optimal[k].iter().any(|(&other_l, &(_, other_g, _))| other_l <= l && other_g <= g) {
+            return;
+        }
+        optimal[k].insert(l, (pi, g, m));
+    };
+    for k in 0..n {
+        for m in matches.iter().filter(|m| m.j == k) {
+            if m.i == 0 {
+                update(&mut optimal, m.clone(), 1, m.guesses);
+            } else {
+                let prev: Vec<(usize, f64)> = optimal[m.i - 1].iter().map(|(&l, &(pi, _, _))| (l, pi)).collect();
+                for (l, pi) in prev {
+                    update(&mut optimal, m.clone(), l + 1, pi * m.guesses);
+                }
+            }
+        }
+        let m = bruteforce(0, k);
+        let guesses = m.guesses;
+        update(&mut optimal, m, 1, guesses);
+        for i in 1..=k {
+            let prev: Vec<(usize, f64)> = optimal[i - 1]
+                .iter()
+                .filter(|(_, (_, _, last))| last.kind != Kind::Bruteforce)
+                .map(|(&l, &(pi, _, _))| (l, pi))
+                .collect();
+            for (l, pi) in prev {
+                let m = bruteforce(i, k);
+                let guesses = m.guesses;
+                update(&mut optimal, m, l + 1, pi * guesses);
+            }
+        }
+    }
+
+    let Some((&best_l, &(_, guesses, _))) = optimal[n - 1].iter().min_by(|a, b| a.1 .1.total_cmp(&b.1 .1)) else {
+        return (BRUTEFORCE_CARDINALITY.powi(n as i32), Vec::new());
+    };
+    let mut sequence = Vec::new();
+    let (mut k, mut l) = (n - 1, best_l);
+    loop {
+        let m = optimal[k][&l].2.clone();
+        let start = m.i;
+        sequence.push(m);
+        if start == 0 || l == 1 {
+            break;
+        }
+        k = start - 1;
+        l -= 1;
+    }
+    sequence.reverse();
+    (guesses, sequence)
+}
+
+// ============================================================================
+// SCORING
+// ============================================================================
+
+/// Theoretical entropy from the character classes present: length × log2(charset)
+pub fn charset_entropy(password: &str) -> f64 {
+    let has = |f: fn(&char) -> bool| password.chars().any(|c| f(&c));
+    let charset = [
+        (has(char::is_ascii_lowercase), 26),
+        (has(char::is_ascii_uppercase), 26),
+        (has(char::is_ascii_digit), 10),
+        (has(|c| c.is_ascii_punctuation() || *c == ' '), 33),
+        (has(|c| !c.is_ascii()), 100),
+    ]
+    .iter()
+    .filter(|(present, _)| *present)
+    .map(|(_, size)| size)
+    .sum();
+    super::generate::password_entropy(password.chars().count(), charset)
+}
+
+fn display_time(seconds: f64) -> String {
+    const UNITS: [(&str, f64); 6] =
+        [("second", 1.0), ("minute", 60.0), ("hour", 3600.0), ("day", 86400.0), ("month", 2_678_400.0), ("year", 31_536_000.0)];
+    if seconds < 1.0 {
+        return "less than a second".to_string();
+    }
+    if seconds >= 100.0 * 31_536_000.0 {
+        return "centuries".to_string();
+    }
+    let (unit, size) = UNITS.iter().rev().find(|(_, size)| seconds >= *size).copied().unwrap_or(UNITS[0]);
+    let n = (seconds / size).round() as u64;
+    format!("{} {}{}", n, unit, if n == 1 { "" } else { "s" })
+}
+
+/// Length of the longest pronounceable letter run (after undoing l33t): maybe a word the dictionary lacks
+fn word_like_len(token: &str) -> usize {
+    let chars: Vec<char> = token.chars().collect();
+    unleet(&chars)
+        .iter()
+        .flat_map(|(text, _)| {
+            text.split(|c: char| !c.is_ascii_lowercase())
+                .filter(|run| run.chars().filter(|c| "aeiouy".contains(*c)).count() * 10 >= run.len() * 3)
+                .map(str::len)
+                .collect::<Vec<_>>()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn feedback(score: u8, matches: &[Match], unlisted: bool) -> (Option<&'static str>, Vec<&'static str>) {
+    if matches.is_empty() {
+        return (None, vec!["Use a few words, avoid common phrases", "No need for symbols, digits, or uppercase letters"]);
+    }
+    if score > 2 {
+        if unlisted {
+            return (
+                Some("Parts may be words outside the small built-in dictionary"),
+                vec!["Treat the score and crack times as an upper bound"],
+            );
+        }
+        return (None, Vec::new());
+    }
+    let Some(longest) = matches.iter().max_by_key(|m| m.j - m.i) else {
+        return (None, Vec::new());
+    };
+    let mut suggestions = vec!["Add another word or two. Uncommon words are better."];
+    let warning = match &longest.kind {
+        Kind::Dictionary { rank, common, l33t, reversed, .. } => {
+            let token: Vec<char> = longest.token.chars().collect();
+            if token.first().is_some_and(|c| c.is_uppercase()) && uppercase_variations(&token) <= 2.0 {
+                suggestions.push("Capitalization doesn't help very much");
+            } else if token.iter().all(|c| !c.is_lowercase()) && token.iter().any(|c| c.is_uppercase()) {
+                suggestions.push("All-uppercase is almost as easy to guess as all-lowercase");
+            }
+            if *reversed {
+                suggestions.push("Reversed words aren't much harder to guess");
+            }
+            if *l33t {
+                suggestions.push("Predictable substitutions like '@' instead of 'a' don't help very much");
+            }
+            match (common, rank) {
+                (true, 1..=10) => Some("This is a top-10 common password"),
+                (true, 11..=100) => Some("This is a top-100 common password"),
+                (true, _) => Some("This is a very common password"),
+                (false, _) if matches.len() == 1 => Some("A word by itself is easy to guess"),
+                _ => None,
+            }
+        }
+        Kind::Spatial { turns } => {
+            suggestions.push("Use a longer keyboard pattern with more turns");
+            Some(if *turns == 1 { "Straight rows of keys are easy to guess" } else { "Short keyboard patterns are easy to guess" })
+        }
+        Kind::Repeat { block } => {
+            suggestions.push("Avoid repeated words and characters");
+            Some(if block.chars().count() == 1 {
+                "Repeats like \"aaa\" are easy to guess"
+            } else {
+                "Repeats like \"abcabcabc\" are only slightly harder to guess than \"abc\""
+            })
+        }
+        Kind::Sequence => {
+            suggestions.push("Avoid sequences");
+            Some("Sequences like abc or 6543 are easy to guess")
+        }
+        Kind::Date { year_only } => {
+            suggestions.push("Avoid dates and years that are associated with you");
+            Some(if *year_only { "Recent years are easy to guess" } else { "Dates are often easy to guess" })
+        }
+        Kind::Bruteforce => None,
+    };
+    (warning, suggestions)
+}
+
+/// Estimate guesses, score, crack times and feedback for a password
+pub fn estimate(password: &str) -> Strength {
+    let chars: Vec<char> = password.chars().take(256).collect();
+    let (guesses, matches) = most_guessable(&chars);
+    let score = match guesses {
+        g if g < 1e3 + 5.0 => 0,
+        g if g < 1e6 + 5.0 => 1,
+        g if g < 1e8 + 5.0 => 2,
+        g if g < 1e10 + 5.0 => 3,
+        _ => 4,
+    };
+    // Brute-forced letters may still be an ordinary word: price the longest such run as one
+    // from a large wordlist and don't promise "very strong" if that would undercut it
+    let unlisted = matches.iter().filter(|m| m.kind == Kind::Bruteforce).any(|m| {
+        let run = BRUTEFORCE_CARDINALITY.powi(word_like_len(&m.token) as i32);
+        run > UNLISTED_WORD_GUESSES && guesses / run * UNLISTED_WORD_GUESSES < 1e10 + 5.0
+    });
+    let score = if unlisted { score.min(3) } else { score };
+    let crack_times = SCENARIOS.iter().map(|(scenario, rate)| (*scenario, display_time(guesses / rate))).collect();
+    let (warning, suggestions) = feedback(score, &matches, unlisted);
+    Strength { guesses, score, entropy: charset_entropy(password), crack_times, matches, warning, suggestions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weak_patterns() {
+        for (password, pattern) in [
+            ("password", "dictionary"),
+            ("P@ssw0rd", "dictionary"),
+            ("qwertyui", "keyboard"),
+            ("abcdefgh", "sequence"),
+            ("aaaaaaaa", "repeat"),
+            ("1987-06-15", "date"),
+        ] {
+            let strength = estimate(password);
+            assert!(strength.score <= 1, "{} scored {}", password, strength.score);
+            assert_eq!(strength.matches[0].pattern(), pattern, "{}", password);
+            assert!(strength.warning.is_some(), "{}", password);
+        }
+        assert_eq!(estimate("password").warning, Some("This is a top-10 common password"));
+        assert!(estimate("drowssap").matches[0].detail().contains("reversed"));
+    }
+
+    #[test]
+    fn test_strong_and_entropy() {
+        let strength = estimate("correct horse battery staple 9!");
+        assert_eq!(strength.score, 4);
+        assert_eq!(strength.offline_time(), "centuries");
+        assert!(strength.warning.is_none());
+        assert!((charset_entropy("abc123") - 6.0 * 36f64.log2()).abs() < 1e-9);
+        assert_eq!(estimate("").score, 0);
+    }
+
+    #[test]
+    fn test_words_outside_dictionary() {
+        let strength = estimate("Tr0ub4dor&3");
+        assert_eq!(strength.score, 3);
+        assert_eq!(strength.warning, Some("Parts may be words outside the small built-in dictionary"));
+        let strength = estimate("correcthorsebatterystaple");
+        for word in ["correct", "horse"] {
+            assert!(strength.matches.iter().any(|m| m.detail() == format!("English word \"{}\"", word)), "{}", word);
+        }
+        assert_eq!(word_like_len("Tr0ub4dor&3"), 9);
+        assert_eq!(word_like_len("xq7zk"), 0);
+    }
+
+    #[test]
+    fn test_matchers() {
+        let chars = |s: &str| s.chars().collect::<Vec<char>>();
+        let mut matches = Vec::new();
+        spatial_matches(&chars("qwerty"), &mut matches);
+        assert_eq!(matches[0].detail(), "1 turn");
+        matches.clear();
+        spatial_matches(&chars("zxcvfr"), &mut matches);
+        assert_eq!(matches[0].detail(), "2 turns");
+        matches.clear();
+        sequence_matches(&chars("9876"), &mut matches);
+        sequence_matches(&chars("abcd"), &mut matches);
+        assert_eq!(matches.iter().map(|m| m.guesses).collect::<Vec<_>>(), [32.0, 16.0]);
+        assert_eq!(estimate("abcabcabc").matches[0].detail(), "\"abc\" repeated");
+        assert_eq!(estimate("15.06.1987").matches[0].pattern(), "date");
+        assert_eq!(estimate("1987").matches[0].detail(), "year");
+        assert_eq!(date_year(&["1987", "06", "15"]), Some(1987));
+        assert_eq!(date_year(&["15", "06", "87"]), Some(1987));
+        assert_eq!(date_year(&["06", "15", "1987"]), Some(1987));
+        assert_eq!(date_year(&["13", "13", "87"]), None);
+        assert_eq!(unleet(&chars("p4$$")), [("pass".to_string(), true)]);
+        assert_eq!(unleet(&chars("1")), [("i".to_string(), true), ("l".to_string(), true)]);
+    }
+
+    #[test]
+    fn test_variations_and_display() {
+        let chars = |s: &str| s.chars().collect::<Vec<char>>();
+        assert_eq!(uppercase_variations(&chars("password")), 1.0);
+        assert_eq!(uppercase_variations(&chars("Password")), 2.0);
+        assert_eq!(uppercase_variations(&chars("PASSWORD")), 2.0);
+        assert_eq!(uppercase_variations(&chars("PassWord")), 36.0);
+        assert_eq!(display_time(0.5), "less than a second");
+        assert_eq!(display_time(1.0), "1 second");
+        assert_eq!(display_time(90.0), "2 minutes");
+        assert_eq!(display_time(3600.0), "1 hour");
+        assert_eq!(display_time(1e10), "centuries");
+    }
+}
@@ -0,0 +1,404 @@
+//! Text data formats: JSON formatting, YAML, TOML, XML and CSV via JSON
+
+use serde_json::{Map, Value};
+
+/// Line and column (1-based) of a byte offset in `text`
+fn line_col(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..offset.min(text.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+    (line, column)
+}
+
+// ============================================================================
+// JSON FORMATTING
+// ============================================================================
+
+/// JSON output options
+#[derive(Debug, Clone, Copy)]
+pub struct FmtOptions {
+    /// Single line, no whitespace
+    pub minify: bool,
+    /// Sort object keys recursively
+    pub sort_keys: bool,
+    /// Spaces per indent level when pretty-printing
+    pub indent: usize,
+}
+
+impl Default for FmtOptions {
+    fn default() -> Self {
+        FmtOptions { minify: false, sort_keys: false, indent: 2 }
+    }
+}
+
+/// Parse JSON, reporting the line and column of any error
+pub fn parse_json(text: &str) -> Result<Value, String> {
+    serde_json::from_str(text).map_err(|e| format!("JSON parse error: {}", e))
+}
+
+/// Sort object keys recursively
+pub fn sort_keys(value: &mut Value) {
+    match value {
+        Value::Object(obj) => {
+            let mut entries: Vec<(String, Value)> = std::mem::take(obj).into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            for (key, mut v) in entries {
+                sort_keys(&mut v);
+                obj.insert(key, v);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(sort_keys),
+        _ => {}
+    }
+}
+
+/// Serialize JSON pretty-printed or minified
+pub fn write_json(value: &Value, options: FmtOptions) -> String {
+    let mut value = value.clone();
+    if options.sort_keys {
+        sort_keys(&mut value);
+    }
+    if options.minify {
+        return value.to_string();
+    }
+    let indent = " ".repeat(options.indent);
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+    let mut ser = serde_json::Serializer::with_formatter(&mut out, formatter);
+    serde::Serialize::serialize(&value, &mut ser).expect("serializing a JSON value cannot fail");
+    String::from_utf8(out).unwrap_or_default()
+}
+
+/// Pretty-print or minify JSON text
+pub fn format_json(text: &str, options: FmtOptions) -> Result<String, String> {
+    parse_json(text).map(|v| write_json(&v, options))
+}
+
+// ============================================================================
+// YAML & TOML
+// ============================================================================
+
+/// Parse YAML into JSON
+pub fn yaml_to_json(text: &str) -> Result<Value, String> {
+    serde_yaml::from_str(text).map_err(|e| format!("YAML parse error: {}", e))
+}
+
+/// Serialize JSON as YAML
+pub fn json_to_yaml(value: &Value) -> Result<String, String> {
+    serde_yaml::to_string(value).map_err(|e| format!("YAML error: {}", e))
+}
+
+fn toml_value_to_json(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::from(i),
+        toml::Value::Float(f) => serde_json::Number::from_f64(f).map_or(Value::Null, Value::Number),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(d) => Value::String(d.to_string()),
+        toml::Value::Array(items) => Value::Array(items.into_iter().map(toml_value_to_json).collect()),
+        toml::Value::Table(table) => {
+            Value::Object(table.into_iter().map(|(k, v)| (k, toml_value_to_json(v))).collect())
+        }
+    }
+}
+
+/// Parse TOML into JSON (dates become strings)
+pub fn toml_to_json(text: &str) -> Result<Value, String> {
+    text.parse::<toml::Table>()
+        .map(|t| toml_value_to_json(toml::Value::Table(t)))
+        .map_err(|e| format!("TOML parse error: {}", e.to_string().trim_end()))
+}
+
+/// Serialize JSON as TOML (needs a top-level object and no nulls)
+pub fn json_to_toml(value: &Value) -> Result<String, String> {
+    if !value.is_object() {
+        return Err("TOML needs a JSON object at the top level".to_string());
+    }
+    toml::to_string_pretty(value).map_err(|e| format!("TOML error: {}", e))
+}
+
+// ============================================================================
+// XML
+// ============================================================================
+
+/// Add a child under `key`, turning repeated keys into arrays
+fn push_child(obj: &mut Map<String, Value>, key: String, value: Value) {
+    match obj.get_mut(&key) {
+        Some(Value::Array(items)) => items.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+        None => {
+            obj.insert(key, value);
+        }
+    }
+}
+
+/// Collapse `{"#text": "x"}` to `"x"` and empty elements to `null`
+fn finish_element(obj: Map<String, Value>) -> Value {
+    if obj.is_empty() {
+        Value::Null
+    } else if obj.len() == 1 && obj.contains_key("#text") {
+        obj.into_iter().next().map(|(_, v)| v).unwrap_or(Value::Null)
+    } else {
+        Value::Object(obj)
+    }
+}
+
+/// Parse XML into JSON
+///
+/// Elements become objects keyed by child name (repeated names become
+/// arrays), attributes are `@name` and text content is `#text`.
+pub fn xml_to_json(text: &str) -> Result<Value, String> {
+    use quick_xml::events::Event;
+
+    let mut reader = quick_xml::Reader::from_str(text);
+    reader.config_mut().trim_text(true);
+    let err = |reader: &quick_xml::Reader<&[u8]>, e: &dyn std::fmt::Display| {
+        let (line, col) = line_col(text, reader.error_position() as usize);
+        format!("XML parse error at line {}, column {}: {}", line, col, e)
+    };
+    let name_of = |raw: &[u8]| String::from_utf8_lossy(raw).into_owned();
+
+    // Open elements: (name, object under construction)
+    let mut stack: Vec<(String, Map<String, Value>)> = vec![(String::new(), Map::new())];
+    loop {
+        let event = reader.read_event().map_err(|e| err(&reader, &e))?;
+        let is_empty = matches!(event, Event::Empty(_));
+        match event {
+            Event::Start(e) | Event::Empty(e) => {
+                let mut obj = Map::new();
+                for attr in e.attributes() {
+                    let attr = attr.map_err(|e| err(&reader, &e))?;
+                    let value = attr.unescape_value().map_err(|e| err(&reader, &e))?;
+                    obj.insert(format!("@{}", name_of(attr.key.as_ref())), Value::String(value.into_owned()));
+                }
+                let name = name_of(e.name().as_ref());
+                if is_empty {
+                    if let Some((_, parent)) = stack.last_mut() {
+                        push_child(parent, name, finish_element(obj));
+                    }
+                } else {
+                    stack.push((name, obj));
+                }
+            }
+            Event::End(_) => {
+                let (name, obj) = stack.pop().ok_or("XML parse error: unbalanced end tag")?;
+                if let Some((_, parent)) = stack.last_mut() {
+                    push_child(parent, name, finish_element(obj));
+                }
+            }
+            Event::Text(t) => {
+                let text = t.unescape().map_err(|e| err(&reader, &e))?;
+                if let Some((_, obj)) = stack.last_mut() {
+                    push_child(obj, "#text".to_string(), Value::String(text.into_owned()));
+                }
+            }
+            Event::CData(t) => {
+                if let Some((_, obj)) = stack.last_mut() {
+                    push_child(obj, "#text".to_string(), Value::String(name_of(&t)));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    if stack.len() != 1 {
+        return Err(format!("XML parse error: <{}> is never closed", stack[stack.len() - 1].0));
+    }
+    Ok(Value::Object(stack.pop().map(|(_, obj)| obj).unwrap_or_default()))
+}
+
+fn xml_scalar(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn write_element(name: &str, value: &Value, depth: usize, out: &mut String) {
+    let pad = "  ".repeat(depth);
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                write_element(name, item, depth, out);
+            }
+        }
+        Value::Object(obj) => {
+            out.push_str(&format!("{}<{}", pad, name));
+            for (key, v) in obj.iter().filter(|(k, _)| k.starts_with('@')) {
+                let escaped = html_escape::encode_double_quoted_attribute(&xml_scalar(v)).into_owned();
+                out.push_str(&format!(" {}=\"{}\"", &key[1..], escaped));
+            }
+            let children: Vec<(&String, &Value)> = obj.iter().filter(|(k, _)| !k.starts_with('@')).collect();
+            match children.as_slice() {
+                [] => out.push_str("/>\n"),
+                [(key, text)] if key.as_str() == "#text" => {
+                    out.push_str(&format!(">{}</{}>\n", html_escape::encode_text(&xml_scalar(text)), name));
+                }
+                _ => {
+                    out.push_str(">\n");
+                    for (key, v) in children {
+                        if key == "#text" {
+                            out.push_str(&format!("{}  {}\n", pad, html_escape::encode_text(&xml_scalar(v))));
+                        } else {
+                            write_element(key, v, depth + 1, out);
+                        }
+                    }
+                    out.push_str(&format!("{}</{}>\n", pad, name));
+                }
+            }
+        }
+        Value::Null => out.push_str(&format!("{}<{}/>\n", pad, name)),
+        scalar => out.push_str(&format!("{}<{}>{}</{}>\n", pad, name, html_escape::encode_text(&xml_scalar(scalar)), name)),
+    }
+}
+
+/// Serialize JSON as XML, the inverse of [`xml_to_json`]
+///
+/// An object with a single key names the root element; anything else is
+/// wrapped in `<root>`, with array entries as `<item>` children.
+pub fn json_to_xml(value: &Value) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    match value {
+        Value::Object(obj) if obj.len() == 1 && obj.values().next().is_some_and(|v| !v.is_array()) => {
+            let (name, inner) = obj.iter().next().expect("object has one key");
+            write_element(name, inner, 0, &mut out);
+        }
+        Value::Array(items) => {
+            let mut root = Map::new();
+            root.insert("item".to_string(), Value::Array(items.clone()));
+            write_element("root", &Value::Object(root), 0, &mut out);
+        }
+        other => write_element("root", other, 0, &mut out),
+    }
+    out.trim_end().to_string()
+}
+
+// ============================================================================
+// CSV
+// ============================================================================
+
+/// Parse CSV with a header row into an array of objects (values stay strings)
+pub fn csv_to_json(text: &str) -> Result<Value, String> {
+    let csv_err = |e: csv::Error| match e.position() {
+        Some(p) => format!("CSV parse error at line {}: {}", p.line(), e),
+        None => format!("CSV parse error: {}", e),
+    };
+    let mut reader = csv::ReaderBuilder::new().from_reader(text.as_bytes());
+    let headers = reader.headers().map_err(csv_err)?.clone();
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(csv_err)?;
+        let row: Map<String, Value> = headers
+            .iter()
+            .zip(record.iter())
+            .map(|(h, v)| (h.to_string(), Value::String(v.to_string())))
+            .collect();
+        rows.push(Value::Object(row));
+    }
+    Ok(Value::Array(rows))
+}
+
+/// Serialize an array of objects (or of arrays) as CSV
+///
+/// Headers are the union of object keys in first-seen order; nested values
+/// are written as JSON.
+pub fn json_to_csv(value: &Value) -> Result<String, String> {
+    let rows: Vec<&Value> = match value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Object(_) => vec![value],
+        _ => return Err("CSV needs an array of objects".to_string()),
+    };
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let csv_err = |e: csv::Error| format!("CSV error: {}", e);
+
+    if rows.iter().all(|r| r.is_array()) {
+        for row in &rows {
+            let cells: Vec<String> = row.as_array().into_iter().flatten().map(xml_scalar).collect();
+            writer.write_record(&cells).map_err(csv_err)?;
+        }
+    } else {
+        let mut headers: Vec<&str> = Vec::new();
+        for row in &rows {
+            let obj = row.as_object().ok_or("CSV rows must all be objects or all be arrays")?;
+            for key in obj.keys() {
+                if !headers.contains(&key.as_str()) {
+                    headers.push(key);
+                }
+            }
+        }
+        writer.write_record(&headers).map_err(csv_err)?;
+        for row in &rows {
+            let cells: Vec<String> = headers.iter().map(|h| row.get(*h).map(xml_scalar).unwrap_or_default()).collect();
+            writer.write_record(&cells).map_err(csv_err)?;
+        }
+    }
+    let bytes = writer.into_inner().map_err(|e| format!("CSV error: {}", e))?;
+    Ok(String::from_utf8_lossy(&bytes).trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_json() {
+        let text = r#"{"b": [1, 2], "a": {"d": 1, "c": null}}"#;
+        let minified = FmtOptions { minify: true, sort_keys: true, indent: 2 };
+        assert_eq!(format_json(text, minified).unwrap(), r#"{"a":{"c":null,"d":1},"b":[1,2]}"#);
+        let pretty = format_json(r#"{"a":[1]}"#, FmtOptions { indent: 4, ..Default::default() }).unwrap();
+        assert_eq!(pretty, "{\n    \"a\": [\n        1\n    ]\n}");
+        let err = format_json("{\n  \"a\": 1,\n}", FmtOptions::default()).unwrap_err();
+        assert!(err.contains("line 3 column 1"), "{}", err);
+    }
+
+    #[test]
+    fn test_yaml_toml_roundtrip() {
+        let json = serde_json::json!({"name": "frog", "tags": ["a", "b"], "db": {"port": 5432, "ssl": true}});
+        assert_eq!(yaml_to_json(&json_to_yaml(&json).unwrap()).unwrap(), json);
+        let toml = json_to_toml(&json).unwrap();
+        assert!(toml.contains("[db]"));
+        assert_eq!(toml_to_json(&toml).unwrap(), json);
+        assert!(toml_to_json("a = ").unwrap_err().contains("line 1"));
+    }
+
+    #[test]
+    fn test_xml_and_csv() {
+        let xml = r#"<users><user id="1"><name>Ann &amp; Bo</name></user><user id="2"><name>Cy</name></user></users>"#;
+        let json = xml_to_json(xml).unwrap();
+        assert_eq!(json["users"]["user"][0]["@id"], "1");
+        assert_eq!(json["users"]["user"][0]["name"], "Ann & Bo");
+        assert_eq!(xml_to_json(&json_to_xml(&json)).unwrap(), json);
+        assert!(xml_to_json("<a>\n<b></a>").unwrap_err().contains("line 2"));
+
+        let csv = "name,age\nann,30\n\"bo, jr\",4";
+        let json = csv_to_json(csv).unwrap();
+        assert_eq!(json[1]["name"], "bo, jr");
+        assert_eq!(json_to_csv(&json).unwrap(), csv);
+    }
+
+    #[test]
+    fn test_xml_array_has_one_root() {
+        use quick_xml::events::Event;
+        let json = csv_to_json("name,age\nann,30\nbo,4").unwrap();
+        let xml = json_to_xml(&json);
+        let mut reader = quick_xml::Reader::from_str(&xml);
+        let (mut depth, mut roots) = (0, 0);
+        loop {
+            match reader.read_event().unwrap() {
+                Event::Start(_) => {
+                    roots += usize::from(depth == 0);
+                    depth += 1;
+                }
+                Event::End(_) => depth -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        assert_eq!(roots, 1);
+        assert_eq!(xml_to_json(&xml).unwrap()["root"]["item"], json);
+    }
+}
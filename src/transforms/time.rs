@@ -0,0 +1,398 @@
+//! Timestamp conversion between Unix units, calendar formats and other epochs
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+
+const NS: i128 = 1_000_000_000;
+/// Seconds from 1601-01-01 (Windows FILETIME epoch) to the Unix epoch
+const FILETIME_OFFSET: i128 = 11_644_473_600;
+/// Seconds from 1970-01-01 to 2001-01-01 (Apple Cocoa / Core Data epoch)
+const COCOA_OFFSET: i128 = 978_307_200;
+/// .NET ticks (100 ns since 0001-01-01) at the Unix epoch
+const TICKS_OFFSET: i128 = 621_355_968_000_000_000;
+/// 100 ns intervals from 1582-10-15 (UUID v1/v6 epoch) to the Unix epoch
+pub(crate) const UUID_OFFSET: i128 = 0x01B2_1DD2_1381_4000;
+pub(crate) const TWITTER_EPOCH_MS: i128 = 1_288_834_974_657;
+pub(crate) const DISCORD_EPOCH_MS: i128 = 1_420_070_400_000;
+
+/// How to read a numeric input
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Kind {
+    /// Guess Unix units from the digit count; dates, UUIDs and ULIDs by shape
+    #[default]
+    Auto,
+    Seconds,
+    Millis,
+    Micros,
+    Nanos,
+    FileTime,
+    Cocoa,
+    Ticks,
+    Snowflake,
+    Discord,
+}
+
+pub const KINDS: [Kind; 10] = [
+    Kind::Auto,
+    Kind::Seconds,
+    Kind::Millis,
+    Kind::Micros,
+    Kind::Nanos,
+    Kind::FileTime,
+    Kind::Cocoa,
+    Kind::Ticks,
+    Kind::Snowflake,
+    Kind::Discord,
+];
+
+impl Kind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Kind::Auto => "auto",
+            Kind::Seconds => "s",
+            Kind::Millis => "ms",
+            Kind::Micros => "us",
+            Kind::Nanos => "ns",
+            Kind::FileTime => "filetime",
+            Kind::Cocoa => "cocoa",
+            Kind::Ticks => "ticks",
+            Kind::Snowflake => "snowflake",
+            Kind::Discord => "discord",
+        }
+    }
+
+    /// Description shown as the source of a parsed value
+    pub fn label(&self) -> &'static str {
+        match self {
+            Kind::Auto => "auto",
+            Kind::Seconds => "Unix seconds",
+            Kind::Millis => "Unix milliseconds",
+            Kind::Micros => "Unix microseconds",
+            Kind::Nanos => "Unix nanoseconds",
+            Kind::FileTime => "Windows FILETIME",
+            Kind::Cocoa => "Cocoa timestamp",
+            Kind::Ticks => ".NET ticks",
+            Kind::Snowflake => "Twitter snowflake",
+            Kind::Discord => "Discord snowflake",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        match name.to_ascii_lowercase().as_str() {
+            "auto" => Ok(Kind::Auto),
+            "s" | "sec" | "seconds" | "unix" => Ok(Kind::Seconds),
+            "ms" | "millis" => Ok(Kind::Millis),
+            "us" | "µs" | "micros" => Ok(Kind::Micros),
+            "ns" | "nanos" => Ok(Kind::Nanos),
+            "filetime" | "windows" => Ok(Kind::FileTime),
+            "cocoa" | "apple" | "mac" => Ok(Kind::Cocoa),
+            "ticks" | "dotnet" | ".net" => Ok(Kind::Ticks),
+            "snowflake" | "twitter" => Ok(Kind::Snowflake),
+            "discord" => Ok(Kind::Discord),
+            other => Err(format!(
+                "Unknown input kind '{}'. Use: {}",
+                other,
+                KINDS.iter().map(Kind::name).collect::<Vec<_>>().join(", ")
+            )),
+        }
+    }
+}
+
+/// Output representations for `qt time --to`
+pub const TARGETS: [&str; 11] =
+    ["s", "ms", "us", "ns", "rfc3339", "local", "rfc2822", "iso-week", "filetime", "cocoa", "ticks"];
+
+/// A parsed instant and every representation of it
+#[derive(Debug, Clone)]
+pub struct TimeInfo {
+    /// Nanoseconds since the Unix epoch
+    pub nanos: i128,
+    /// What the input was read as
+    pub source: String,
+    pub fields: Vec<(String, String)>,
+}
+
+impl TimeInfo {
+    /// Aligned `Label: value` lines
+    pub fn summary(&self) -> String {
+        let mut out = format!("[{}]", self.source);
+        for (label, value) in &self.fields {
+            out.push_str(&format!("\n  {:<18} {}", format!("{}:", label), value));
+        }
+        out
+    }
+}
+
+/// Human-readable offset such as `in 2h 5m` or `3d 4h ago`
+pub fn relative(delta: i64) -> String {
+    let secs = delta.unsigned_abs();
+    let (d, h, m, s) = (secs / 86400, secs % 86400 / 3600, secs % 3600 / 60, secs % 60);
+    let span = if d > 0 {
+        format!("{}d {}h", d, h)
+    } else if h > 0 {
+        format!("{}h {}m", h, m)
+    } else if m > 0 {
+        format!("{}m {}s", m, s)
+    } else {
+        format!("{}s", s)
+    };
+    if delta >= 0 { format!("in {}", span) } else { format!("{} ago", span) }
+}
+
+// ============================================================================
+// PARSING
+// ============================================================================
+
+/// `"1.5"` scaled by `unit` nanoseconds, without going through floats
+fn scaled(text: &str, unit: i128) -> Result<i128, String> {
+    let bad = || format!("Not a number: {}", text);
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    if int.is_empty() && frac.is_empty() || !int.chars().chain(frac.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(bad());
+    }
+    let int: i128 = if int.is_empty() { 0 } else { int.parse().map_err(|_| bad())? };
+    let mut value = int.checked_mul(unit).ok_or_else(bad)?;
+    let mut place = unit;
+    for c in frac.chars() {
+        place /= 10;
+        value = value.checked_add(i128::from(c as u8 - b'0') * place).ok_or_else(bad)?;
+    }
+    Ok(if negative { -value } else { value })
+}
+
+fn integer(text: &str) -> Result<i128, String> {
+    text.parse().map_err(|_| format!("Not an integer: {}", text))
+}
+
+fn from_datetime<Tz: chrono::TimeZone>(dt: DateTime<Tz>) -> i128 {
+    i128::from(dt.timestamp()) * NS + i128::from(dt.timestamp_subsec_nanos())
+}
+
+/// Nanoseconds from a UUID v1, v6 or v7
+fn uuid_time(text: &str) -> Result<i128, String> {
+    let hex: String = text.chars().filter(|c| *c != '-').collect();
+    let field = |range: std::ops::Range<usize>| i128::from_str_radix(&hex[range], 16).map_err(|_| "Invalid UUID".to_string());
+    match &hex[12..13] {
+        "1" => {
+            let ticks = (field(13..16)? << 48) | (field(8..12)? << 32) | field(0..8)?;
+            Ok((ticks - UUID_OFFSET) * 100)
+        }
+        "6" => {
+            let ticks = (field(0..12)? << 12) | field(13..16)?;
+            Ok((ticks - UUID_OFFSET) * 100)
+        }
+        "7" => Ok(field(0..12)? * 1_000_000),
+        v => Err(format!("UUID v{} has no timestamp (only v1, v6 and v7 do)", v)),
+    }
+}
+
+fn is_uuid(text: &str) -> bool {
+    let hex: String = text.chars().filter(|c| *c != '-').collect();
+    text.len() == 36 && hex.len() == 32 && hex.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+pub(crate) const CROCKFORD: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Milliseconds in the first 10 characters of a ULID
+pub fn ulid_millis(text: &str) -> Result<i128, String> {
+    if text.len() != 26 {
+        return Err("ULID must be 26 characters".to_string());
+    }
+    text[..10].chars().try_fold(0i128, |acc, c| {
+        let c = match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        };
+        let digit = CROCKFORD.iter().position(|&b| b as char == c).ok_or_else(|| format!("Invalid ULID character '{}'", c))?;
+        Ok(acc * 32 + digit as i128)
+    })
+}
+
+fn parse_date(text: &str) -> Option<(i128, &'static str)> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        return Some((from_datetime(dt), "RFC 3339"));
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc2822(text) {
+        return Some((from_datetime(dt), "RFC 2822"));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(text, format) {
+            return Some((from_datetime(dt.and_utc()), "date-time (UTC)"));
+        }
+    }
+    let week = if text.matches('-').count() == 1 { format!("{}-1", text) } else { text.to_string() };
+    if let Ok(date) = NaiveDate::parse_from_str(&week, "%G-W%V-%u") {
+        return Some((from_datetime(date.and_hms_opt(0, 0, 0)?.and_utc()), "ISO week date"));
+    }
+    let date = NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()?;
+    Some((from_datetime(date.and_hms_opt(0, 0, 0)?.and_utc()), "date (UTC)"))
+}
+
+/// Nanoseconds since the Unix epoch, and a description of what was parsed
+pub fn parse(input: &str, kind: Kind) -> Result<(i128, String), String> {
+    let text = input.trim();
+    if text.is_empty() || text.eq_ignore_ascii_case("now") {
+        return Ok((from_datetime(Utc::now()), "now".to_string()));
+    }
+    let ms = |v: i128| v * 1_000_000;
+    let out_of_range = || "Timestamp out of range".to_string();
+    let nanos = match kind {
+        Kind::Seconds => scaled(text, NS)?,
+        Kind::Millis => scaled(text, 1_000_000)?,
+        Kind::Micros => scaled(text, 1_000)?,
+        Kind::Nanos => integer(text)?,
+        Kind::FileTime => {
+            integer(text)?.checked_mul(100).and_then(|n| n.checked_sub(FILETIME_OFFSET * NS)).ok_or_else(out_of_range)?
+        }
+        Kind::Cocoa => scaled(text, NS)?.checked_add(COCOA_OFFSET * NS).ok_or_else(out_of_range)?,
+        Kind::Ticks => {
+            integer(text)?.checked_sub(TICKS_OFFSET).and_then(|n| n.checked_mul(100)).ok_or_else(out_of_range)?
+        }
+        Kind::Snowflake => ms((integer(text)? >> 22) + TWITTER_EPOCH_MS),
+        Kind::Discord => ms((integer(text)? >> 22) + DISCORD_EPOCH_MS),
+        Kind::Auto => {
+            let digits = text.trim_start_matches('-').split('.').next().unwrap_or("");
+            if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                let kind = match digits.len() {
+                    0..=11 => Kind::Seconds,
+                    12..=14 => Kind::Millis,
+                    15..=17 => Kind::Micros,
+                    _ => Kind::Nanos,
+                };
+                let (nanos, _) = parse(text, kind)?;
+                return Ok((nanos, kind.label().to_string()));
+            }
+            if is_uuid(text) {
+                return Ok((uuid_time(text)?, format!("UUID v{}", &text[14..15])));
+            }
+            if text.len() == 26 && text.is_char_boundary(10) {
+                if let Ok(millis) = ulid_millis(text) {
+                    return Ok((ms(millis), "ULID".to_string()));
+                }
+            }
+            return parse_date(text)
+                .map(|(nanos, what)| (nanos, what.to_string()))
+                .ok_or_else(|| format!("Cannot read '{}' as a timestamp or date", text));
+        }
+    };
+    Ok((nanos, kind.label().to_string()))
+}
+
+// ============================================================================
+// FORMATTING
+// ============================================================================
+
+/// `value / unit` with the remainder as trimmed decimals (`1.5`, not `1.500000000`)
+fn decimal(value: i128, unit: i128) -> String {
+    if value < 0 {
+        return format!("-{}", decimal(-value, unit));
+    }
+    let (whole, rest) = (value / unit, value % unit);
+    if rest == 0 {
+        return whole.to_string();
+    }
+    let width = unit.to_string().len() - 1;
+    format!("{}.{}", whole, format!("{:0width$}", rest, width = width).trim_end_matches('0'))
+}
+
+fn datetime(nanos: i128) -> Result<DateTime<Utc>, String> {
+    let secs = i64::try_from(nanos.div_euclid(NS)).map_err(|_| "Timestamp out of range".to_string())?;
+    DateTime::from_timestamp(secs, nanos.rem_euclid(NS) as u32).ok_or_else(|| "Timestamp out of range".to_string())
+}
+
+/// One representation of `nanos` (see [`TARGETS`])
+pub fn format(nanos: i128, target: &str) -> Result<String, String> {
+    let dt = || datetime(nanos);
+    Ok(match target.to_ascii_lowercase().as_str() {
+        "s" | "unix" | "seconds" => decimal(nanos, NS),
+        "ms" => nanos.div_euclid(1_000_000).to_string(),
+        "us" | "µs" => nanos.div_euclid(1_000).to_string(),
+        "ns" => nanos.to_string(),
+        "rfc3339" | "iso" | "utc" => dt()?.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        "local" => dt()?.with_timezone(&Local).to_rfc3339_opts(SecondsFormat::AutoSi, false),
+        "rfc2822" => dt()?.to_rfc2822(),
+        "iso-week" | "week" => dt()?.format("%G-W%V-%u").to_string(),
+        "filetime" => (nanos.div_euclid(100) + FILETIME_OFFSET * NS / 100).to_string(),
+        "cocoa" => decimal(nanos - COCOA_OFFSET * NS, NS),
+        "ticks" => (nanos.div_euclid(100) + TICKS_OFFSET).to_string(),
+        other => return Err(format!("Unknown output '{}'. Use: {}", other, TARGETS.join(", "))),
+    })
+}
+
+/// Parse `input` and show it in every supported representation
+pub fn inspect(input: &str, kind: Kind) -> Result<TimeInfo, String> {
+    let (nanos, source) = parse(input, kind)?;
+    let dt = datetime(nanos)?;
+    let labels = [
+        ("Unix seconds", "s"),
+        ("Unix ms", "ms"),
+        ("Unix µs", "us"),
+        ("Unix ns", "ns"),
+        ("UTC", "rfc3339"),
+        ("Local", "local"),
+        ("RFC 2822", "rfc2822"),
+        ("ISO week", "iso-week"),
+        ("Windows FILETIME", "filetime"),
+        ("Cocoa (2001)", "cocoa"),
+        (".NET ticks", "ticks"),
+    ];
+    let mut fields = labels
+        .iter()
+        .map(|(label, target)| Ok((label.to_string(), format(nanos, target)?)))
+        .collect::<Result<Vec<_>, String>>()?;
+    fields.insert(8, ("Day of year".to_string(), dt.format("%j (%A)").to_string()));
+    fields.push(("Relative".to_string(), relative(dt.timestamp() - Utc::now().timestamp())));
+    Ok(TimeInfo { nanos, source, fields })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OCT_17: i128 = 1_697_558_400 * NS; // 2023-10-17T16:00:00Z
+
+    #[test]
+    fn test_parse_units_and_epochs() {
+        assert_eq!(parse("1697558400", Kind::Auto).unwrap().0, OCT_17);
+        assert_eq!(parse("1697558400000", Kind::Auto).unwrap().0, OCT_17);
+        assert_eq!(parse("1697558400.25", Kind::Auto).unwrap().0, OCT_17 + 250_000_000);
+        assert_eq!(parse("133420320000000000", Kind::FileTime).unwrap().0, OCT_17);
+        assert_eq!(parse("719251200", Kind::Cocoa).unwrap().0, OCT_17);
+        assert_eq!(parse("638331552000000000", Kind::Ticks).unwrap().0, OCT_17);
+        assert_eq!(parse("2023-10-17T18:00:00+02:00", Kind::Auto).unwrap().0, OCT_17);
+        assert_eq!(parse("Tue, 17 Oct 2023 16:00:00 +0000", Kind::Auto).unwrap().0, OCT_17);
+        assert_eq!(parse("2023-W42-2", Kind::Auto).unwrap().0, OCT_17 - 16 * 3600 * NS);
+        // Discord's own example ID
+        assert_eq!(format(parse("175928847299117063", Kind::Discord).unwrap().0, "ms").unwrap(), "1462015105796");
+        assert!(parse("yesterday-ish", Kind::Auto).is_err());
+        let huge = "100000000000000000000000000000000000000";
+        assert_eq!(parse(huge, Kind::FileTime).unwrap_err(), "Timestamp out of range");
+        assert_eq!(parse(&format!("-{}", huge), Kind::Ticks).unwrap_err(), "Timestamp out of range");
+        assert_eq!(parse("170141183460469231731687303715", Kind::Cocoa).unwrap_err(), "Timestamp out of range");
+    }
+
+    #[test]
+    fn test_embedded_timestamps() {
+        // RFC 9562 example UUIDs (2022-02-22T19:22:22Z, v7 with ms precision)
+        assert_eq!(format(parse("c232ab00-9414-11ec-b3c8-9f6bdeced846", Kind::Auto).unwrap().0, "s").unwrap(), "1645557742");
+        assert_eq!(format(parse("1ec9414c-232a-6b00-b3c8-9f6bdeced846", Kind::Auto).unwrap().0, "s").unwrap(), "1645557742");
+        assert_eq!(format(parse("017f22e2-79b0-7cc3-98c4-dc0c0c07398f", Kind::Auto).unwrap().0, "s").unwrap(), "1645557742");
+        assert_eq!(ulid_millis("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap(), 1_469_922_850_259);
+        assert!(parse("550e8400-e29b-41d4-a716-446655440000", Kind::Auto).unwrap_err().contains("v4"));
+    }
+
+    #[test]
+    fn test_format_targets() {
+        assert_eq!(format(OCT_17, "rfc3339").unwrap(), "2023-10-17T16:00:00Z");
+        assert_eq!(format(OCT_17, "iso-week").unwrap(), "2023-W42-2");
+        assert_eq!(format(OCT_17, "filetime").unwrap(), "133420320000000000");
+        assert_eq!(format(OCT_17 + 500_000_000, "s").unwrap(), "1697558400.5");
+        assert_eq!(format(-NS / 2, "s").unwrap(), "-0.5");
+        let info = inspect("1697558400", Kind::Auto).unwrap();
+        assert!(info.summary().contains("Tue, 17 Oct 2023 16:00:00 +0000"));
+    }
+}
@@ -0,0 +1,349 @@
+//! Unicode inspection, normalization and byte encodings
+
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::UnicodeNormalization;
+
+/// One character of inspected text
+#[derive(Debug, Clone)]
+pub struct CharInfo {
+    /// Byte offset in the UTF-8 text
+    pub offset: usize,
+    pub ch: char,
+    pub name: String,
+    /// Two-letter general category, e.g. `Lu` or `Cf`
+    pub category: &'static str,
+    /// Why this character is worth a second look
+    pub warning: Option<String>,
+}
+
+impl CharInfo {
+    pub fn code_point(&self) -> String {
+        format!("U+{:04X}", self.ch as u32)
+    }
+
+    pub fn utf8_hex(&self) -> String {
+        let mut buf = [0u8; 4];
+        let bytes = self.ch.encode_utf8(&mut buf).as_bytes();
+        bytes.iter().map(|b| format!("{:02x}", b)).collect::<Vec<_>>().join(" ")
+    }
+
+    pub fn utf16_hex(&self) -> String {
+        let mut buf = [0u16; 2];
+        self.ch.encode_utf16(&mut buf).iter().map(|u| format!("{:04x}", u)).collect::<Vec<_>>().join(" ")
+    }
+
+    /// The character itself, or a visible stand-in for controls and invisibles
+    pub fn display(&self) -> String {
+        let c = self.ch;
+        match self.category {
+            "Cc" if (c as u32) < 0x20 => char::from_u32(0x2400 + c as u32).map(String::from).unwrap_or_default(),
+            "Cc" => "␡".to_string(),
+            "Cf" | "Zl" | "Zp" => "·".to_string(),
+            "Zs" if c != ' ' => "␣".to_string(),
+            "Mn" | "Me" => format!("◌{}", c),
+            _ => c.to_string(),
+        }
+    }
+}
+
+/// Names for controls, which have none of their own in the Unicode name list
+fn control_name(c: char) -> &'static str {
+    match c {
+        '\0' => "NULL",
+        '\t' => "CHARACTER TABULATION",
+        '\n' => "LINE FEED",
+        '\r' => "CARRIAGE RETURN",
+        '\x1b' => "ESCAPE",
+        '\x7f' => "DELETE",
+        _ => "<control>",
+    }
+}
+
+fn warning(c: char, category: GeneralCategory) -> Option<String> {
+    let text = match c {
+        '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{180E}' => "zero-width",
+        '\u{FEFF}' => "zero-width (byte order mark)",
+        '\u{00AD}' => "invisible soft hyphen",
+        '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}' => "bidi override",
+        '\u{200E}' | '\u{200F}' | '\u{061C}' => "bidi mark",
+        '\u{FFFD}' => "replacement character (decoding error)",
+        '\t' | '\n' | '\r' | ' ' => return None,
+        _ => match category {
+            GeneralCategory::Control => "control character",
+            GeneralCategory::Format => "invisible format character",
+            GeneralCategory::SpaceSeparator => "non-ASCII space",
+            GeneralCategory::LineSeparator | GeneralCategory::ParagraphSeparator => "line/paragraph separator",
+            GeneralCategory::PrivateUse => "private use",
+            GeneralCategory::Unassigned => "unassigned code point",
+            _ if !c.is_ascii() => return confusable(c),
+            _ => return None,
+        },
+    };
+    Some(text.to_string())
+}
+
+/// Non-ASCII characters that look like ASCII ones (Cyrillic `а`, fullwidth `Ａ`, ...)
+fn confusable(c: char) -> Option<String> {
+    let skeleton: String = unicode_security::confusable_detection::skeleton(&c.to_string()).collect();
+    (skeleton != c.to_string() && skeleton.is_ascii() && skeleton.chars().any(|s| s.is_ascii_graphic()))
+        .then(|| format!("confusable with \"{}\"", skeleton))
+}
+
+/// Every character with its name, category and any warning
+pub fn inspect(text: &str) -> Vec<CharInfo> {
+    text.char_indices()
+        .map(|(offset, ch)| {
+            let category = get_general_category(ch);
+            let name = match unicode_names2::name(ch) {
+                Some(name) => name.to_string(),
+                None if category == GeneralCategory::Control => control_name(ch).to_string(),
+                None => "<unnamed>".to_string(),
+            };
+            CharInfo { offset, ch, name, category: category.abbreviation(), warning: warning(ch, category) }
+        })
+        .collect()
+}
+
+/// Table of characters followed by the [`summary`]
+pub fn inspect_table(text: &str) -> String {
+    let chars = inspect(text);
+    let mut lines = vec![format!("{:>6}  {:<4} {:<9} {:<12} {:<10} {:<3} Name", "Offset", "Char", "Code", "UTF-8", "UTF-16", "Cat")];
+    for c in &chars {
+        let mut line = format!(
+            "{:>6}  {:<4} {:<9} {:<12} {:<10} {:<3} {}",
+            c.offset,
+            c.display(),
+            c.code_point(),
+            c.utf8_hex(),
+            c.utf16_hex(),
+            c.category,
+            c.name
+        );
+        if let Some(ref w) = c.warning {
+            line.push_str(&format!("  ⚠ {}", w));
+        }
+        lines.push(line);
+    }
+
+    lines.push(String::new());
+    lines.push(summary(text, &chars));
+    lines.join("\n")
+}
+
+/// Character/byte counts, flagged count and which normalizations change the text
+pub fn summary(text: &str, chars: &[CharInfo]) -> String {
+    let flagged = chars.iter().filter(|c| c.warning.is_some()).count();
+    let not_in = FORMS.iter().filter(|f| f.apply(text) != text).map(|f| f.name()).collect::<Vec<_>>();
+    let normal = if not_in.is_empty() {
+        "Already in NFC, NFD, NFKC and NFKD".to_string()
+    } else {
+        format!("Changed by {}", not_in.join(", "))
+    };
+    format!(
+        "{} chars, {} UTF-8 bytes, {} UTF-16 units, {} flagged\n{}",
+        chars.len(),
+        text.len(),
+        text.encode_utf16().count(),
+        flagged,
+        normal
+    )
+}
+
+// ============================================================================
+// NORMALIZATION
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+pub const FORMS: [Form; 4] = [Form::Nfc, Form::Nfd, Form::Nfkc, Form::Nfkd];
+
+impl Form {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Form::Nfc => "NFC",
+            Form::Nfd => "NFD",
+            Form::Nfkc => "NFKC",
+            Form::Nfkd => "NFKD",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        FORMS
+            .into_iter()
+            .find(|f| f.name().eq_ignore_ascii_case(name))
+            .ok_or_else(|| format!("Unknown normalization '{}'. Use: nfc, nfd, nfkc, nfkd", name))
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        match self {
+            Form::Nfc => text.nfc().collect(),
+            Form::Nfd => text.nfd().collect(),
+            Form::Nfkc => text.nfkc().collect(),
+            Form::Nfkd => text.nfkd().collect(),
+        }
+    }
+}
+
+// ============================================================================
+// BYTE ENCODINGS
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Utf32Le,
+    Utf32Be,
+    Latin1,
+    Windows1252,
+}
+
+pub const ENCODINGS: [TextEncoding; 7] = [
+    TextEncoding::Utf8,
+    TextEncoding::Utf16Le,
+    TextEncoding::Utf16Be,
+    TextEncoding::Utf32Le,
+    TextEncoding::Utf32Be,
+    TextEncoding::Latin1,
+    TextEncoding::Windows1252,
+];
+
+impl TextEncoding {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TextEncoding::Utf8 => "utf-8",
+            TextEncoding::Utf16Le => "utf-16le",
+            TextEncoding::Utf16Be => "utf-16be",
+            TextEncoding::Utf32Le => "utf-32le",
+            TextEncoding::Utf32Be => "utf-32be",
+            TextEncoding::Latin1 => "latin-1",
+            TextEncoding::Windows1252 => "windows-1252",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, String> {
+        let key: String = name.to_ascii_lowercase().chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+        match key.as_str() {
+            "utf8" => Ok(TextEncoding::Utf8),
+            "utf16" | "utf16le" | "ucs2" => Ok(TextEncoding::Utf16Le),
+            "utf16be" => Ok(TextEncoding::Utf16Be),
+            "utf32" | "utf32le" => Ok(TextEncoding::Utf32Le),
+            "utf32be" => Ok(TextEncoding::Utf32Be),
+            "latin1" | "iso88591" => Ok(TextEncoding::Latin1),
+            "windows1252" | "cp1252" => Ok(TextEncoding::Windows1252),
+            _ => Err(format!(
+                "Unknown encoding '{}'. Use: {}",
+                name,
+                ENCODINGS.iter().map(|e| e.name()).collect::<Vec<_>>().join(", ")
+            )),
+        }
+    }
+
+    pub fn encode(&self, text: &str) -> Result<Vec<u8>, String> {
+        let unencodable = |c: char| format!("{} cannot encode U+{:04X} '{}'", self.name(), c as u32, c);
+        Ok(match self {
+            TextEncoding::Utf8 => text.as_bytes().to_vec(),
+            TextEncoding::Utf16Le => text.encode_utf16().flat_map(u16::to_le_bytes).collect(),
+            TextEncoding::Utf16Be => text.encode_utf16().flat_map(u16::to_be_bytes).collect(),
+            TextEncoding::Utf32Le => text.chars().flat_map(|c| (c as u32).to_le_bytes()).collect(),
+            TextEncoding::Utf32Be => text.chars().flat_map(|c| (c as u32).to_be_bytes()).collect(),
+            TextEncoding::Latin1 => text
+                .chars()
+                .map(|c| u8::try_from(c as u32).map_err(|_| unencodable(c)))
+                .collect::<Result<_, _>>()?,
+            TextEncoding::Windows1252 => {
+                let mut out = Vec::with_capacity(text.len());
+                let mut buf = [0; 4];
+                for c in text.chars() {
+                    let (bytes, _, failed) = encoding_rs::WINDOWS_1252.encode(c.encode_utf8(&mut buf));
+                    if failed {
+                        return Err(unencodable(c));
+                    }
+                    out.extend_from_slice(&bytes);
+                }
+                out
+            }
+        })
+    }
+
+    pub fn decode(&self, data: &[u8]) -> Result<String, String> {
+        let units16 = |be: bool| -> Result<Vec<u16>, String> {
+            if data.len() % 2 != 0 {
+                return Err(format!("{} needs an even number of bytes", self.name()));
+            }
+            Ok(data
+                .chunks(2)
+                .map(|p| if be { u16::from_be_bytes([p[0], p[1]]) } else { u16::from_le_bytes([p[0], p[1]]) })
+                .collect())
+        };
+        let utf32 = |be: bool| -> Result<String, String> {
+            if data.len() % 4 != 0 {
+                return Err(format!("{} needs a multiple of 4 bytes", self.name()));
+            }
+            data.chunks(4)
+                .map(|q| {
+                    let bytes = [q[0], q[1], q[2], q[3]];
+                    let n = if be { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) };
+                    char::from_u32(n).ok_or_else(|| format!("Invalid code point 0x{:X}", n))
+                })
+                .collect()
+        };
+        match self {
+            TextEncoding::Utf8 => String::from_utf8(data.to_vec()).map_err(|e| format!("UTF-8 error: {}", e)),
+            TextEncoding::Utf16Le => String::from_utf16(&units16(false)?).map_err(|e| format!("UTF-16 error: {}", e)),
+            TextEncoding::Utf16Be => String::from_utf16(&units16(true)?).map_err(|e| format!("UTF-16 error: {}", e)),
+            TextEncoding::Utf32Le => utf32(false),
+            TextEncoding::Utf32Be => utf32(true),
+            TextEncoding::Latin1 => Ok(data.iter().map(|&b| b as char).collect()),
+            TextEncoding::Windows1252 => Ok(encoding_rs::WINDOWS_1252.decode_without_bom_handling(data).0.into_owned()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inspect_flags() {
+        let chars = inspect("p\u{0430}y\u{200B}\u{202E}é");
+        assert_eq!(chars[0].warning, None);
+        assert_eq!(chars[1].name, "CYRILLIC SMALL LETTER A");
+        assert_eq!(chars[1].warning.as_deref(), Some("confusable with \"a\""));
+        assert_eq!(chars[3].warning.as_deref(), Some("zero-width"));
+        assert_eq!(chars[4].warning.as_deref(), Some("bidi override"));
+        assert_eq!(chars[5].category, "Ll");
+        assert_eq!(chars[5].utf8_hex(), "c3 a9");
+        assert_eq!(inspect("\n")[0].name, "LINE FEED");
+        assert!(inspect_table("é").contains("Changed by NFD, NFKD"));
+    }
+
+    #[test]
+    fn test_normalization() {
+        assert_eq!(Form::Nfd.apply("é"), "e\u{301}");
+        assert_eq!(Form::Nfc.apply("e\u{301}"), "é");
+        assert_eq!(Form::Nfkc.apply("ﬁ①"), "fi1");
+        assert!(Form::from_name("nfx").is_err());
+    }
+
+    #[test]
+    fn test_encodings() {
+        let text = "a€😀";
+        assert_eq!(hex::encode(TextEncoding::Utf16Le.encode(text).unwrap()), "6100ac203dd800de");
+        assert_eq!(hex::encode(TextEncoding::Utf32Be.encode("€").unwrap()), "000020ac");
+        assert_eq!(TextEncoding::Windows1252.encode("€").unwrap(), vec![0x80]);
+        assert!(TextEncoding::Latin1.encode("€").is_err());
+        for enc in ENCODINGS.into_iter().take(5) {
+            assert_eq!(enc.decode(&enc.encode(text).unwrap()).unwrap(), text);
+        }
+        assert_eq!(TextEncoding::Windows1252.decode(&[0x93, 0x80, 0x94]).unwrap(), "“€”");
+        assert!(TextEncoding::Utf16Le.decode(&[0x61]).is_err());
+    }
+}